@@ -1,7 +1,7 @@
 // FILE: examples/cookbook.rs - Curated notification recipes with code snippets
-// VERSION: 1.1.0
-// WCTX: Adding cookbook example for common configurations
-// CLOG: Added notification feedback on file write, auto-incrementing filename
+// VERSION: 1.1.1
+// WCTX: Clippy cleanup
+// CLOG: Collapsed code-modal key guard into match arm
 //
 // Cookbook of common notification configurations.
 // Run with: cargo run --example cookbook
@@ -378,10 +378,8 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                             KeyCode::Char('q') | KeyCode::Esc => {
                                 app.should_quit = true;
                             }
-                            KeyCode::Char('i') => {
-                                if !app.current_code.is_empty() {
-                                    app.show_code_modal = true;
-                                }
+                            KeyCode::Char('i') if !app.current_code.is_empty() => {
+                                app.show_code_modal = true;
                             }
                             KeyCode::Char(c) => {
                                 // Find matching recipe
@@ -542,4 +540,4 @@ fn render_code_modal(f: &mut Frame<'_>, frame_area: Rect, app: &App) {
 }
 
 // FILE: examples/cookbook.rs - Curated notification recipes with code snippets
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.1.1
@@ -1,7 +1,7 @@
 // FILE: src/lib.rs - Ratatui Notifications library root
-// VERSION: 2.1.0
-// WCTX: Adding code generation feature
-// CLOG: Added generate_code export
+// VERSION: 2.21.0
+// WCTX: Typed notification handles
+// CLOG: Export NotificationHandle
 
 //! # Ratatui Notifications
 //!
@@ -108,18 +108,44 @@ pub use notifications::{
     // Core types
     Notification,
     NotificationBuilder,
+    NotificationHandle,
+    NotificationState,
     Notifications,
+    NotificationsExt,
+    NotificationRenderer,
 
     // Configuration enums
     Anchor,
     Animation,
     AutoDismiss,
+    ClickTrigger,
+    DismissReason,
+    Easing,
+    InitialRender,
+    KindMode,
     Level,
+    LevelStyle,
+    LevelTheme,
     Overflow,
+    PhaseEvent,
+    Shape,
     SizeConstraint,
     SlideDirection,
+    StackDirection,
     Timing,
 
+    // Diagnostics
+    AddOutcome,
+    DebugStats,
+    DismissalMetrics,
+    RecordedEvent,
+    Recording,
+
+    // Hit-testing
+    ActionId,
+    Hit,
+    HitRegion,
+
     // Error type
     NotificationError,
 
@@ -132,8 +158,12 @@ pub use notifications::{
     generate_code,
 };
 
+// tracing bridge
+#[cfg(feature = "tracing")]
+pub use notifications::NotificationLayer;
+
 // Re-export ratatui Position for custom positioning
 pub use ratatui::layout::Position;
 
 // FILE: src/lib.rs - Ratatui Notifications library root
-// END OF VERSION: 2.1.0
+// END OF VERSION: 2.21.0
@@ -1,18 +1,80 @@
 // FILE: src/notifications/classes/cls_notification.rs - Notification class with builder
-// VERSION: 2.2.0
-// WCTX: Adding code generation support
-// CLOG: Added content() and title() getters for code generation feature
+// VERSION: 2.28.0
+// WCTX: Dismiss callbacks run once
+// CLOG: Make DismissCallback one-shot across clones
 
+use crossterm::event::KeyCode;
 use ratatui::prelude::*;
 use ratatui::widgets::{BorderType, Padding};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
+use crate::notifications::functions::fnc_resolve_styles::resolve_styles;
 use crate::notifications::types::{
-    Anchor, Animation, AutoDismiss, Level, NotificationError, SlideDirection, SizeConstraint, Timing,
+    ActionId, Anchor, Animation, AutoDismiss, DismissReason, Easing, Level, NotificationError, Shape, SlideDirection, SizeConstraint,
+    Timing,
 };
 
 /// Maximum allowed characters in notification content.
 const MAX_CONTENT_CHARS: usize = 1000;
 
+/// Condition polled each tick; the notification exits once it returns true.
+///
+/// Shared behind a mutex so `Notification` stays `Clone`; clones poll the
+/// same closure.
+#[derive(Clone)]
+pub(crate) struct DismissCondition(Arc<Mutex<Box<dyn FnMut() -> bool + Send>>>);
+
+impl DismissCondition {
+    /// Evaluates the condition. A poisoned lock counts as not met.
+    pub(crate) fn is_met(&self) -> bool {
+        self.0.lock().is_ok_and(|mut condition| condition())
+    }
+}
+
+impl std::fmt::Debug for DismissCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DismissCondition(..)")
+    }
+}
+
+/// Callback run once when the notification leaves the manager.
+///
+/// Shared behind a mutex so `Notification` stays `Clone`; clones share the
+/// same closure, which is taken out on the first call so it runs at most
+/// once between them.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct DismissCallback(Arc<Mutex<Option<Box<dyn FnMut(u64, DismissReason) + Send>>>>);
+
+impl DismissCallback {
+    pub(crate) fn new(callback: Box<dyn FnMut(u64, DismissReason) + Send>) -> Self {
+        Self(Arc::new(Mutex::new(Some(callback))))
+    }
+
+    /// Runs the callback unless it already ran. A poisoned lock skips it.
+    pub(crate) fn call(&self, id: u64, reason: DismissReason) {
+        let callback = self.0.lock().ok().and_then(|mut callback| callback.take());
+        if let Some(mut callback) = callback {
+            callback(id, reason);
+        }
+    }
+
+    /// Combines two callbacks into one that runs both, `self` first.
+    pub(crate) fn then(self, next: DismissCallback) -> Self {
+        Self::new(Box::new(move |id, reason| {
+            self.call(id, reason);
+            next.call(id, reason);
+        }))
+    }
+}
+
+impl std::fmt::Debug for DismissCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DismissCallback(..)")
+    }
+}
+
 /// A notification with content, styling, and animation configuration.
 ///
 /// Notifications are created using the builder pattern via `NotificationBuilder`.
@@ -30,22 +92,32 @@ const MAX_CONTENT_CHARS: usize = 1000;
 ///     .unwrap();
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Notification {
     /// The notification content (body text).
+    #[cfg_attr(feature = "serde", serde(with = "crate::shared_utils::serde_ratatui::text"))]
     pub(crate) content: Text<'static>,
 
     /// Optional title line displayed at the top.
+    #[cfg_attr(feature = "serde", serde(with = "crate::shared_utils::serde_ratatui::option_line"))]
     pub(crate) title: Option<Line<'static>>,
 
+    /// Optional bold first line drawn inside the box, above a dimmed body.
+    pub(crate) headline: Option<String>,
+
     /// Severity level affecting visual styling.
     pub(crate) level: Option<Level>,
 
     /// Screen position from which notification expands.
-    pub(crate) anchor: Anchor,
+    /// `None` falls back to the manager's default anchor when added.
+    pub(crate) anchor: Option<Anchor>,
 
     /// Animation style for entry and exit.
     pub(crate) animation: Animation,
 
+    /// Curve applied to entry and exit animation progress.
+    pub(crate) easing: Easing,
+
     /// Direction from which notification slides (for Slide animation).
     pub(crate) slide_direction: SlideDirection,
 
@@ -68,6 +140,7 @@ pub struct Notification {
     pub(crate) max_height: Option<SizeConstraint>,
 
     /// Inner padding around content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::shared_utils::serde_ratatui::padding"))]
     pub(crate) padding: Padding,
 
     /// Outer margin from screen edge.
@@ -82,9 +155,14 @@ pub struct Notification {
     /// Title style.
     pub(crate) title_style: Option<Style>,
 
-    /// Border type (single, double, thick, etc.).
+    /// Border type (single, double, thick, etc.); `None` draws no border.
+    #[cfg_attr(feature = "serde", serde(with = "crate::shared_utils::serde_ratatui::option_border_type"))]
     pub(crate) border_type: Option<BorderType>,
 
+    /// Whether the builder chose the border; otherwise the manager's
+    /// default border applies when added.
+    pub(crate) border_explicit: bool,
+
     /// Custom entry position (overrides anchor-based calculation).
     pub(crate) custom_entry_position: Option<Position>,
 
@@ -93,6 +171,53 @@ pub struct Notification {
 
     /// Whether to apply fade effect during animation.
     pub(crate) fade_effect: bool,
+
+    /// Whether `Notifications::clear()` leaves this notification in place.
+    pub(crate) protected: bool,
+
+    /// Icon glyphs cycled in the title, each shown for the given period.
+    pub(crate) animated_icon: Option<(Vec<String>, Duration)>,
+
+    /// Whether a "NEW" tag is drawn while the entry animation runs.
+    pub(crate) new_badge: bool,
+
+    /// Foreground colours at full and at zero remaining display time.
+    pub(crate) time_color: Option<(Color, Color)>,
+
+    /// App-state condition that starts the exit once it returns true.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dismiss_when: Option<DismissCondition>,
+
+    /// Callback run when the notification is dismissed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) on_dismiss: Option<DismissCallback>,
+
+    /// Kind shared by notifications that replace or update each other.
+    pub(crate) kind: Option<String>,
+
+    /// Outline drawn around the notification.
+    pub(crate) shape: Shape,
+
+    /// Completion fraction in `0.0..=1.0` for progress notifications.
+    pub(crate) progress: Option<f32>,
+
+    /// Whether the dismiss timer is disabled until the app removes it.
+    pub(crate) sticky: bool,
+
+    /// Delay between progress reaching `1.0` and the exit starting.
+    pub(crate) progress_linger: Option<Duration>,
+
+    /// Total time on screen, entry and exit animations included.
+    pub(crate) duration: Option<Duration>,
+
+    /// Overflow evicts lower priorities first.
+    pub(crate) priority: u8,
+
+    /// Action buttons drawn along the bottom row, in order.
+    pub(crate) actions: Vec<(String, ActionId)>,
+
+    /// Keys that trigger an action while focused, as `(label, key)` pairs.
+    pub(crate) action_keys: Vec<(String, KeyCode)>,
 }
 
 impl Notification {
@@ -129,14 +254,36 @@ impl Notification {
         self.title.as_ref()
     }
 
+    /// Returns the bold headline drawn above the body, if set.
+    pub fn headline(&self) -> Option<&str> {
+        self.headline.as_deref()
+    }
+
+    /// Returns the text drawn inside the box: the headline in bold over the
+    /// dimmed content, or just the content when there is no headline.
+    pub(crate) fn display_content(&self) -> Text<'static> {
+        let Some(headline) = &self.headline else {
+            return self.content.clone();
+        };
+        let mut text = self.content.clone();
+        for line in &mut text.lines {
+            line.style = line.style.add_modifier(Modifier::DIM);
+        }
+        text.lines.insert(0, Line::styled(headline.clone(), Style::new().add_modifier(Modifier::BOLD)));
+        text
+    }
+
     /// Returns the notification's severity level.
     pub fn level(&self) -> Option<Level> {
         self.level
     }
 
     /// Returns the notification's anchor position.
+    ///
+    /// If no anchor was set this is `Anchor::BottomRight` until the
+    /// notification is added, when the manager's default anchor applies.
     pub fn anchor(&self) -> Anchor {
-        self.anchor
+        self.anchor.unwrap_or_default()
     }
 
     /// Returns the notification's animation type.
@@ -144,6 +291,11 @@ impl Notification {
         self.animation
     }
 
+    /// Returns the easing curve for entry and exit animations.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
     /// Returns the notification's slide direction.
     pub fn slide_direction(&self) -> SlideDirection {
         self.slide_direction
@@ -208,6 +360,73 @@ impl Notification {
     pub fn fade_effect(&self) -> bool {
         self.fade_effect
     }
+
+    /// Returns whether the notification survives `Notifications::clear()`.
+    pub fn protected(&self) -> bool {
+        self.protected
+    }
+
+    /// Returns the animated icon glyphs and per-glyph period, if set.
+    pub fn animated_icon(&self) -> Option<(&[String], Duration)> {
+        self.animated_icon
+            .as_ref()
+            .map(|(frames, period)| (frames.as_slice(), *period))
+    }
+
+    /// Returns whether a "NEW" tag is drawn during the entry animation.
+    pub fn new_badge(&self) -> bool {
+        self.new_badge
+    }
+
+    /// Returns the kind used to deduplicate notifications, if set.
+    pub fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+
+    /// Returns the outline shape.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Returns the completion fraction, if this is a progress notification.
+    pub fn progress(&self) -> Option<f32> {
+        self.progress
+    }
+
+    /// Returns whether the dismiss timer is disabled.
+    pub fn sticky(&self) -> bool {
+        self.sticky
+    }
+
+    /// Returns how long a completed progress notification stays before exiting, if set.
+    pub fn progress_linger(&self) -> Option<Duration> {
+        self.progress_linger
+    }
+
+    /// Returns the fixed total time on screen, if set.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Returns the overflow priority.
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// Returns the `(label, id)` action buttons, in display order.
+    pub fn actions(&self) -> &[(String, ActionId)] {
+        &self.actions
+    }
+
+    /// Returns the `(label, key)` bindings for the action buttons.
+    pub fn action_keys(&self) -> &[(String, KeyCode)] {
+        &self.action_keys
+    }
+
+    /// Returns the `(from, to)` colours shifted through as time runs out, if set.
+    pub fn time_color(&self) -> Option<(Color, Color)> {
+        self.time_color
+    }
 }
 
 impl Default for Notification {
@@ -215,9 +434,11 @@ impl Default for Notification {
         Self {
             content: Text::from(""),
             title: None,
+            headline: None,
             level: Some(Level::Info),
-            anchor: Anchor::default(),
+            anchor: None,
             animation: Animation::default(),
+            easing: Easing::default(),
             slide_direction: SlideDirection::default(),
             slide_in_timing: Timing::default(),
             dwell_timing: Timing::default(),
@@ -231,9 +452,25 @@ impl Default for Notification {
             border_style: None,
             title_style: None,
             border_type: Some(BorderType::Rounded),
+            border_explicit: false,
             custom_entry_position: None,
             custom_exit_position: None,
             fade_effect: false,
+            protected: false,
+            animated_icon: None,
+            new_badge: false,
+            time_color: None,
+            dismiss_when: None,
+            on_dismiss: None,
+            kind: None,
+            shape: Shape::default(),
+            progress: None,
+            sticky: false,
+            duration: None,
+            priority: 0,
+            progress_linger: None,
+            actions: Vec::new(),
+            action_keys: Vec::new(),
         }
     }
 }
@@ -302,6 +539,79 @@ impl NotificationBuilder {
         self
     }
 
+    /// Sets a headline drawn in bold as the first line inside the box.
+    ///
+    /// The content below it is dimmed and wraps on its own; both count
+    /// towards the height. Unlike `title`, which sits in the top border, the
+    /// headline is part of the body.
+    ///
+    /// # Arguments
+    ///
+    /// * `headline` - Short summary, e.g. "Build failed"
+    pub fn headline(mut self, headline: impl Into<String>) -> Self {
+        self.notification.headline = Some(headline.into());
+        self
+    }
+
+    /// Replaces the content with a fully styled `Text`.
+    ///
+    /// Lines, spans and their styles are drawn as given, layered over the
+    /// content style; each line wraps independently to the notification width.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Multi-line, styled content
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui::prelude::*;
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let text = Text::from(vec![
+    ///     Line::from(vec!["Build ".into(), "failed".red().bold()]),
+    ///     Line::from("3 errors, 1 warning".dim()),
+    /// ]);
+    /// let builder = NotificationBuilder::new("").text(text);
+    /// ```
+    pub fn text(mut self, text: Text<'static>) -> Self {
+        self.notification.content = text;
+        self
+    }
+
+    /// Sets the content to lines that each carry their own level.
+    ///
+    /// Every line is drawn in its level's colour (the same one used for that
+    /// level's border), e.g. for a summary mixing errors and warnings. The
+    /// notification's own level still styles the border and title.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - `(level, text)` pairs, one per content line
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, NotificationBuilder};
+    ///
+    /// let builder = NotificationBuilder::new("").level_lines(vec![
+    ///     (Level::Info, "12 files synced".to_string()),
+    ///     (Level::Warn, "2 files skipped".to_string()),
+    ///     (Level::Error, "1 file failed".to_string()),
+    /// ]);
+    /// ```
+    pub fn level_lines(mut self, lines: Vec<(Level, String)>) -> Self {
+        self.notification.content = lines
+            .into_iter()
+            .map(|(level, text)| {
+                let (_, level_style, _) = resolve_styles(Some(level), None, None, None);
+                Line::styled(text, level_style)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        self
+    }
+
     /// Sets the notification severity level.
     ///
     /// # Arguments
@@ -312,13 +622,13 @@ impl NotificationBuilder {
         self
     }
 
-    /// Sets the screen anchor position.
+    /// Sets the screen anchor position, overriding the manager's default anchor.
     ///
     /// # Arguments
     ///
     /// * `anchor` - Position from which notification expands
     pub fn anchor(mut self, anchor: Anchor) -> Self {
-        self.notification.anchor = anchor;
+        self.notification.anchor = Some(anchor);
         self
     }
 
@@ -332,11 +642,29 @@ impl NotificationBuilder {
         self
     }
 
+    /// Sets the easing curve for the entry and exit animations.
+    ///
+    /// Slide positions, expand/collapse sizes and fade colours are
+    /// interpolated with the eased progress. `Easing::Linear` (the default)
+    /// keeps the animations at constant speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `easing` - Curve, e.g. `Easing::EaseOutCubic` to decelerate into place
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.notification.easing = easing;
+        self
+    }
+
     /// Sets the slide direction.
     ///
+    /// By default the direction follows the anchor, e.g. from the bottom
+    /// for `BottomCenter`. `SlideDirection::None` plays the slide as a fade
+    /// in place, with no movement.
+    ///
     /// # Arguments
     ///
-    /// * `direction` - Direction from which notification slides in
+    /// * `direction` - Direction from which notification slides in (and back out to)
     pub fn slide_direction(mut self, direction: SlideDirection) -> Self {
         self.notification.slide_direction = direction;
         self
@@ -378,6 +706,33 @@ impl NotificationBuilder {
         self
     }
 
+    /// Caps the notification width at `width` cells, keeping the height limit.
+    ///
+    /// Longer content word-wraps to this width and the notification grows
+    /// taller to fit, up to its maximum height.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Maximum width in cells, borders included
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.notification.max_width = Some(SizeConstraint::Absolute(width));
+        self
+    }
+
+    /// Caps the notification height at `height` cells, keeping the width limit.
+    ///
+    /// Content that wraps to more rows than fit is cut off after the last
+    /// whole row that does, and the final visible row becomes a
+    /// "▼ N more lines" indicator. The notification stacks at the capped height.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Maximum height in cells, borders included
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.notification.max_height = Some(SizeConstraint::Absolute(height));
+        self
+    }
+
     /// Sets inner padding.
     ///
     /// # Arguments
@@ -435,6 +790,16 @@ impl NotificationBuilder {
     /// * `border_type` - Border type (Single, Double, Thick, etc.)
     pub fn border_type(mut self, border_type: BorderType) -> Self {
         self.notification.border_type = Some(border_type);
+        self.notification.border_explicit = true;
+        self
+    }
+
+    /// Draws the notification without a border, as padded text.
+    ///
+    /// Saves two rows and two columns; a title still takes the top row.
+    pub fn borderless(mut self) -> Self {
+        self.notification.border_type = None;
+        self.notification.border_explicit = true;
         self
     }
 
@@ -468,16 +833,276 @@ impl NotificationBuilder {
         self
     }
 
-    /// Builds the notification, validating content size.
+    /// Protects the notification from `Notifications::clear()`.
+    ///
+    /// Protected notifications still auto-dismiss and can be removed by ID;
+    /// only `Notifications::force_clear()` removes them in bulk. Overflow
+    /// evicts them only when every candidate is protected.
+    ///
+    /// # Arguments
+    ///
+    /// * `protected` - Whether `clear()` should skip this notification
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.notification.protected = protected;
+        self
+    }
+
+    /// Sets an animated icon that cycles through glyphs in the title.
+    ///
+    /// Replaces the level icon. Each glyph is shown for `period`, independent
+    /// of any progress, which suits spinners for ongoing operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - Glyphs to cycle through (e.g. a spinning gear)
+    /// * `period` - How long each glyph is shown
+    pub fn animated_icon(mut self, frames: Vec<String>, period: Duration) -> Self {
+        self.notification.animated_icon = Some((frames, period));
+        self
+    }
+
+    /// Shows a "NEW" tag in the top border until the notification is fully visible.
+    ///
+    /// The tag is drawn only during the entry animation and disappears once
+    /// the notification starts dwelling.
+    pub fn new_badge(mut self, enable: bool) -> Self {
+        self.notification.new_badge = enable;
+        self
+    }
+
+    /// Shifts the foreground from one colour to another as the display time runs out.
+    ///
+    /// While dwelling the border, title and content are drawn in `from` with
+    /// the full display time left, moving toward `to` as it expires.
+    /// Notifications with `AutoDismiss::Never` have no countdown and ignore this.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Colour with the full display time remaining
+    /// * `to` - Colour as the display time reaches zero
+    pub fn time_color(mut self, from: Color, to: Color) -> Self {
+        self.notification.time_color = Some((from, to));
+        self
+    }
+
+    /// Dismisses the notification once a condition becomes true.
+    ///
+    /// The closure is polled on every `Notifications::tick`; the first time
+    /// it returns `true` the notification starts its exit animation. This
+    /// works alongside `auto_dismiss`, so combine it with `AutoDismiss::Never`
+    /// for notifications that should only go away with the condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - Polled each tick, e.g. checking whether the app is back online
+    pub fn dismiss_when(mut self, condition: Box<dyn FnMut() -> bool + Send>) -> Self {
+        self.notification.dismiss_when = Some(DismissCondition(Arc::new(Mutex::new(condition))));
+        self
+    }
+
+    /// Runs a callback when the notification is dismissed, e.g. to cancel
+    /// the background task it reports on.
+    ///
+    /// The manager calls it exactly once, right after removing the
+    /// notification, with its ID and why it went away. A repeat folded into
+    /// an existing notification by `coalesce` runs its callback when that
+    /// notification leaves, with that ID. An `update` bringing a callback of
+    /// its own runs the old one with `DismissReason::Replaced`. Clones of the
+    /// notification, including those from `Notifications::snapshot`, share
+    /// one callback that runs at most once between them.
+    ///
+    /// The closure must be `Send + 'static`: it cannot borrow from the
+    /// surrounding scope, so move owned values, channel senders or `Arc`s
+    /// into it.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Called with the notification's ID and `DismissReason`
+    pub fn on_dismiss(mut self, callback: Box<dyn FnMut(u64, DismissReason) + Send>) -> Self {
+        self.notification.on_dismiss = Some(DismissCallback::new(callback));
+        self
+    }
+
+    /// Sets the notification's kind, allowing one notification per kind.
+    ///
+    /// Adding a notification whose kind matches one already showing either
+    /// replaces it or updates it in place, per `Notifications::kind_mode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Identifies the notification's purpose, e.g. `"saving"`
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.notification.kind = Some(kind.into());
+        self
+    }
+
+    /// Sets the outline shape.
+    ///
+    /// `Shape::Pill` draws a single row with rounded ends, showing only the
+    /// first content line; borders, title and padding rows are ignored.
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.notification.shape = shape;
+        self
+    }
+
+    /// Makes this a progress notification starting at `progress`.
+    ///
+    /// A gauge is drawn along the bottom row of the box (pills skip it).
+    /// Values are clamped to `0.0..=1.0`. Update it with
+    /// `Notifications::set_progress`; reaching `1.0` starts the exit unless
+    /// the notification is `sticky`, or after `progress_linger` if set.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - Completion fraction, e.g. `0.25` for a quarter done
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.notification.progress = Some(progress.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Disables the dismiss timer, keeping the notification until it is removed.
+    ///
+    /// Unlike `AutoDismiss::Never`, a sticky progress notification also
+    /// stays when progress reaches `1.0`, unless `progress_linger` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to wait for `Notifications::remove`
+    pub fn sticky(mut self, enable: bool) -> Self {
+        self.notification.sticky = enable;
+        self
+    }
+
+    /// Sets how long this notification is on screen, overriding the manager's
+    /// default display time.
+    ///
+    /// The duration covers the entry and exit animations; the dwell gets what
+    /// is left. A duration shorter than both animations together still plays
+    /// the full entry, then exits straight away. Takes precedence over
+    /// `auto_dismiss`, but not over `sticky`.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - Total time from the start of the entry to the end of the exit
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.notification.duration = Some(duration);
+        self
+    }
+
+    /// Sets the priority used when an anchor overflows `max_concurrent`.
+    ///
+    /// Overflow evicts the lowest priority at the anchor, picking among equals
+    /// by the overflow rule. When every notification there outranks this one,
+    /// `add` rejects it with `NotificationError::Outranked` instead (with
+    /// `Overflow::Queue` it waits as usual).
+    ///
+    /// # Arguments
+    ///
+    /// * `priority` - Higher survives longer; 0 by default
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.notification.priority = priority;
+        self
+    }
+
+    /// Keeps a completed progress notification up for `linger` before it exits.
+    ///
+    /// Once `Notifications::set_progress` reaches `1.0` the notification
+    /// counts `linger` down like a display time, then starts its exit. Applies
+    /// to sticky notifications too.
+    ///
+    /// # Arguments
+    ///
+    /// * `linger` - Time to show the full gauge, e.g. 800ms
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let upload = NotificationBuilder::new("Uploading report.pdf")
+    ///     .progress(0.0)
+    ///     .sticky(true)
+    ///     .progress_linger(Duration::from_millis(800))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn progress_linger(mut self, linger: Duration) -> Self {
+        self.notification.progress_linger = Some(linger);
+        self
+    }
+
+    /// Adds an action button, drawn as `[label]` on a row of its own.
+    ///
+    /// Buttons line up left to right along the bottom of the notification in
+    /// the order added; any that don't fit the width are left out. The app
+    /// handles them: look up clicks with `Notifications::action_at`.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Button text
+    /// * `id` - Returned by `action_at` when the button is under the pointer
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{ActionId, Level, NotificationBuilder};
+    ///
+    /// let prompt = NotificationBuilder::new("Upload failed")
+    ///     .level(Level::Error)
+    ///     .action("Retry", ActionId(1))
+    ///     .action("Dismiss", ActionId(2))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn action(mut self, label: impl Into<String>, id: ActionId) -> Self {
+        self.notification.actions.push((label.into(), id));
+        self
+    }
+
+    /// Binds keys to action buttons by label.
+    ///
+    /// While the notification has keyboard focus, `Notifications::handle_key`
+    /// maps a bound key to the button with that label. Bindings whose label
+    /// matches no `action` are ignored; the first match wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - `(label, key)` pairs
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use crossterm::event::KeyCode;
+    /// use ratatui_notifications::notifications::{ActionId, NotificationBuilder};
+    ///
+    /// let prompt = NotificationBuilder::new("Upload failed")
+    ///     .action("Retry", ActionId(1))
+    ///     .action("Dismiss", ActionId(2))
+    ///     .action_keys(vec![("Retry".to_string(), KeyCode::Char('r')), ("Dismiss".to_string(), KeyCode::Char('d'))])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn action_keys(mut self, keys: Vec<(String, KeyCode)>) -> Self {
+        self.notification.action_keys = keys;
+        self
+    }
+
+    /// Builds the notification, validating content size, durations and size limits.
+    ///
+    /// Empty content is valid: with no title either, the notification shows
+    /// just its icon (level or animated), e.g. as a compact status marker.
     ///
     /// # Returns
     ///
     /// * `Ok(Notification)` if validation passes
-    /// * `Err(NotificationError::ContentTooLarge)` if content exceeds limit
+    /// * `Err(NotificationError)` naming the first setting that failed
     ///
     /// # Errors
     ///
-    /// Returns error if content exceeds `MAX_CONTENT_CHARS` (1000) characters.
+    /// * `ContentTooLarge` if content exceeds `MAX_CONTENT_CHARS` (1000) characters
+    /// * `InvalidDuration` if `duration` is zero
+    /// * `InvalidSize` if an absolute `max_size`/`max_width` is zero cells
     pub fn build(self) -> Result<Notification, NotificationError> {
         // Validate content size
         let content_str = self.notification.content.to_string();
@@ -490,6 +1115,17 @@ impl NotificationBuilder {
             ));
         }
 
+        if let Some(got) = self.notification.duration.filter(Duration::is_zero) {
+            return Err(NotificationError::InvalidDuration { field: "duration", got });
+        }
+
+        let limits = [("max_width", self.notification.max_width), ("max_height", self.notification.max_height)];
+        for (field, limit) in limits {
+            if let Some(SizeConstraint::Absolute(0)) = limit {
+                return Err(NotificationError::InvalidSize { field, got: 0 });
+            }
+        }
+
         Ok(self.notification)
     }
 }
@@ -497,20 +1133,20 @@ impl NotificationBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
 
     #[test]
     fn test_default_notification_has_sensible_defaults() {
         let notification = Notification::default();
 
         // Check default values match specification
-        assert_eq!(notification.anchor, Anchor::BottomRight);
+        assert_eq!(notification.anchor, None);
+        assert_eq!(notification.anchor(), Anchor::BottomRight);
         assert_eq!(notification.animation, Animation::Slide);
         assert_eq!(notification.slide_direction, SlideDirection::Default);
         assert_eq!(notification.auto_dismiss, AutoDismiss::After(Duration::from_secs(4)));
         assert_eq!(notification.level, Some(Level::Info));
         assert_eq!(notification.title, None);
-        assert_eq!(notification.fade_effect, false);
+        assert!(!notification.fade_effect);
         assert_eq!(notification.exterior_margin, 0);
         assert_eq!(notification.max_width, Some(SizeConstraint::Percentage(0.4)));
         assert_eq!(notification.max_height, Some(SizeConstraint::Percentage(0.2)));
@@ -558,7 +1194,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(notification.anchor, Anchor::TopLeft);
+        assert_eq!(notification.anchor, Some(Anchor::TopLeft));
     }
 
     #[test]
@@ -607,6 +1243,38 @@ mod tests {
         assert_eq!(notification.auto_dismiss, AutoDismiss::Never);
     }
 
+    #[test]
+    fn test_headline_leads_a_dimmed_body() {
+        let notification = NotificationBuilder::new("3 errors in src/main.rs").headline("Build failed").build().unwrap();
+        let text = notification.display_content();
+
+        assert_eq!(notification.headline(), Some("Build failed"));
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(text.lines[0].to_string(), "Build failed");
+        assert!(text.lines[0].style.add_modifier.contains(Modifier::BOLD));
+        assert!(text.lines[1].style.add_modifier.contains(Modifier::DIM));
+
+        // Without a headline the content is drawn untouched
+        let plain = NotificationBuilder::new("3 errors").build().unwrap();
+        assert_eq!(plain.display_content(), *plain.content());
+    }
+
+    #[test]
+    fn test_builder_max_width_keeps_height_limit() {
+        let notification = NotificationBuilder::new("Test").max_width(24).build().unwrap();
+
+        assert_eq!(notification.max_width, Some(SizeConstraint::Absolute(24)));
+        assert_eq!(notification.max_height, Some(SizeConstraint::Percentage(0.2)));
+    }
+
+    #[test]
+    fn test_builder_max_height_keeps_width_limit() {
+        let notification = NotificationBuilder::new("Test").max_height(6).build().unwrap();
+
+        assert_eq!(notification.max_height, Some(SizeConstraint::Absolute(6)));
+        assert_eq!(notification.max_width, Some(SizeConstraint::Percentage(0.4)));
+    }
+
     #[test]
     fn test_builder_sets_max_size() {
         let notification = NotificationBuilder::new("Test")
@@ -717,7 +1385,204 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(notification.fade_effect, true);
+        assert!(notification.fade_effect);
+    }
+
+    #[test]
+    fn test_builder_sets_protected() {
+        let notification = NotificationBuilder::new("Test")
+            .protected(true)
+            .build()
+            .unwrap();
+
+        assert!(notification.protected);
+        assert!(!Notification::default().protected);
+    }
+
+    #[test]
+    fn test_builder_sets_animated_icon() {
+        let frames = vec!["◐".to_string(), "◓".to_string()];
+
+        let notification = NotificationBuilder::new("Test")
+            .animated_icon(frames.clone(), Duration::from_millis(120))
+            .build()
+            .unwrap();
+
+        assert_eq!(notification.animated_icon, Some((frames, Duration::from_millis(120))));
+    }
+
+    #[test]
+    fn test_builder_text_replaces_content() {
+        let text = Text::from(vec![Line::from("First"), Line::from(Span::styled("Second", Style::new().red()))]);
+        let notification = NotificationBuilder::new("Ignored").text(text.clone()).build().unwrap();
+
+        assert_eq!(notification.content(), &text);
+    }
+
+    #[test]
+    fn test_builder_level_lines_styles_each_line() {
+        let notification = NotificationBuilder::new("Ignored")
+            .level_lines(vec![
+                (Level::Info, "synced".to_string()),
+                (Level::Warn, "skipped".to_string()),
+                (Level::Error, "failed".to_string()),
+            ])
+            .build()
+            .unwrap();
+
+        let lines = &notification.content().lines;
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].to_string(), "synced");
+        assert_eq!(lines[0].style.fg, Some(Color::Green));
+        assert_eq!(lines[1].style.fg, Some(Color::Yellow));
+        assert_eq!(lines[2].style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_builder_borderless_clears_border() {
+        let notification = NotificationBuilder::new("Test").borderless().build().unwrap();
+
+        assert_eq!(notification.border_type(), None);
+        assert!(notification.border_explicit);
+        assert!(!Notification::default().border_explicit);
+    }
+
+    #[test]
+    fn test_builder_sets_priority() {
+        let notification = NotificationBuilder::new("Test").priority(9).build().unwrap();
+
+        assert_eq!(notification.priority(), 9);
+        assert_eq!(Notification::default().priority(), 0);
+    }
+
+    #[test]
+    fn test_builder_sets_duration() {
+        let notification = NotificationBuilder::new("Test").duration(Duration::from_secs(8)).build().unwrap();
+
+        assert_eq!(notification.duration(), Some(Duration::from_secs(8)));
+        assert_eq!(Notification::default().duration(), None);
+    }
+
+    #[test]
+    fn test_builder_appends_actions_in_order() {
+        let notification = NotificationBuilder::new("Upload failed")
+            .action("Retry", ActionId(1))
+            .action("Dismiss", ActionId(2))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            notification.actions(),
+            [("Retry".to_string(), ActionId(1)), ("Dismiss".to_string(), ActionId(2))]
+        );
+        assert!(Notification::default().actions().is_empty());
+    }
+
+    #[test]
+    fn test_builder_sets_sticky_and_progress_linger() {
+        let notification = NotificationBuilder::new("Uploading")
+            .sticky(true)
+            .progress_linger(Duration::from_millis(800))
+            .build()
+            .unwrap();
+
+        assert!(notification.sticky());
+        assert_eq!(notification.progress_linger(), Some(Duration::from_millis(800)));
+        assert!(!Notification::default().sticky());
+        assert_eq!(Notification::default().progress_linger(), None);
+    }
+
+    #[test]
+    fn test_builder_sets_easing() {
+        let notification = NotificationBuilder::new("Test").easing(Easing::EaseOutCubic).build().unwrap();
+
+        assert_eq!(notification.easing(), Easing::EaseOutCubic);
+        assert_eq!(Notification::default().easing(), Easing::Linear);
+    }
+
+    #[test]
+    fn test_builder_sets_kind() {
+        let notification = NotificationBuilder::new("Test").kind("saving").build().unwrap();
+
+        assert_eq!(notification.kind(), Some("saving"));
+        assert_eq!(Notification::default().kind(), None);
+    }
+
+    #[test]
+    fn test_builder_sets_progress_clamped() {
+        let notification = NotificationBuilder::new("Test").progress(0.25).build().unwrap();
+        let overfull = NotificationBuilder::new("Test").progress(1.5).build().unwrap();
+
+        assert_eq!(notification.progress(), Some(0.25));
+        assert_eq!(overfull.progress(), Some(1.0));
+        assert_eq!(Notification::default().progress(), None);
+    }
+
+    #[test]
+    fn test_builder_sets_shape() {
+        let notification = NotificationBuilder::new("Test").shape(Shape::Pill).build().unwrap();
+
+        assert_eq!(notification.shape(), Shape::Pill);
+        assert_eq!(Notification::default().shape(), Shape::Box);
+    }
+
+    #[test]
+    fn test_builder_sets_new_badge() {
+        let notification = NotificationBuilder::new("Test").new_badge(true).build().unwrap();
+
+        assert!(notification.new_badge);
+        assert!(!Notification::default().new_badge);
+    }
+
+    #[test]
+    fn test_builder_sets_time_color() {
+        let notification = NotificationBuilder::new("Test")
+            .time_color(Color::Green, Color::Red)
+            .build()
+            .unwrap();
+
+        assert_eq!(notification.time_color, Some((Color::Green, Color::Red)));
+    }
+
+    #[test]
+    fn test_builder_sets_dismiss_when() {
+        let notification = NotificationBuilder::new("Offline")
+            .dismiss_when(Box::new(|| true))
+            .build()
+            .unwrap();
+
+        assert!(notification.dismiss_when.as_ref().is_some_and(DismissCondition::is_met));
+        assert!(Notification::default().dismiss_when.is_none());
+    }
+
+    #[test]
+    fn test_builder_sets_on_dismiss() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&calls);
+        let notification = NotificationBuilder::new("Upload")
+            .on_dismiss(Box::new(move |id, reason| sink.lock().unwrap().push((id, reason))))
+            .build()
+            .unwrap();
+
+        notification.on_dismiss.as_ref().unwrap().call(7, DismissReason::Manual);
+        assert_eq!(*calls.lock().unwrap(), vec![(7, DismissReason::Manual)]);
+        assert!(Notification::default().on_dismiss.is_none());
+    }
+
+    #[test]
+    fn test_on_dismiss_runs_once_across_clones() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&calls);
+        let notification = NotificationBuilder::new("Upload")
+            .on_dismiss(Box::new(move |id, reason| sink.lock().unwrap().push((id, reason))))
+            .build()
+            .unwrap();
+        let copy = notification.clone();
+
+        notification.on_dismiss.as_ref().unwrap().call(1, DismissReason::Cleared);
+        copy.on_dismiss.as_ref().unwrap().call(2, DismissReason::Manual);
+        notification.on_dismiss.as_ref().unwrap().call(1, DismissReason::Expired);
+        assert_eq!(*calls.lock().unwrap(), vec![(1, DismissReason::Cleared)]);
     }
 
     #[test]
@@ -758,7 +1623,7 @@ mod tests {
         assert_eq!(notification.content.to_string(), "Full config test");
         assert_eq!(notification.title.unwrap().to_string(), "Test Title");
         assert_eq!(notification.level, Some(Level::Warn));
-        assert_eq!(notification.anchor, Anchor::TopCenter);
+        assert_eq!(notification.anchor, Some(Anchor::TopCenter));
         assert_eq!(notification.animation, Animation::ExpandCollapse);
         assert_eq!(notification.slide_direction, SlideDirection::FromBottom);
         assert_eq!(notification.auto_dismiss, AutoDismiss::After(Duration::from_secs(5)));
@@ -772,7 +1637,7 @@ mod tests {
         assert_eq!(notification.border_type, Some(BorderType::Thick));
         assert_eq!(notification.custom_entry_position, Some(entry_pos));
         assert_eq!(notification.custom_exit_position, Some(exit_pos));
-        assert_eq!(notification.fade_effect, true);
+        assert!(notification.fade_effect);
     }
 
     #[test]
@@ -802,6 +1667,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_rejects_zero_duration() {
+        let result = NotificationBuilder::new("Saved").duration(Duration::ZERO).build();
+
+        let error = result.unwrap_err();
+        assert_eq!(error, NotificationError::InvalidDuration { field: "duration", got: Duration::ZERO });
+        assert_eq!(error.to_string(), "Invalid duration: 0ns must be greater than zero");
+    }
+
+    #[test]
+    fn test_build_rejects_zero_size_limits() {
+        let narrow = NotificationBuilder::new("Saved").max_width(0).build();
+        assert_eq!(narrow.unwrap_err(), NotificationError::InvalidSize { field: "max_width", got: 0 });
+
+        let flat = NotificationBuilder::new("Saved")
+            .max_size(SizeConstraint::Percentage(0.5), SizeConstraint::Absolute(0))
+            .build();
+        let error = flat.unwrap_err();
+        assert_eq!(error, NotificationError::InvalidSize { field: "max_height", got: 0 });
+        assert_eq!(error.to_string(), "Invalid max_height: 0 cells is too small, must be at least 1");
+    }
+
+    #[test]
+    fn test_content_too_large_message_counts_characters() {
+        let error = NotificationBuilder::new("é".repeat(1001)).build().unwrap_err();
+
+        assert_eq!(error.to_string(), "Content too large: 1001 characters exceeds limit of 1000 characters");
+        let _: &dyn std::error::Error = &error;
+    }
+
     #[test]
     fn test_content_validation_at_boundary() {
         // Exactly 1000 chars should be accepted
@@ -877,4 +1772,4 @@ mod tests {
 }
 
 // FILE: src/notifications/classes/cls_notification.rs - Notification class with builder
-// END OF VERSION: 2.2.0
+// END OF VERSION: 2.28.0
@@ -0,0 +1,71 @@
+// FILE: src/notifications/classes/cls_notification_handle.rs - Typed notification handle
+// VERSION: 1.0.0
+// WCTX: Typed notification handles
+// CLOG: Initial creation
+
+use crate::notifications::orc_manager::Notifications;
+
+/// A notification ID tied to the manager that issued it.
+///
+/// Returned by `Notifications::add_handle`. Bare `u64` IDs from two
+/// managers can collide, so an ID from one may silently hit an unrelated
+/// notification in the other. A handle only ever matches in its own
+/// manager and is a no-op anywhere else. `id()` gives the raw ID for the
+/// `u64`-based methods such as `update`.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+///
+/// let mut manager = Notifications::new();
+/// let handle = manager.add_handle(NotificationBuilder::new("Saving...").build().unwrap()).unwrap();
+///
+/// assert!(handle.is_active(&manager));
+/// handle.dismiss(&mut manager);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotificationHandle {
+    pub(crate) id: u64,
+    pub(crate) manager: u64,
+}
+
+impl NotificationHandle {
+    /// Returns the raw notification ID.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Whether the notification is still in `manager`, including while it
+    /// animates out.
+    ///
+    /// # Returns
+    /// `false` once it has been removed, or if `manager` did not issue this handle
+    pub fn is_active(&self, manager: &Notifications) -> bool {
+        manager.owns(*self)
+    }
+
+    /// Starts the notification's exit animation, like `Notifications::dismiss`.
+    ///
+    /// # Returns
+    /// `false` if it is already gone or `manager` did not issue this handle
+    pub fn dismiss(&self, manager: &mut Notifications) -> bool {
+        manager.owns(*self) && manager.dismiss(self.id)
+    }
+
+    /// Removes the notification at once, like `Notifications::remove`.
+    ///
+    /// # Returns
+    /// `false` if it is already gone or `manager` did not issue this handle
+    pub fn remove(&self, manager: &mut Notifications) -> bool {
+        manager.owns(*self) && manager.remove(self.id)
+    }
+}
+
+impl From<NotificationHandle> for u64 {
+    fn from(handle: NotificationHandle) -> Self {
+        handle.id
+    }
+}
+
+// FILE: src/notifications/classes/cls_notification_handle.rs - Typed notification handle
+// END OF VERSION: 1.0.0
@@ -0,0 +1,103 @@
+// FILE: src/notifications/classes/cls_notification_layer.rs - tracing layer feeding notifications
+// VERSION: 1.0.0
+// WCTX: Bridging tracing events
+// CLOG: Initial creation
+
+use std::fmt::Write as _;
+use std::sync::mpsc::Sender;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::notifications::classes::{Notification, NotificationBuilder};
+use crate::notifications::types::Level;
+
+/// A `tracing_subscriber` layer that turns events into notifications.
+///
+/// `ERROR`, `WARN` and `INFO` events map to `Level::Error`, `Level::Warn`
+/// and `Level::Info`; `DEBUG` and `TRACE` events are ignored. The message
+/// is followed by the event's other fields as `key=value` pairs.
+///
+/// The layer never touches the manager: events may fire on any thread, so
+/// each notification is sent down a channel and only added when the UI
+/// thread calls `Notifications::drain_pending`. Created with
+/// `Notifications::tracing_layer`.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::Notifications;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let mut notifications = Notifications::new();
+/// let subscriber = tracing_subscriber::registry().with(notifications.tracing_layer());
+/// tracing::subscriber::set_global_default(subscriber).unwrap();
+///
+/// // In the render loop, on the UI thread:
+/// notifications.drain_pending();
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotificationLayer {
+    sender: Sender<Notification>,
+}
+
+impl NotificationLayer {
+    pub(crate) fn new(sender: Sender<Notification>) -> Self {
+        Self { sender }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for NotificationLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let level = match *event.metadata().level() {
+            tracing::Level::ERROR => Level::Error,
+            tracing::Level::WARN => Level::Warn,
+            tracing::Level::INFO => Level::Info,
+            _ => return,
+        };
+
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+
+        // Oversized messages are dropped rather than reported; a closed
+        // channel just means the manager is gone
+        if let Ok(notification) = NotificationBuilder::new(message.into_text()).level(level).build() {
+            let _ = self.sender.send(notification);
+        }
+    }
+}
+
+/// Collects the `message` field first and the remaining fields after it.
+#[derive(Default)]
+struct MessageVisitor {
+    text: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn into_text(self) -> String {
+        format!("{}{}", self.text, self.fields).trim_start().to_string()
+    }
+
+    fn push(&mut self, field: &Field, value: std::fmt::Arguments<'_>) {
+        if field.name() == "message" {
+            let _ = write!(self.text, "{value}");
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.push(field, format_args!("{value:?}"));
+    }
+}
+
+// FILE: src/notifications/classes/cls_notification_layer.rs - tracing layer feeding notifications
+// END OF VERSION: 1.0.0
@@ -1,10 +1,12 @@
 // FILE: src/notifications/classes/cls_notification_state.rs - NotificationState internal state management
-// VERSION: 1.2.0
-// WCTX: Fixing custom entry/exit position handling
-// CLOG: Copy custom_entry_position and custom_exit_position from Notification to NotificationState; Added tests
+// VERSION: 1.36.1
+// WCTX: Dismiss callbacks run once
+// CLOG: Run replaced dismiss callback on update
 
 use super::cls_notification::Notification;
-use crate::notifications::types::{AnimationPhase, Timing, AutoDismiss};
+use crate::notifications::functions::fnc_apply_easing::apply_easing;
+use crate::notifications::types::{ActionId, Anchor, AnimationPhase, DismissReason, Easing, Timing, AutoDismiss};
+use crate::shared_utils::math::lerp;
 use ratatui::prelude::*;
 use std::time::{Duration, Instant};
 
@@ -31,12 +33,34 @@ impl Default for ManagerDefaults {
     }
 }
 
-/// Internal state for a single notification (pub(crate)).
+/// Movement from an old stack position to a new one.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReflowMotion {
+    /// Where the notification was shown when the move started
+    pub(crate) from: Rect,
+    pub(crate) elapsed: Duration,
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+}
+
+/// Gauge fill easing from the value shown when `set_progress` was called
+/// toward the notification's new progress.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProgressMotion {
+    /// Fill shown when the change started
+    pub(crate) from: f32,
+    pub(crate) elapsed: Duration,
+    pub(crate) duration: Duration,
+    pub(crate) easing: Easing,
+}
+
+/// Live state for a single notification held by the manager.
 ///
 /// Tracks animation progress, timing, and lifecycle for a notification
-/// being managed by the AnimatedNotificationManager.
+/// being managed by `Notifications`. Read-only outside the crate; obtain it
+/// from `Notifications::iter`.
 #[derive(Debug)]
-pub(crate) struct NotificationState {
+pub struct NotificationState {
     /// Unique identifier for this notification
     pub(crate) id: u64,
 
@@ -49,19 +73,79 @@ pub(crate) struct NotificationState {
     /// Current animation phase
     pub(crate) current_phase: AnimationPhase,
 
+    /// Total time this notification has been animating
+    pub(crate) elapsed: Duration,
+
     /// Progress through current animation (0.0 to 1.0)
     pub(crate) animation_progress: f32,
 
     /// Target position/size (updated by render)
     pub(crate) full_rect: Rect,
 
+    /// Size measured ahead of render, with the frame area it was measured for
+    pub(crate) layout_cache: Option<(Rect, (u16, u16))>,
+
+    /// Content wrapped to the inner width it was wrapped at
+    pub(crate) wrap_cache: Option<(u16, Text<'static>)>,
+
+    /// Whether the notification draws; timers run either way
+    pub(crate) visible: bool,
+
+    /// Cell a connector line is drawn toward, if any
+    pub(crate) pointer: Option<Position>,
+
+    /// Whether the notification is kept at the anchor end of its stack
+    pub(crate) pinned_top: bool,
+
+    /// Whether the dismiss countdown is held (animations keep running)
+    pub(crate) frozen: bool,
+
+    /// How many times this notification was added while showing (at least 1)
+    pub(crate) repeat_count: u32,
+
+    /// Whether progress has reached `1.0` and the completion was handled
+    pub(crate) progress_completed: bool,
+
+    /// Why the notification is exiting, reported to its dismiss callback
+    pub(crate) dismiss_reason: DismissReason,
+
+    /// In-progress move to a new stack position after a reflow
+    pub(crate) reflow: Option<ReflowMotion>,
+
+    /// In-progress gauge fill toward a new progress value
+    pub(crate) progress_motion: Option<ProgressMotion>,
+
+    /// Entry animation used instead of the notification's own, if set
+    pub(crate) entry_animation: Option<crate::notifications::types::Animation>,
+
+    /// Drawn as a motionless one-row pill while the manager is degraded
+    pub(crate) compact: bool,
+
+    /// Drawn without colour, with the level as a text tag instead
+    pub(crate) monochrome: bool,
+
+    /// The notification with its level tag written in, while monochrome;
+    /// rebuilt by `reset_layout`
+    tagged: Option<Notification>,
+
+    /// Where the action buttons were drawn in the last render
+    pub(crate) action_rects: Vec<(Rect, ActionId)>,
+
+    /// Anchor the stack was last laid out from, when it differs from the
+    /// notification's own (see `Notifications::stack_direction`)
+    pub(crate) baseline_anchor: Option<Anchor>,
+
     /// Remaining time until auto-dismiss (if applicable)
     pub(crate) remaining_display_time: Option<Duration>,
 
+    /// Resolved auto-dismiss duration the countdown starts from
+    pub(crate) display_time: Option<Duration>,
+
     /// Resolved entry animation duration
     pub(crate) actual_entry_duration: Duration,
 
     /// Resolved dwell duration
+    #[allow(dead_code)]
     pub(crate) actual_dwell_duration: Duration,
 
     /// Resolved exit animation duration
@@ -75,6 +159,38 @@ pub(crate) struct NotificationState {
 }
 
 impl NotificationState {
+    /// Returns the notification being shown.
+    pub fn notification(&self) -> &Notification {
+        &self.notification
+    }
+
+    /// Returns the current animation phase.
+    pub fn phase(&self) -> AnimationPhase {
+        self.current_phase
+    }
+
+    /// Returns the current entry or exit progress mapped through the
+    /// notification's easing curve; this is the factor positions and fades
+    /// are interpolated with. `0.0` while dwelling.
+    pub fn eased_progress(&self) -> f32 {
+        apply_easing(self.notification.easing, self.animation_progress)
+    }
+
+    /// Returns the display time left before the exit starts, or `None` for
+    /// notifications with `AutoDismiss::Never`. Counts down only while dwelling.
+    pub fn remaining_display_time(&self) -> Option<Duration> {
+        self.remaining_display_time
+    }
+
+    /// Returns the resolved `(entry, display, exit)` durations.
+    ///
+    /// `Timing::Auto` and a zero `AutoDismiss::After` are resolved against the
+    /// manager's defaults. The display time is `None` when the notification
+    /// never dismisses itself.
+    pub fn phase_durations(&self) -> (Duration, Option<Duration>, Duration) {
+        (self.actual_entry_duration, self.display_time, self.actual_exit_duration)
+    }
+
     /// Creates a new notification state.
     ///
     /// Resolves all timing durations based on the notification's configuration
@@ -85,28 +201,8 @@ impl NotificationState {
     /// * `notification` - The notification configuration
     /// * `defaults` - Manager-level default durations
     pub(crate) fn new(id: u64, notification: Notification, defaults: &ManagerDefaults) -> Self {
-        // Resolve actual durations from Timing enum
-        let actual_entry_duration = match notification.slide_in_timing {
-            Timing::Fixed(d) => d,
-            Timing::Auto => defaults.default_entry_duration,
-        };
-
-        let actual_dwell_duration = match notification.dwell_timing {
-            Timing::Fixed(d) => d,
-            Timing::Auto => defaults.default_dwell_duration,
-        };
-
-        let actual_exit_duration = match notification.slide_out_timing {
-            Timing::Fixed(d) => d,
-            Timing::Auto => defaults.default_exit_duration,
-        };
-
-        // Resolve remaining display time from AutoDismiss
-        let remaining_display_time = match notification.auto_dismiss {
-            AutoDismiss::Never => None,
-            AutoDismiss::After(d) if d > Duration::ZERO => Some(d),
-            AutoDismiss::After(_) => Some(defaults.default_display_time),
-        };
+        let (actual_entry_duration, actual_dwell_duration, actual_exit_duration, remaining_display_time) =
+            resolve_durations(&notification, defaults);
 
         // Copy custom positions from notification (convert Position to (f32, f32))
         let custom_entry_pos = notification
@@ -121,9 +217,28 @@ impl NotificationState {
             notification,
             created_at: Instant::now(),
             current_phase: AnimationPhase::Pending,
+            elapsed: Duration::ZERO,
             animation_progress: 0.0,
             full_rect: Rect::default(),
+            layout_cache: None,
+            wrap_cache: None,
+            visible: true,
+            pointer: None,
+            pinned_top: false,
+            frozen: false,
+            repeat_count: 1,
+            progress_completed: false,
+            dismiss_reason: DismissReason::default(),
+            reflow: None,
+            progress_motion: None,
+            entry_animation: None,
+            compact: false,
+            monochrome: false,
+            tagged: None,
+            action_rects: Vec::new(),
+            baseline_anchor: None,
             remaining_display_time,
+            display_time: remaining_display_time,
             actual_entry_duration,
             actual_dwell_duration,
             actual_exit_duration,
@@ -132,6 +247,268 @@ impl NotificationState {
         }
     }
 
+    /// Wraps and measures the content now, caching the size for `frame_area`
+    /// and the content wrapped to the inner width of that size.
+    ///
+    /// Stacking reuses the cached size as long as the render area has the
+    /// same dimensions, and rendering reuses the wrapped lines as long as the
+    /// inner width matches; either is redone otherwise.
+    pub(crate) fn precompute_layout(&mut self, frame_area: Rect) {
+        use crate::notifications::functions::fnc_wrap_text::wrap_text;
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let size = self.measure(frame_area);
+        self.layout_cache = Some((frame_area, size));
+
+        // Pills draw their first line unwrapped
+        if self.shape() == crate::notifications::types::Shape::Pill {
+            return;
+        }
+        let shown = self.tagged.as_ref().unwrap_or(&self.notification);
+        let border = if shown.border_type.is_some() { 2 } else { 0 };
+        let inner_width = size.0.saturating_sub(border + shown.padding.left + shown.padding.right);
+        self.wrap_cache = Some((inner_width, wrap_text(&self.content(), inner_width)));
+    }
+
+    /// Measures the notification as drawn: tagged in monochrome mode and as a
+    /// pill in compact mode.
+    fn measure(&self, frame_area: Rect) -> (u16, u16) {
+        use crate::notifications::functions::fnc_calculate_size::calculate_size;
+
+        let shown = self.tagged.as_ref().unwrap_or(&self.notification);
+        if self.compact {
+            let pill = Notification { shape: crate::notifications::types::Shape::Pill, ..shown.clone() };
+            return calculate_size(&pill, frame_area);
+        }
+        calculate_size(shown, frame_area)
+    }
+
+    /// Forgets the stacked rect, cached size and wrapped lines so the next
+    /// render lays the notification out again, and rebuilds its monochrome
+    /// tagged copy.
+    pub(crate) fn reset_layout(&mut self) {
+        self.full_rect = Rect::default();
+        self.layout_cache = None;
+        self.wrap_cache = None;
+        self.tagged = self.tagged_notification();
+    }
+
+    /// Switches compact rendering on or off, re-measuring if it changed.
+    pub(crate) fn set_compact(&mut self, compact: bool) {
+        if self.compact != compact {
+            self.compact = compact;
+            self.reset_layout();
+        }
+    }
+
+    /// Switches monochrome rendering on or off, re-measuring if it changed.
+    pub(crate) fn set_monochrome(&mut self, monochrome: bool) {
+        if self.monochrome != monochrome {
+            self.monochrome = monochrome;
+            self.reset_layout();
+        }
+    }
+
+    /// The notification with its level tag written in, as drawn in
+    /// monochrome mode: at the start of the title, or of the first message
+    /// line when there is no title or it is drawn as a pill.
+    fn tagged_notification(&self) -> Option<Notification> {
+        use crate::notifications::types::{Level, Shape};
+
+        let level = self.notification.level.filter(|_| self.monochrome)?;
+        let tag = match level {
+            Level::Info => "[INFO]",
+            Level::Success => "[OK]",
+            Level::Warn => "[WARN]",
+            Level::Error => "[ERROR]",
+            Level::Debug => "[DEBUG]",
+            Level::Trace => "[TRACE]",
+        };
+
+        let mut tagged = self.notification.clone();
+        let pill = self.compact || tagged.shape == Shape::Pill;
+        if let Some(title) = tagged.title.as_mut().filter(|_| !pill) {
+            title.spans.insert(0, Span::raw(format!(" {tag} ")));
+        } else if let Some(headline) = tagged.headline.as_mut() {
+            *headline = format!("{tag} {headline}");
+        } else if let Some(line) = tagged.content.lines.first_mut() {
+            line.spans.insert(0, Span::raw(format!("{tag} ")));
+        } else {
+            tagged.content.lines.push(Line::raw(tag));
+        }
+        Some(tagged)
+    }
+
+    /// Returns whether the notification is playing its exit animation.
+    pub(crate) fn is_exiting(&self) -> bool {
+        matches!(
+            self.current_phase,
+            AnimationPhase::SlidingOut | AnimationPhase::FadingOut | AnimationPhase::Collapsing
+        )
+    }
+
+    /// Starts the exit animation now, without waiting for the dismiss timer.
+    ///
+    /// A notification that never started (still pending) finishes at once;
+    /// one already exiting or finished is left alone.
+    pub(crate) fn begin_exit(&mut self) {
+        use crate::notifications::types::Animation;
+
+        match self.current_phase {
+            AnimationPhase::Pending => self.current_phase = AnimationPhase::Finished,
+            AnimationPhase::Finished => {}
+            _ if self.is_exiting() => {}
+            _ => {
+                self.current_phase = match self.without_motion(self.notification.animation) {
+                    Animation::Slide => AnimationPhase::SlidingOut,
+                    Animation::ExpandCollapse | Animation::Collapse => AnimationPhase::Collapsing,
+                    Animation::Fade => AnimationPhase::FadingOut,
+                };
+                self.animation_progress = 0.0;
+            }
+        }
+    }
+
+    /// Starts the exit like `begin_exit`, recording `reason` for the dismiss
+    /// callback unless the notification is already on its way out.
+    pub(crate) fn begin_dismiss(&mut self, reason: DismissReason) {
+        if !self.is_exiting() && self.current_phase != AnimationPhase::Finished {
+            self.dismiss_reason = reason;
+        }
+        self.begin_exit();
+    }
+
+    /// Moves a pending notification into the first frame of its entry animation.
+    pub(crate) fn start_entry(&mut self) {
+        use crate::notifications::types::Animation;
+
+        if self.current_phase == AnimationPhase::Pending {
+            self.current_phase = match self.animation() {
+                Animation::Slide => AnimationPhase::SlidingIn,
+                Animation::ExpandCollapse | Animation::Collapse => AnimationPhase::Expanding,
+                Animation::Fade => AnimationPhase::FadingIn,
+            };
+            self.animation_progress = 0.0;
+        }
+    }
+
+    /// Moves a pending notification straight to dwelling, skipping its entry animation.
+    pub(crate) fn skip_entry(&mut self) {
+        if self.current_phase == AnimationPhase::Pending {
+            self.current_phase = AnimationPhase::Dwelling;
+            self.animation_progress = 0.0;
+        }
+    }
+
+    /// Reacts to progress reaching `1.0`: exits now, counts down the
+    /// notification's linger first, or (sticky without a linger) stays.
+    /// Only the first completion counts, so repeated `1.0` updates never
+    /// restart the linger.
+    pub(crate) fn complete_progress(&mut self) {
+        if self.progress_completed || self.is_exiting() || self.current_phase == AnimationPhase::Finished {
+            return;
+        }
+        self.progress_completed = true;
+
+        match self.notification.progress_linger {
+            Some(linger) if !linger.is_zero() => {
+                self.dismiss_reason = DismissReason::Manual;
+                self.remaining_display_time = Some(linger);
+                self.display_time = Some(linger);
+            }
+            None if self.notification.sticky => {}
+            _ => self.begin_dismiss(DismissReason::Manual),
+        }
+    }
+
+    /// Sets the progress fraction. With `easing`, the drawn fill moves from
+    /// the value shown now to the new one instead of jumping.
+    pub(crate) fn set_progress(&mut self, progress: f32, easing: Option<(Duration, Easing)>) {
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let shown = self.progress();
+        // A gauge appearing adds a row, so measure again
+        if self.notification.progress.replace(progress).is_none() {
+            self.reset_layout();
+        }
+        self.progress_motion = easing
+            .zip(shown)
+            .filter(|((duration, _), _)| !duration.is_zero())
+            .map(|((duration, easing), from)| ProgressMotion { from, elapsed: Duration::ZERO, duration, easing });
+    }
+
+    /// Swaps in an updated notification while keeping the anchor and
+    /// animation phase. Durations are resolved again from the new
+    /// notification, and a dwelling one gets a full countdown of its new
+    /// display time. The old dismiss callback carries over, unless the new
+    /// notification brings its own: then the old one runs as `Replaced`.
+    pub(crate) fn update_notification(&mut self, mut notification: Notification, defaults: &ManagerDefaults) {
+        notification.anchor = self.notification.anchor;
+        match (self.notification.on_dismiss.take(), &notification.on_dismiss) {
+            (Some(old), Some(_)) => old.call(self.id, DismissReason::Replaced),
+            (old, None) => notification.on_dismiss = old,
+            (None, Some(_)) => {}
+        }
+        let (entry, dwell, exit, display_time) = resolve_durations(&notification, defaults);
+        self.actual_entry_duration = entry;
+        self.actual_dwell_duration = dwell;
+        self.actual_exit_duration = exit;
+        self.display_time = display_time;
+        self.notification = notification;
+        self.reset_layout();
+        match self.current_phase {
+            AnimationPhase::Dwelling => self.restart_dwell(),
+            _ if !self.is_exiting() => self.remaining_display_time = display_time,
+            _ => {}
+        }
+    }
+
+    /// Animation driving the current phase: the entry override while
+    /// entering, otherwise the notification's own.
+    pub(crate) fn animation(&self) -> crate::notifications::types::Animation {
+        let animation = match self.entry_animation {
+            Some(animation)
+                if matches!(
+                    self.current_phase,
+                    AnimationPhase::Pending
+                        | AnimationPhase::SlidingIn
+                        | AnimationPhase::FadingIn
+                        | AnimationPhase::Expanding
+                ) =>
+            {
+                animation
+            }
+            _ => self.notification.animation,
+        };
+        self.without_motion(animation)
+    }
+
+    /// Plays `Slide` as a fade when the slide direction is `SlideDirection::None`.
+    fn without_motion(&self, animation: crate::notifications::types::Animation) -> crate::notifications::types::Animation {
+        use crate::notifications::types::{Animation, SlideDirection};
+
+        match animation {
+            Animation::Slide if self.notification.slide_direction == SlideDirection::None => Animation::Fade,
+            animation => animation,
+        }
+    }
+
+    /// Counts another arrival of the same notification, giving it a full
+    /// countdown again if it is already dwelling.
+    pub(crate) fn repeat(&mut self) {
+        self.repeat_count = self.repeat_count.saturating_add(1);
+        if self.current_phase == AnimationPhase::Dwelling {
+            self.restart_dwell();
+        }
+    }
+
+    /// Returns to dwelling with a full auto-dismiss countdown.
+    pub(crate) fn restart_dwell(&mut self) {
+        self.current_phase = AnimationPhase::Dwelling;
+        self.animation_progress = 0.0;
+        self.remaining_display_time = self.display_time;
+    }
+
     /// Updates the notification state based on elapsed time.
     ///
     /// Advances animation phases and progress based on timing configuration.
@@ -141,18 +518,27 @@ impl NotificationState {
     /// # Arguments
     /// * `delta` - Time elapsed since last update
     pub(crate) fn update(&mut self, delta: Duration) {
-        use crate::notifications::types::Animation;
+        self.elapsed = self.elapsed.saturating_add(delta);
 
-        // Start animation if still pending
-        if self.current_phase == AnimationPhase::Pending {
-            self.current_phase = match self.notification.animation {
-                Animation::Slide => AnimationPhase::SlidingIn,
-                Animation::ExpandCollapse => AnimationPhase::Expanding,
-                Animation::Fade => AnimationPhase::FadingIn,
-            };
-            self.animation_progress = 0.0;
+        // Advance any stack reflow, dropping it once it arrives
+        if let Some(motion) = self.reflow.as_mut() {
+            motion.elapsed = motion.elapsed.saturating_add(delta);
+            if motion.elapsed >= motion.duration {
+                self.reflow = None;
+            }
         }
 
+        // Advance any gauge fill the same way
+        if let Some(motion) = self.progress_motion.as_mut() {
+            motion.elapsed = motion.elapsed.saturating_add(delta);
+            if motion.elapsed >= motion.duration {
+                self.progress_motion = None;
+            }
+        }
+
+        // Start animation if still pending
+        self.start_entry();
+
         // Update animation progress for entry/exit phases (NOT dwelling)
         let phase_duration = match self.current_phase {
             AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding => {
@@ -198,18 +584,13 @@ impl NotificationState {
             }
         }
 
-        // Handle dwelling phase timer (separate from animation progress)
-        if self.current_phase == AnimationPhase::Dwelling {
+        // Handle dwelling phase timer (separate from animation progress), unless frozen
+        if self.current_phase == AnimationPhase::Dwelling && !self.frozen {
             if let Some(remaining) = self.remaining_display_time.as_mut() {
                 *remaining = remaining.saturating_sub(delta);
                 if remaining.is_zero() {
                     // Timer expired, transition to exit animation
-                    self.current_phase = match self.notification.animation {
-                        Animation::Slide => AnimationPhase::SlidingOut,
-                        Animation::ExpandCollapse => AnimationPhase::Collapsing,
-                        Animation::Fade => AnimationPhase::FadingOut,
-                    };
-                    self.animation_progress = 0.0;
+                    self.begin_exit();
                 }
             }
             // If remaining_display_time is None, notification stays dwelling indefinitely
@@ -240,8 +621,51 @@ impl crate::notifications::orc_stacking::StackableNotification for NotificationS
     }
 
     fn calculate_content_size(&self, frame_area: ratatui::prelude::Rect) -> (u16, u16) {
-        crate::notifications::functions::fnc_calculate_size::calculate_size(&self.notification, frame_area)
+        match self.layout_cache {
+            Some((area, size)) if area.as_size() == frame_area.as_size() => size,
+            _ => self.measure(frame_area),
+        }
     }
+
+    fn is_pinned_top(&self) -> bool {
+        self.pinned_top
+    }
+}
+
+/// Resolves a notification's (entry, dwell, exit) durations and display
+/// time against the manager defaults.
+///
+/// A fixed duration leaves the dwell whatever the animations don't use
+/// (never cutting into the entry), otherwise AutoDismiss applies. Sticky
+/// notifications have no display time.
+fn resolve_durations(
+    notification: &Notification,
+    defaults: &ManagerDefaults,
+) -> (Duration, Duration, Duration, Option<Duration>) {
+    let entry = match notification.slide_in_timing {
+        Timing::Fixed(d) => d,
+        Timing::Auto => defaults.default_entry_duration,
+    };
+
+    let dwell = match notification.dwell_timing {
+        Timing::Fixed(d) => d,
+        Timing::Auto => defaults.default_dwell_duration,
+    };
+
+    let exit = match notification.slide_out_timing {
+        Timing::Fixed(d) => d,
+        Timing::Auto => defaults.default_exit_duration,
+    };
+
+    let display_time = match (notification.duration, notification.auto_dismiss) {
+        _ if notification.sticky => None,
+        (Some(total), _) => Some(total.saturating_sub(entry + exit)),
+        (None, AutoDismiss::Never) => None,
+        (None, AutoDismiss::After(d)) if d > Duration::ZERO => Some(d),
+        (None, AutoDismiss::After(_)) => Some(defaults.default_display_time),
+    };
+
+    (entry, dwell, exit, display_time)
 }
 
 // Implement RenderableNotification trait for render orchestrator
@@ -251,27 +675,134 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
     }
 
     fn title(&self) -> Option<ratatui::text::Line<'static>> {
-        self.notification.title.clone()
+        self.tagged.as_ref().unwrap_or(&self.notification).title.clone()
+    }
+
+    fn icon(&self) -> Option<String> {
+        use crate::notifications::functions::fnc_animated_icon_frame::animated_icon_frame;
+        use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+
+        match &self.notification.animated_icon {
+            Some((frames, period)) => {
+                animated_icon_frame(frames, *period, self.elapsed).map(|glyph| format!(" {}", glyph))
+            }
+            None => get_level_icon(self.notification.level).map(str::to_string),
+        }
+    }
+
+    fn has_animated_icon(&self) -> bool {
+        self.notification.animated_icon.is_some()
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn pointer(&self) -> Option<Position> {
+        self.pointer
+    }
+
+    fn shape(&self) -> crate::notifications::types::Shape {
+        if self.compact {
+            crate::notifications::types::Shape::Pill
+        } else {
+            self.notification.shape
+        }
+    }
+
+    fn begin_reflow(&mut self, from: Rect, duration: Duration, easing: Easing) {
+        self.reflow = Some(ReflowMotion { from, elapsed: Duration::ZERO, duration, easing });
+    }
+
+    fn reflow_rect(&self, target: Rect) -> Rect {
+        match self.reflow {
+            Some(motion) => {
+                let t = apply_easing(motion.easing, motion.elapsed.as_secs_f32() / motion.duration.as_secs_f32());
+                Rect {
+                    x: lerp(f32::from(motion.from.x), f32::from(target.x), t).round() as u16,
+                    y: lerp(f32::from(motion.from.y), f32::from(target.y), t).round() as u16,
+                    ..target
+                }
+            }
+            None => target,
+        }
+    }
+
+    fn time_color(&self) -> Option<Color> {
+        use crate::notifications::functions::fnc_fade_interpolate_color::interpolate_color;
+
+        let (from, to) = self.notification.time_color?;
+        let total = self.display_time.filter(|total| !total.is_zero())?;
+        let remaining = self.remaining_display_time.unwrap_or_default();
+
+        let elapsed_fraction = 1.0 - remaining.as_secs_f32() / total.as_secs_f32();
+        interpolate_color(Some(from), Some(to), elapsed_fraction, true)
+    }
+
+    fn repeat_count(&self) -> u32 {
+        self.repeat_count
+    }
+
+    fn progress(&self) -> Option<f32> {
+        let target = self.notification.progress?;
+        match self.progress_motion {
+            Some(motion) => {
+                let t = apply_easing(motion.easing, motion.elapsed.as_secs_f32() / motion.duration.as_secs_f32());
+                Some(lerp(motion.from, target, t))
+            }
+            None => Some(target),
+        }
+    }
+
+    fn actions(&self) -> &[(String, ActionId)] {
+        &self.notification.actions
+    }
+
+    fn set_action_rects(&mut self, rects: Vec<(ratatui::prelude::Rect, ActionId)>) {
+        self.action_rects = rects;
+    }
+
+    fn show_new_badge(&self) -> bool {
+        self.notification.new_badge
+            && matches!(
+                self.current_phase,
+                AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding
+            )
     }
 
     fn content(&self) -> ratatui::prelude::Text<'static> {
-        self.notification.content.clone()
+        self.tagged.as_ref().unwrap_or(&self.notification).display_content()
+    }
+
+    fn wrapped_content(&self, width: u16) -> Option<ratatui::prelude::Text<'static>> {
+        self.wrap_cache.as_ref().filter(|(cached, _)| *cached == width).map(|(_, text)| text.clone())
+    }
+
+    fn cache_wrapped_content(&mut self, width: u16, wrapped: ratatui::prelude::Text<'static>) {
+        self.wrap_cache = Some((width, wrapped));
     }
 
     fn border_type(&self) -> ratatui::widgets::BorderType {
         self.notification.border_type.unwrap_or(ratatui::widgets::BorderType::Plain)
     }
 
+    fn borders(&self) -> ratatui::widgets::Borders {
+        match self.notification.border_type {
+            Some(_) => ratatui::widgets::Borders::ALL,
+            None => ratatui::widgets::Borders::NONE,
+        }
+    }
+
     fn fade_effect(&self) -> bool {
         self.notification.fade_effect
     }
 
     fn animation_type(&self) -> crate::notifications::types::Animation {
-        self.notification.animation
+        self.animation()
     }
 
     fn animation_progress(&self) -> f32 {
-        self.animation_progress
+        self.eased_progress()
     }
 
     fn block_style(&self) -> Option<ratatui::prelude::Style> {
@@ -294,17 +825,26 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         self.full_rect = rect;
     }
 
+    fn set_baseline_anchor(&mut self, anchor: crate::notifications::types::Anchor) {
+        self.baseline_anchor = (anchor != self.notification.anchor()).then_some(anchor);
+    }
+
     fn calculate_animation_rect(&self, frame_area: ratatui::prelude::Rect) -> ratatui::prelude::Rect {
         use crate::notifications::types::Animation;
 
-        match self.notification.animation {
+        // Compact notifications skip motion and appear in place
+        if self.compact {
+            return self.full_rect;
+        }
+
+        match self.animation() {
             Animation::Slide => {
                 crate::notifications::functions::fnc_slide_calculate_rect::slide_calculate_rect(
                     self.full_rect,
                     frame_area,
-                    self.animation_progress,
+                    self.eased_progress(),
                     self.current_phase,
-                    self.notification.anchor,
+                    self.baseline_anchor.unwrap_or_else(|| self.notification.anchor()),
                     self.notification.slide_direction,
                     self.custom_entry_pos,
                     self.custom_exit_pos,
@@ -315,7 +855,7 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
                     self.full_rect,
                     frame_area,
                     self.current_phase,
-                    self.animation_progress,
+                    self.eased_progress(),
                 )
             }
             Animation::Fade => {
@@ -323,7 +863,15 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
                     self.full_rect,
                     frame_area,
                     self.current_phase,
-                    self.animation_progress,
+                    self.eased_progress(),
+                )
+            }
+            Animation::Collapse => {
+                crate::notifications::functions::fnc_collapse_calculate_rect::calculate_rect(
+                    self.full_rect,
+                    self.baseline_anchor.unwrap_or_else(|| self.notification.anchor()),
+                    self.current_phase,
+                    self.eased_progress(),
                 )
             }
         }
@@ -337,13 +885,13 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
     ) -> ratatui::widgets::Block<'a> {
         use crate::notifications::types::Animation;
 
-        match self.notification.animation {
+        match self.animation() {
             Animation::Slide => {
                 crate::notifications::functions::fnc_slide_apply_border_effect::slide_apply_border_effect(
                     block,
-                    self.notification.anchor,
+                    self.baseline_anchor.unwrap_or_else(|| self.notification.anchor()),
                     self.notification.slide_direction,
-                    self.animation_progress,
+                    self.eased_progress(),
                     self.current_phase,
                     self.full_rect,
                     self.custom_entry_pos,
@@ -365,7 +913,7 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         use crate::notifications::types::Animation;
         use crate::notifications::functions::fnc_fade_interpolate_color::FadeHandler;
 
-        match self.notification.animation {
+        match self.animation() {
             Animation::Fade => {
                 FadeHandler.interpolate_frame_foreground(base_fg, phase, progress)
             }
@@ -385,7 +933,7 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         use crate::notifications::types::Animation;
         use crate::notifications::functions::fnc_fade_interpolate_color::FadeHandler;
 
-        match self.notification.animation {
+        match self.animation() {
             Animation::Fade => {
                 FadeHandler.interpolate_content_foreground(base_fg, phase, progress)
             }
@@ -452,6 +1000,23 @@ mod tests {
         assert_eq!(state.actual_entry_duration, Duration::from_millis(600));
     }
 
+    #[test]
+    fn test_phase_durations_resolve_against_defaults() {
+        let defaults = ManagerDefaults::default();
+        let mut notification = create_test_notification();
+        notification.slide_out_timing = Timing::Fixed(Duration::from_millis(200));
+
+        let state = NotificationState::new(1, notification, &defaults);
+        assert_eq!(
+            state.phase_durations(),
+            (Duration::from_millis(500), Some(Duration::from_secs(4)), Duration::from_millis(200))
+        );
+
+        let mut sticky = create_test_notification();
+        sticky.sticky = true;
+        assert_eq!(NotificationState::new(2, sticky, &defaults).phase_durations().1, None);
+    }
+
     #[test]
     fn test_auto_dismiss_never_sets_none() {
         let defaults = ManagerDefaults::default();
@@ -487,6 +1052,27 @@ mod tests {
         assert_eq!(state.remaining_display_time, Some(Duration::from_secs(7)));
     }
 
+    #[test]
+    fn test_duration_leaves_dwell_after_animations() {
+        let defaults = ManagerDefaults::default();
+        let mut notification = create_test_notification();
+        notification.auto_dismiss = AutoDismiss::Never;
+        notification.duration = Some(Duration::from_secs(3));
+
+        // 500ms entry and 750ms exit leave 1.75s of dwell, overriding Never
+        let state = NotificationState::new(1, notification.clone(), &defaults);
+        assert_eq!(state.remaining_display_time, Some(Duration::from_millis(1750)));
+
+        // Too short for both animations: no dwell, but the entry still plays in full
+        notification.duration = Some(Duration::from_millis(800));
+        let state = NotificationState::new(1, notification.clone(), &defaults);
+        assert_eq!(state.remaining_display_time, Some(Duration::ZERO));
+        assert_eq!(state.actual_entry_duration, Duration::from_millis(500));
+
+        notification.sticky = true;
+        assert!(NotificationState::new(1, notification, &defaults).remaining_display_time.is_none());
+    }
+
     #[test]
     fn test_created_at_timestamp_is_set() {
         let defaults = ManagerDefaults::default();
@@ -544,6 +1130,240 @@ mod tests {
         assert_eq!(state.custom_exit_pos, Some((100.0, 50.0)));
     }
 
+    #[test]
+    fn test_update_accumulates_elapsed_time() {
+        let defaults = ManagerDefaults::default();
+        let notification = create_test_notification();
+        let mut state = NotificationState::new(1, notification, &defaults);
+
+        state.update(Duration::from_millis(100));
+        state.update(Duration::from_millis(250));
+
+        assert_eq!(state.elapsed, Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_precompute_layout_caches_size_for_area() {
+        use crate::notifications::orc_stacking::StackableNotification;
+
+        let defaults = ManagerDefaults::default();
+        let notification = create_test_notification();
+        let mut state = NotificationState::new(1, notification, &defaults);
+        let area = Rect::new(0, 0, 80, 24);
+
+        assert!(state.layout_cache.is_none());
+        state.precompute_layout(area);
+
+        let (cached_area, size) = state.layout_cache.unwrap();
+        assert_eq!(cached_area, area);
+        assert_eq!(state.calculate_content_size(area), size);
+
+        // Wrapped to the box width less the border and padding
+        let padding = state.notification.padding;
+        let (width, wrapped) = state.wrap_cache.clone().unwrap();
+        assert_eq!(width, size.0 - 2 - padding.left - padding.right);
+        assert_eq!(wrapped.lines.len(), usize::from(size.1 - 2 - padding.top - padding.bottom));
+    }
+
+    #[test]
+    fn test_progress_motion_eases_toward_target_and_finishes() {
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let defaults = ManagerDefaults::default();
+        let notification = Notification { progress: Some(0.8), ..create_test_notification() };
+        let mut state = NotificationState::new(1, notification, &defaults);
+        state.progress_motion =
+            Some(ProgressMotion { from: 0.2, elapsed: Duration::ZERO, duration: Duration::from_secs(1), easing: Easing::Linear });
+        assert_eq!(state.progress(), Some(0.2));
+
+        state.update(Duration::from_millis(500));
+        assert!((state.progress().unwrap() - 0.5).abs() < 1e-6);
+
+        state.update(Duration::from_millis(500));
+        assert!(state.progress_motion.is_none());
+        assert_eq!(state.progress(), Some(0.8));
+    }
+
+    #[test]
+    fn test_reflow_rect_interpolates_and_finishes() {
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let defaults = ManagerDefaults::default();
+        let mut state = NotificationState::new(1, create_test_notification(), &defaults);
+        let target = Rect::new(0, 0, 20, 3);
+        assert_eq!(state.reflow_rect(target), target);
+
+        state.begin_reflow(Rect::new(0, 8, 20, 3), Duration::from_secs(1), Easing::Linear);
+        assert_eq!(state.reflow_rect(target).y, 8);
+
+        state.update(Duration::from_millis(500));
+        assert_eq!(state.reflow_rect(target), Rect::new(0, 4, 20, 3));
+
+        state.update(Duration::from_millis(500));
+        assert!(state.reflow.is_none());
+        assert_eq!(state.reflow_rect(target), target);
+    }
+
+    #[test]
+    fn test_update_notification_keeps_phase_and_anchor() {
+        use crate::notifications::types::Anchor;
+
+        let defaults = ManagerDefaults::default();
+        let original = Notification { anchor: Some(Anchor::TopLeft), ..create_test_notification() };
+        let mut state = NotificationState::new(1, original, &defaults);
+        state.current_phase = AnimationPhase::Dwelling;
+        state.remaining_display_time = Some(Duration::from_millis(10));
+
+        let updated = Notification { content: Text::from("Updated"), ..Default::default() };
+        state.update_notification(updated, &defaults);
+
+        assert_eq!(state.notification.content, Text::from("Updated"));
+        assert_eq!(state.notification.anchor, Some(Anchor::TopLeft));
+        assert_eq!(state.current_phase, AnimationPhase::Dwelling);
+        assert_eq!(state.remaining_display_time, state.display_time);
+    }
+
+    #[test]
+    fn test_update_notification_restarts_dwell_with_new_duration() {
+        let defaults = ManagerDefaults::default();
+        let mut state = NotificationState::new(1, create_test_notification(), &defaults);
+        state.current_phase = AnimationPhase::Dwelling;
+        state.remaining_display_time = Some(Duration::from_millis(10));
+
+        let updated = Notification {
+            auto_dismiss: AutoDismiss::After(Duration::from_secs(9)),
+            ..create_test_notification()
+        };
+        state.update_notification(updated, &defaults);
+
+        assert_eq!(state.display_time, Some(Duration::from_secs(9)));
+        assert_eq!(state.remaining_display_time, Some(Duration::from_secs(9)));
+    }
+
+    #[test]
+    fn test_reset_layout_clears_rect_and_cache() {
+        let defaults = ManagerDefaults::default();
+        let mut state = NotificationState::new(1, create_test_notification(), &defaults);
+        state.precompute_layout(Rect::new(0, 0, 80, 24));
+        state.full_rect = Rect::new(2, 2, 30, 4);
+
+        state.reset_layout();
+
+        assert!(state.layout_cache.is_none());
+        assert!(state.wrap_cache.is_none());
+        assert_eq!(state.full_rect, Rect::default());
+    }
+
+    #[test]
+    fn test_restart_dwell_resets_countdown() {
+        let defaults = ManagerDefaults::default();
+        let notification = Notification {
+            animation: crate::notifications::types::Animation::Fade,
+            auto_dismiss: AutoDismiss::After(Duration::from_secs(2)),
+            ..create_test_notification()
+        };
+        let mut state = NotificationState::new(1, notification, &defaults);
+
+        state.update(Duration::from_millis(500));
+        state.update(Duration::from_secs(2));
+        assert!(state.is_exiting());
+
+        state.restart_dwell();
+        assert_eq!(state.current_phase, AnimationPhase::Dwelling);
+        assert_eq!(state.remaining_display_time, Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_eased_progress_follows_notification_easing() {
+        let defaults = ManagerDefaults::default();
+        let notification = Notification { easing: Easing::EaseInQuad, ..create_test_notification() };
+        let mut linear = NotificationState::new(1, create_test_notification(), &defaults);
+        let mut eased = NotificationState::new(2, notification, &defaults);
+
+        // Halfway through the 500ms entry
+        linear.update(Duration::ZERO);
+        eased.update(Duration::ZERO);
+        linear.update(Duration::from_millis(250));
+        eased.update(Duration::from_millis(250));
+
+        assert!((linear.eased_progress() - 0.5).abs() < 1e-4);
+        assert!((eased.eased_progress() - 0.25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_begin_exit_starts_exit_animation() {
+        let defaults = ManagerDefaults::default();
+        let mut state = NotificationState::new(1, create_test_notification(), &defaults);
+
+        state.update(Duration::from_millis(100));
+        state.begin_exit();
+
+        assert_eq!(state.current_phase, AnimationPhase::SlidingOut);
+        assert_eq!(state.animation_progress, 0.0);
+    }
+
+    #[test]
+    fn test_slide_direction_none_fades_in_place() {
+        use crate::notifications::orc_render::RenderableNotification;
+        use crate::notifications::types::SlideDirection;
+
+        let defaults = ManagerDefaults::default();
+        let notification = Notification { slide_direction: SlideDirection::None, ..create_test_notification() };
+        let mut state = NotificationState::new(1, notification, &defaults);
+        let full_rect = Rect::new(60, 20, 20, 3);
+        state.set_full_rect(full_rect);
+
+        state.update(Duration::from_millis(100));
+        assert_eq!(state.current_phase, AnimationPhase::FadingIn);
+        assert_eq!(state.calculate_animation_rect(Rect::new(0, 0, 80, 24)), full_rect);
+
+        state.begin_exit();
+        assert_eq!(state.current_phase, AnimationPhase::FadingOut);
+    }
+
+    #[test]
+    fn test_begin_exit_on_pending_finishes_immediately() {
+        let defaults = ManagerDefaults::default();
+        let mut state = NotificationState::new(1, create_test_notification(), &defaults);
+
+        state.begin_exit();
+
+        assert_eq!(state.current_phase, AnimationPhase::Finished);
+    }
+
+    #[test]
+    fn test_time_color_tracks_remaining_display_time() {
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let defaults = ManagerDefaults::default();
+        let notification = Notification {
+            time_color: Some((Color::Rgb(0, 255, 0), Color::Rgb(255, 0, 0))),
+            auto_dismiss: AutoDismiss::After(Duration::from_secs(4)),
+            ..create_test_notification()
+        };
+        let mut state = NotificationState::new(1, notification, &defaults);
+
+        assert_eq!(state.time_color(), Some(Color::Rgb(0, 255, 0)));
+
+        state.remaining_display_time = Some(Duration::ZERO);
+        assert_eq!(state.time_color(), Some(Color::Rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn test_time_color_ignored_without_countdown() {
+        use crate::notifications::orc_render::RenderableNotification;
+
+        let defaults = ManagerDefaults::default();
+        let notification = Notification {
+            time_color: Some((Color::Green, Color::Red)),
+            auto_dismiss: AutoDismiss::Never,
+            ..create_test_notification()
+        };
+        let state = NotificationState::new(1, notification, &defaults);
+
+        assert_eq!(state.time_color(), None);
+    }
+
     #[test]
     fn test_all_timing_fields_resolved() {
         let defaults = ManagerDefaults::default();
@@ -561,4 +1381,4 @@ mod tests {
 }
 
 // FILE: src/notifications/classes/cls_notification_state.rs - NotificationState internal state management
-// END OF VERSION: 1.2.0
+// END OF VERSION: 1.36.1
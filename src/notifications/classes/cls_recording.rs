@@ -0,0 +1,98 @@
+// FILE: src/notifications/classes/cls_recording.rs - Recorded manager session
+// VERSION: 1.2.0
+// WCTX: Per-anchor clearing
+// CLOG: Add ClearAnchor event
+
+use crate::notifications::classes::Notification;
+use crate::notifications::types::Anchor;
+use std::time::Duration;
+
+/// A single manager operation captured while recording.
+///
+/// IDs in `Add`, `Update` and `Remove` are the ones the recording manager issued;
+/// replay maps them onto whatever IDs the replaying manager hands out.
+#[derive(Debug, Clone)]
+pub enum RecordedEvent {
+    /// `add` was called and issued the given ID.
+    Add(u64, Box<Notification>),
+    /// `update` replaced the given notification's content.
+    Update(u64, Box<Notification>),
+    /// `tick` was called with the given delta.
+    Tick(Duration),
+    /// `remove` was called with the given ID.
+    Remove(u64),
+    /// `clear` was called.
+    Clear,
+    /// `force_clear` was called.
+    ForceClear,
+    /// `clear_anchor` was called for the given anchor.
+    ClearAnchor(Anchor),
+}
+
+/// Sequence of manager operations captured between `start_recording` and
+/// `stop_recording`.
+///
+/// Each event is stamped with the ticked time since recording began, so the
+/// timeline follows the manager's own clock rather than wall time.
+#[derive(Debug, Clone, Default)]
+pub struct Recording {
+    events: Vec<(Duration, RecordedEvent)>,
+    elapsed: Duration,
+}
+
+impl Recording {
+    /// Returns the recorded events with their timestamps, oldest first.
+    pub fn events(&self) -> &[(Duration, RecordedEvent)] {
+        &self.events
+    }
+
+    /// Returns the total ticked time covered by the recording.
+    pub fn duration(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, event: RecordedEvent) {
+        if let RecordedEvent::Tick(delta) = event {
+            self.elapsed += delta;
+        }
+        self.events.push((self.elapsed, event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_advances_timestamps() {
+        let mut recording = Recording::default();
+        recording.push(RecordedEvent::Clear);
+        recording.push(RecordedEvent::Tick(Duration::from_millis(100)));
+        recording.push(RecordedEvent::Remove(3));
+
+        let stamps: Vec<Duration> = recording.events().iter().map(|(at, _)| *at).collect();
+        assert_eq!(stamps, vec![Duration::ZERO, Duration::from_millis(100), Duration::from_millis(100)]);
+        assert_eq!(recording.duration(), Duration::from_millis(100));
+        assert_eq!(recording.len(), 3);
+    }
+
+    #[test]
+    fn test_default_is_empty() {
+        let recording = Recording::default();
+        assert!(recording.is_empty());
+        assert_eq!(recording.duration(), Duration::ZERO);
+    }
+}
+
+// FILE: src/notifications/classes/cls_recording.rs - Recorded manager session
+// END OF VERSION: 1.2.0
@@ -1,16 +1,25 @@
 // FILE: src/notifications/classes/mod.rs - Classes module
-// VERSION: 1.1.0
-// WCTX: OFPF migration - Notification class
-// CLOG: Export Notification and NotificationBuilder publicly
+// VERSION: 1.5.0
+// WCTX: Typed notification handles
+// CLOG: Add cls_notification_handle
 
 pub(crate) mod cls_notification;
+pub(crate) mod cls_notification_handle;
+#[cfg(feature = "tracing")]
+pub(crate) mod cls_notification_layer;
 pub(crate) mod cls_notification_state;
+pub(crate) mod cls_recording;
 
 // Public exports
 pub use cls_notification::{Notification, NotificationBuilder};
+pub use cls_notification_handle::NotificationHandle;
+#[cfg(feature = "tracing")]
+pub use cls_notification_layer::NotificationLayer;
+pub use cls_notification_state::NotificationState;
+pub use cls_recording::{RecordedEvent, Recording};
 
 // Internal exports
-pub(crate) use cls_notification_state::{NotificationState, ManagerDefaults};
+pub(crate) use cls_notification_state::ManagerDefaults;
 
 // FILE: src/notifications/classes/mod.rs - Classes module
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.5.0
@@ -0,0 +1,71 @@
+// FILE: src/notifications/functions/fnc_accessible_text.rs - Plain-text rendering of a notification
+// VERSION: 1.2.0
+// WCTX: Notification headlines
+// CLOG: Read the headline before the body
+
+use crate::notifications::classes::Notification;
+use crate::notifications::types::Level;
+use ratatui::text::Line;
+
+/// Returns a notification as a single unstyled line for screen readers or logs.
+///
+/// The format is `"<Level>: <title> - <content>"`, leaving out the level
+/// prefix and title when they are not set. Multi-line content is joined
+/// with spaces.
+///
+/// # Arguments
+///
+/// * `notification` - The notification to describe
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_accessible_text::accessible_text;
+/// use ratatui_notifications::{Level, Notification};
+///
+/// let notification = Notification::new("Disk full").level(Level::Error).build().unwrap();
+/// assert_eq!(accessible_text(&notification), "Error: Disk full");
+///
+/// let notification = Notification::new("Saved").title("Backup").build().unwrap();
+/// assert_eq!(accessible_text(&notification), "Info: Backup - Saved");
+/// ```
+pub fn accessible_text(notification: &Notification) -> String {
+    let content = notification
+        .headline()
+        .map(str::to_string)
+        .into_iter()
+        .chain(notification.content().lines.iter().map(plain_line))
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let body = match notification.title().map(plain_line) {
+        Some(title) if !title.trim().is_empty() => format!("{} - {}", title.trim(), content),
+        _ => content,
+    };
+
+    match notification.level() {
+        Some(level) => format!("{}: {}", level_label(level), body),
+        None => body,
+    }
+}
+
+/// Concatenates a line's spans without styling.
+fn plain_line(line: &Line<'_>) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+/// Human-readable name for a level.
+fn level_label(level: Level) -> &'static str {
+    match level {
+        Level::Info => "Info",
+        Level::Success => "Success",
+        Level::Warn => "Warning",
+        Level::Error => "Error",
+        Level::Debug => "Debug",
+        Level::Trace => "Trace",
+    }
+}
+
+// FILE: src/notifications/functions/fnc_accessible_text.rs - Plain-text rendering of a notification
+// END OF VERSION: 1.2.0
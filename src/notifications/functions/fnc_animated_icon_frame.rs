@@ -0,0 +1,53 @@
+// FILE: src/notifications/functions/fnc_animated_icon_frame.rs - Selects the current frame of an animated icon
+// VERSION: 1.0.0
+// WCTX: Adding animated icons
+// CLOG: Initial creation
+
+use std::time::Duration;
+
+/// Selects the glyph an animated icon shows after `elapsed` time.
+///
+/// Each glyph is shown for `period` before advancing to the next one,
+/// wrapping back to the first glyph at the end of the cycle.
+///
+/// # Arguments
+///
+/// * `frames` - The glyphs to cycle through
+/// * `period` - How long each glyph is shown
+/// * `elapsed` - Time since the notification started animating
+///
+/// # Returns
+///
+/// * `Some(&str)` - The glyph to draw
+/// * `None` - If `frames` is empty
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui_notifications::notifications::functions::fnc_animated_icon_frame::animated_icon_frame;
+///
+/// let frames = vec!["|".to_string(), "/".to_string(), "-".to_string()];
+/// let period = Duration::from_millis(100);
+///
+/// assert_eq!(animated_icon_frame(&frames, period, Duration::ZERO), Some("|"));
+/// assert_eq!(animated_icon_frame(&frames, period, Duration::from_millis(150)), Some("/"));
+/// assert_eq!(animated_icon_frame(&frames, period, Duration::from_millis(300)), Some("|"));
+/// ```
+pub fn animated_icon_frame(frames: &[String], period: Duration, elapsed: Duration) -> Option<&str> {
+    if frames.is_empty() {
+        return None;
+    }
+
+    // A zero period cannot advance, so hold the first glyph
+    let index = if period.is_zero() {
+        0
+    } else {
+        ((elapsed.as_nanos() / period.as_nanos()) % frames.len() as u128) as usize
+    };
+
+    frames.get(index).map(String::as_str)
+}
+
+// FILE: src/notifications/functions/fnc_animated_icon_frame.rs - Selects the current frame of an animated icon
+// END OF VERSION: 1.0.0
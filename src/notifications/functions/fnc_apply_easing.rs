@@ -0,0 +1,42 @@
+// FILE: src/notifications/functions/fnc_apply_easing.rs - Maps linear progress through an easing curve
+// VERSION: 1.0.0
+// WCTX: Adding reflow animation
+// CLOG: Initial creation
+
+use crate::notifications::types::Easing;
+use crate::shared_utils::math::ease_in_quad;
+use std::f32::consts::PI;
+
+/// Maps linear progress through an easing curve.
+///
+/// # Arguments
+///
+/// * `easing` - The curve to apply
+/// * `t` - Linear progress, clamped to 0.0..=1.0
+///
+/// # Returns
+///
+/// The eased progress. Every curve maps 0.0 to 0.0 and 1.0 to 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_apply_easing::apply_easing;
+/// use ratatui_notifications::notifications::types::Easing;
+///
+/// assert_eq!(apply_easing(Easing::Linear, 0.5), 0.5);
+/// assert_eq!(apply_easing(Easing::EaseInQuad, 0.5), 0.25);
+/// assert_eq!(apply_easing(Easing::EaseOutCubic, 0.5), 0.875);
+/// ```
+pub fn apply_easing(easing: Easing, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    match easing {
+        Easing::Linear => t,
+        Easing::EaseInQuad => ease_in_quad(t),
+        Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+        Easing::EaseInOutSine => (1.0 - (PI * t).cos()) / 2.0,
+    }
+}
+
+// FILE: src/notifications/functions/fnc_apply_easing.rs - Maps linear progress through an easing curve
+// END OF VERSION: 1.0.0
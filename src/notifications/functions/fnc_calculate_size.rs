@@ -1,13 +1,15 @@
 // FILE: src/notifications/functions/fnc_calculate_size.rs - Calculate notification size
-// VERSION: 1.0.0
-// WCTX: TDD implementation of deferred functions
-// CLOG: Initial creation - extracts size calculation logic from layout.rs
+// VERSION: 1.7.0
+// WCTX: Height-capped notifications
+// CLOG: Widen for the more-lines row
 
 use crate::notifications::classes::Notification;
-use crate::notifications::types::SizeConstraint;
+use crate::notifications::functions::fnc_clip_rows::clip_rows;
+use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+use crate::notifications::types::{Shape, SizeConstraint};
+use crate::notifications::functions::fnc_wrap_text::wrap_text;
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Widget, Wrap};
-use ratatui::buffer::{Buffer, Cell};
+use ratatui::widgets::BorderType;
 
 /// Calculates the size of a notification based on its content and constraints.
 ///
@@ -52,9 +54,20 @@ pub fn calculate_size(notification: &Notification, frame_area: Rect) -> (u16, u1
     let h_padding = notification.padding.left + notification.padding.right;
     let v_padding = notification.padding.top + notification.padding.bottom;
 
-    // 3. Calculate minimum size (at least 3x3)
-    let min_width = (1 + h_padding + border_h_offset).max(3);
-    let min_height = (1 + v_padding + border_v_offset).max(3);
+    // Pills are one row: first content line, padding and the two end caps
+    if notification.shape == Shape::Pill {
+        return calculate_pill_size(notification, frame_area, h_padding);
+    }
+
+    // Without a border, a title (shown for animated icons and the "NEW"
+    // badge too) takes a row of its own
+    let has_title = notification.title.is_some() || notification.animated_icon.is_some() || notification.new_badge;
+    let border_v_offset = if notification.border_type.is_none() && has_title { 1 } else { border_v_offset };
+
+    // 3. Calculate minimum size (at least 3x3 when bordered)
+    let min_side = if notification.border_type.is_some() { 3 } else { 1 };
+    let min_width = (1 + h_padding + border_h_offset).max(min_side);
+    let min_height = (1 + v_padding + border_v_offset).max(min_side);
 
     // 4. Apply max_width constraint (Percentage or Absolute)
     let max_width_constraint = notification
@@ -68,14 +81,17 @@ pub fn calculate_size(notification: &Notification, frame_area: Rect) -> (u16, u1
         .unwrap_or(frame_area.width)
         .max(min_width);
 
-    // 5. Calculate intrinsic width from content
-    let content_max_line_width = notification
-        .content
+    // 5. Calculate intrinsic width from content (or the icon standing in for it)
+    let content = notification.display_content();
+    let content_max_line_width = content
         .lines
         .iter()
         .map(|l| l.width())
         .max()
         .unwrap_or(0) as u16;
+    let content_max_line_width = content_max_line_width
+        .max(icon_only_width(notification))
+        .max(actions_width(notification));
 
     let title_width = notification.title.as_ref().map_or(0, |t| t.width()) as u16;
 
@@ -96,38 +112,69 @@ pub fn calculate_size(notification: &Notification, frame_area: Rect) -> (u16, u1
         .unwrap_or(frame_area.height)
         .max(min_height);
 
-    // 7. Render content to buffer to measure actual height with wrapping
-    let mut temp_block = Block::default();
-    if let Some(border_type) = notification.border_type {
-        temp_block = temp_block.borders(Borders::ALL).border_type(border_type);
-    }
-    if let Some(title) = &notification.title {
-        temp_block = temp_block.title(title.clone());
-    }
-    temp_block = temp_block.padding(notification.padding);
+    // 7. Wrap content to the inner width; a progress gauge and the action
+    //    buttons each take a row of their own
+    let inner_width = final_width.saturating_sub(border_h_offset + h_padding);
+    let wrapped = wrap_text(&content, inner_width);
+    let content_lines = wrapped.lines.len() as u16;
+    let gauge_lines = u16::from(notification.progress.is_some());
+    let action_lines = u16::from(!notification.actions.is_empty());
+    let measured_height = content_lines.max(1) + gauge_lines + action_lines + v_padding + border_v_offset;
+
+    // 8. Return (width, height) tuple, widened for a "▼ N more lines" row
+    //    if the height cap cuts the content
+    let final_height = measured_height.max(min_height).min(max_height_constraint);
+    let content_rows = final_height.saturating_sub(gauge_lines + action_lines + v_padding + border_v_offset);
+    let clipped_width = clip_rows(wrapped, content_rows, Style::default())
+        .lines
+        .last()
+        .map_or(0, |line| line.width()) as u16;
+    let final_width = final_width
+        .max(clipped_width + border_h_offset + h_padding)
+        .min(max_width_constraint);
+    (final_width, final_height)
+}
+
+/// Sizes a `Shape::Pill` notification: one row wide enough for the first
+/// content line, horizontal padding and a cap cell at each end.
+fn calculate_pill_size(notification: &Notification, frame_area: Rect, h_padding: u16) -> (u16, u16) {
+    const CAP_WIDTH: u16 = 2;
+
+    let text_width = notification.display_content().lines.first().map_or(0, |l| l.width()) as u16;
+    let text_width = text_width.max(icon_only_width(notification));
+    let max_width = notification
+        .max_width
+        .map(|c| match c {
+            SizeConstraint::Absolute(w) => w.min(frame_area.width),
+            SizeConstraint::Percentage(p) => (frame_area.width as f32 * p.clamp(0.0, 1.0)) as u16,
+        })
+        .unwrap_or(frame_area.width)
+        .max(CAP_WIDTH + 1);
 
-    let temp_paragraph = Paragraph::new(notification.content.clone())
-        .wrap(Wrap { trim: true })
-        .block(temp_block);
+    let width = (text_width.max(1) + h_padding + CAP_WIDTH).min(max_width);
+    (width, 1)
+}
 
-    let buffer_height = max_height_constraint;
-    let mut buffer = Buffer::empty(Rect::new(0, 0, final_width, buffer_height));
-    temp_paragraph.render(buffer.area, &mut buffer);
+/// Width of the action button row: each `[label]` plus one cell between them.
+fn actions_width(notification: &Notification) -> u16 {
+    let buttons: usize = notification.actions.iter().map(|(label, _)| Span::raw(label.as_str()).width() + 2).sum();
+    let gaps = notification.actions.len().saturating_sub(1);
+    u16::try_from(buttons + gaps).unwrap_or(u16::MAX)
+}
 
-    let default_cell = Cell::default();
-    let measured_height = buffer
-        .content
-        .iter()
-        .enumerate()
-        .filter(|(_, cell)| *cell != &default_cell)
-        .map(|(idx, _)| buffer.pos_of(idx).1)
-        .max()
-        .map_or(0, |row_index| row_index + 1);
+/// Width of the icon drawn in place of the body when a notification has
+/// neither content nor a title (0 otherwise).
+fn icon_only_width(notification: &Notification) -> u16 {
+    if notification.title.is_some() || notification.headline.is_some() || notification.content.width() > 0 {
+        return 0;
+    }
 
-    // 8. Return (width, height) tuple
-    let final_height = measured_height.max(min_height).min(max_height_constraint);
-    (final_width, final_height)
+    let width = match &notification.animated_icon {
+        Some((frames, _)) => frames.iter().map(|frame| Span::raw(frame.as_str()).width()).max().unwrap_or(0),
+        None => get_level_icon(notification.level).map_or(0, |icon| Span::raw(icon.trim_start()).width()),
+    };
+    width as u16
 }
 
 // FILE: src/notifications/functions/fnc_calculate_size.rs - Calculate notification size
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.7.0
@@ -0,0 +1,47 @@
+// FILE: src/notifications/functions/fnc_clip_rows.rs - Cuts wrapped text to a row budget
+// VERSION: 1.0.0
+// WCTX: Height-capped notifications
+// CLOG: Initial creation
+
+use ratatui::prelude::*;
+
+/// Cuts wrapped `text` to at most `rows` whole lines.
+///
+/// When lines have to go, the last row that fits becomes a
+/// "▼ N more lines" count of everything left out. With fewer than two rows
+/// there is no room for the count, so the text is returned as is.
+///
+/// # Arguments
+///
+/// * `text` - Text already wrapped to the drawing width
+/// * `rows` - Number of rows available
+/// * `style` - Style of the count line
+///
+/// # Returns
+///
+/// The text, cut and ending in the count line if it was too tall.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_clip_rows::clip_rows;
+///
+/// let clipped = clip_rows(Text::from("a\nb\nc\nd"), 3, Style::default());
+/// assert_eq!(clipped.lines.len(), 3);
+/// assert_eq!(clipped.lines[2].to_string(), "▼ 2 more lines");
+/// ```
+pub fn clip_rows(mut text: Text<'static>, rows: u16, style: Style) -> Text<'static> {
+    let rows = usize::from(rows);
+    if rows < 2 || text.lines.len() <= rows {
+        return text;
+    }
+
+    let hidden = text.lines.len() - (rows - 1);
+    text.lines.truncate(rows - 1);
+    text.lines.push(Line::styled(format!("▼ {hidden} more lines"), style));
+    text
+}
+
+// FILE: src/notifications/functions/fnc_clip_rows.rs - Cuts wrapped text to a row budget
+// END OF VERSION: 1.0.0
@@ -0,0 +1,66 @@
+// FILE: src/notifications/functions/fnc_collapse_calculate_rect.rs - Horizontal collapse animation rect calculation
+// VERSION: 1.0.0
+// WCTX: Adding horizontal collapse animation
+// CLOG: Initial creation
+
+use crate::notifications::types::{Anchor, AnimationPhase};
+use crate::shared_utils::math::lerp;
+use ratatui::prelude::*;
+
+/// Calculates the visible rectangle for a horizontal collapse animation.
+///
+/// Only the width changes: it grows from zero while expanding and shrinks
+/// to zero while collapsing, with full height throughout. The edge nearest
+/// the anchor stays put (both edges meet in the middle for centre anchors).
+///
+/// # Arguments
+///
+/// * `full_rect` - The full rectangle of the notification
+/// * `anchor` - The anchor the notification is attached to
+/// * `phase` - The current animation phase
+/// * `progress` - The animation progress (0.0 to 1.0)
+///
+/// # Returns
+///
+/// The interpolated rectangle, or an empty rect once the width reaches zero
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_collapse_calculate_rect::calculate_rect;
+/// use ratatui_notifications::notifications::types::{Anchor, AnimationPhase};
+///
+/// let full_rect = Rect::new(60, 20, 20, 4);
+///
+/// // Halfway through the exit, half as wide and still flush right
+/// let result = calculate_rect(full_rect, Anchor::BottomRight, AnimationPhase::Collapsing, 0.5);
+/// assert_eq!(result, Rect::new(70, 20, 10, 4));
+/// ```
+pub fn calculate_rect(full_rect: Rect, anchor: Anchor, phase: AnimationPhase, progress: f32) -> Rect {
+    let progress = progress.clamp(0.0, 1.0);
+    let full_width = full_rect.width as f32;
+
+    let width = match phase {
+        AnimationPhase::Expanding => lerp(0.0, full_width, progress),
+        AnimationPhase::Collapsing => lerp(full_width, 0.0, progress),
+        // For other phases, just return the full rect
+        _ => return full_rect,
+    }
+    .round() as u16;
+
+    if width == 0 {
+        return Rect::default();
+    }
+
+    let x = match anchor {
+        Anchor::TopLeft | Anchor::MiddleLeft | Anchor::BottomLeft => full_rect.x,
+        Anchor::TopRight | Anchor::MiddleRight | Anchor::BottomRight => full_rect.right() - width,
+        _ => full_rect.x + (full_rect.width - width) / 2,
+    };
+
+    Rect { x, width, ..full_rect }
+}
+
+// FILE: src/notifications/functions/fnc_collapse_calculate_rect.rs - Horizontal collapse animation rect calculation
+// END OF VERSION: 1.0.0
@@ -0,0 +1,98 @@
+// FILE: src/notifications/functions/fnc_connector_cells.rs - Lays out a connector from a box to a target cell
+// VERSION: 1.0.0
+// WCTX: Adding notification pointers
+// CLOG: Initial creation
+
+use ratatui::layout::{Position, Rect};
+
+/// Lays out a connector line from the edge of `rect` toward `target`.
+///
+/// The line leaves the box edge facing the target and stops one cell short
+/// of it, so the target itself stays uncovered. A target level with the box
+/// gets a straight run; a diagonal one gets a vertical run from the nearest
+/// corner column, a rounded bend, then a horizontal run.
+///
+/// # Arguments
+///
+/// * `rect` - The notification box
+/// * `target` - The cell to point at
+///
+/// # Returns
+///
+/// The cells to draw with their box-drawing glyphs, ordered from the box
+/// outward. Empty if `rect` is empty, contains `target`, or touches it.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::{Position, Rect};
+/// use ratatui_notifications::notifications::functions::fnc_connector_cells::connector_cells;
+///
+/// let cells = connector_cells(Rect::new(0, 0, 4, 3), Position::new(7, 1));
+/// assert_eq!(
+///     cells,
+///     vec![(Position::new(4, 1), "─"), (Position::new(5, 1), "─"), (Position::new(6, 1), "─")]
+/// );
+/// ```
+pub fn connector_cells(rect: Rect, target: Position) -> Vec<(Position, &'static str)> {
+    if rect.is_empty() || rect.contains(target) {
+        return Vec::new();
+    }
+
+    let (tx, ty) = (target.x, target.y);
+    let is_left = tx < rect.x;
+    let is_right = tx >= rect.right();
+    let is_above = ty < rect.y;
+    let is_below = ty >= rect.bottom();
+
+    let mut cells = Vec::new();
+    let horizontal = |cells: &mut Vec<(Position, &'static str)>, from: u16, row: u16| {
+        if is_right {
+            cells.extend((from..tx).map(|x| (Position::new(x, row), "─")));
+        } else {
+            cells.extend((tx + 1..from).rev().map(|x| (Position::new(x, row), "─")));
+        }
+    };
+    let vertical = |cells: &mut Vec<(Position, &'static str)>, column: u16| {
+        if is_below {
+            cells.extend((rect.bottom()..ty).map(|y| (Position::new(column, y), "│")));
+        } else {
+            cells.extend((ty + 1..rect.y).rev().map(|y| (Position::new(column, y), "│")));
+        }
+    };
+
+    match (is_left || is_right, is_above || is_below) {
+        // Level with the box: straight horizontal run
+        (true, false) => {
+            let from = if is_right { rect.right() } else { rect.x };
+            horizontal(&mut cells, from, ty);
+        }
+        // Above or below the box: straight vertical run
+        (false, true) => vertical(&mut cells, tx),
+        // Diagonal: down/up from the nearest corner column, bend, then across
+        (true, true) => {
+            let column = if is_right { rect.right() - 1 } else { rect.x };
+            vertical(&mut cells, column);
+
+            let bend = match (is_right, is_below) {
+                (true, true) => "╰",
+                (false, true) => "╯",
+                (true, false) => "╭",
+                (false, false) => "╮",
+            };
+            cells.push((Position::new(column, ty), bend));
+
+            if is_right {
+                horizontal(&mut cells, column + 1, ty);
+            } else {
+                horizontal(&mut cells, column, ty);
+            }
+        }
+        (false, false) => {}
+    }
+
+    cells
+}
+
+// FILE: src/notifications/functions/fnc_connector_cells.rs - Lays out a connector from a box to a target cell
+// END OF VERSION: 1.0.0
@@ -0,0 +1,49 @@
+// FILE: src/notifications/functions/fnc_dim_style.rs - Dims a style's foreground toward its background
+// VERSION: 1.0.0
+// WCTX: Adding depth fade
+// CLOG: Initial creation
+
+use crate::shared_utils::math::{color_to_rgb, lerp};
+use ratatui::style::{Color, Style};
+
+/// Dims a style's foreground by blending it toward the background.
+///
+/// Terminals have no real opacity, so dimming is approximated by moving the
+/// foreground colour part of the way to the background colour. A missing or
+/// unconvertible foreground is treated as white and a missing background as
+/// black, matching the assumptions of the fade animation.
+///
+/// # Arguments
+///
+/// * `style` - The style to dim
+/// * `amount` - How far to blend, from 0.0 (unchanged) to 1.0 (fully background)
+///
+/// # Returns
+///
+/// The style with an RGB foreground, or unchanged if `amount` is zero or less
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use ratatui_notifications::notifications::functions::fnc_dim_style::dim_style;
+///
+/// let dimmed = dim_style(Style::default().fg(Color::White), 0.5);
+/// assert_eq!(dimmed.fg, Some(Color::Rgb(128, 128, 128)));
+/// ```
+pub fn dim_style(style: Style, amount: f32) -> Style {
+    if amount <= 0.0 {
+        return style;
+    }
+    let amount = amount.min(1.0);
+
+    let (r1, g1, b1) = color_to_rgb(style.fg).unwrap_or((255, 255, 255));
+    let (r2, g2, b2) = color_to_rgb(style.bg).unwrap_or((0, 0, 0));
+
+    let blend = |from: u8, to: u8| lerp(from as f32, to as f32, amount).round() as u8;
+
+    style.fg(Color::Rgb(blend(r1, r2), blend(g1, g2), blend(b1, b2)))
+}
+
+// FILE: src/notifications/functions/fnc_dim_style.rs - Dims a style's foreground toward its background
+// END OF VERSION: 1.0.0
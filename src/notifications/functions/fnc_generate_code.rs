@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_generate_code.rs - Code generation for notifications
-// VERSION: 1.0.0
-// WCTX: Adding "show code" feature to demo
-// CLOG: Initial creation
+// VERSION: 1.14.0
+// WCTX: Keyboard shortcuts for actions
+// CLOG: Emit action_keys call
 
 use std::time::Duration;
 
@@ -50,6 +50,11 @@ pub fn generate_code(notification: &Notification) -> String {
         lines.push(format!("    .title(\"{}\")", title_str));
     }
 
+    // Headline (no default - None)
+    if let Some(headline) = notification.headline() {
+        lines.push(format!("    .headline(\"{}\")", escape_string(headline)));
+    }
+
     // Level - default is Some(Level::Info)
     if notification.level() != defaults.level {
         if let Some(level) = notification.level() {
@@ -57,9 +62,9 @@ pub fn generate_code(notification: &Notification) -> String {
         }
     }
 
-    // Anchor - default is BottomRight
-    if notification.anchor() != defaults.anchor {
-        lines.push(format!("    .anchor(Anchor::{:?})", notification.anchor()));
+    // Anchor - emitted whenever set explicitly, since unset defers to the manager
+    if let Some(anchor) = notification.anchor {
+        lines.push(format!("    .anchor(Anchor::{:?})", anchor));
     }
 
     // Animation - default is Slide
@@ -70,6 +75,11 @@ pub fn generate_code(notification: &Notification) -> String {
         ));
     }
 
+    // Easing - default is Linear
+    if notification.easing() != defaults.easing {
+        lines.push(format!("    .easing(Easing::{:?})", notification.easing()));
+    }
+
     // SlideDirection - default is Default
     if notification.slide_direction() != defaults.slide_direction {
         lines.push(format!(
@@ -125,10 +135,11 @@ pub fn generate_code(notification: &Notification) -> String {
         lines.push(format!("    .margin({})", notification.exterior_margin()));
     }
 
-    // BorderType - default is Some(BorderType::Rounded)
-    if notification.border_type() != defaults.border_type {
-        if let Some(bt) = notification.border_type() {
-            lines.push(format!("    .border_type(BorderType::{:?})", bt));
+    // BorderType - emitted whenever chosen explicitly, since unset defers to the manager
+    if notification.border_explicit {
+        match notification.border_type() {
+            Some(bt) => lines.push(format!("    .border_type(BorderType::{:?})", bt)),
+            None => lines.push("    .borderless()".to_string()),
         }
     }
 
@@ -147,6 +158,66 @@ pub fn generate_code(notification: &Notification) -> String {
         lines.push(format!("    .fade({})", notification.fade_effect()));
     }
 
+    // Protected - default is false
+    if notification.protected() != defaults.protected {
+        lines.push(format!("    .protected({})", notification.protected()));
+    }
+
+    // New badge - default is false
+    if notification.new_badge() != defaults.new_badge {
+        lines.push(format!("    .new_badge({})", notification.new_badge()));
+    }
+
+    // Kind - default is None
+    if let Some(kind) = notification.kind() {
+        lines.push(format!("    .kind(\"{}\")", escape_string(kind)));
+    }
+
+    // Shape - default is Shape::Box
+    if notification.shape() != defaults.shape {
+        lines.push(format!("    .shape(Shape::{:?})", notification.shape()));
+    }
+
+    // Progress - default is None
+    if let Some(progress) = notification.progress() {
+        lines.push(format!("    .progress({:?})", progress));
+    }
+
+    // Sticky - default is false
+    if notification.sticky() != defaults.sticky {
+        lines.push(format!("    .sticky({})", notification.sticky()));
+    }
+
+    // Priority - default is 0
+    if notification.priority() != defaults.priority {
+        lines.push(format!("    .priority({})", notification.priority()));
+    }
+
+    // Duration - default is None
+    if let Some(duration) = notification.duration() {
+        lines.push(format!("    .duration({})", format_duration(duration)));
+    }
+
+    // Progress linger - default is None
+    if let Some(linger) = notification.progress_linger() {
+        lines.push(format!("    .progress_linger({})", format_duration(linger)));
+    }
+
+    // Actions - default is none
+    for (label, id) in notification.actions() {
+        lines.push(format!("    .action(\"{}\", ActionId({}))", escape_string(label), id.0));
+    }
+
+    // Action keys - default is none
+    if !notification.action_keys().is_empty() {
+        let keys: Vec<String> = notification
+            .action_keys()
+            .iter()
+            .map(|(label, key)| format!("(\"{}\".to_string(), KeyCode::{:?})", escape_string(label), key))
+            .collect();
+        lines.push(format!("    .action_keys(vec![{}])", keys.join(", ")));
+    }
+
     // End with build()
     lines.push("    .build()".to_string());
 
@@ -172,11 +243,16 @@ fn format_timing(timing: Timing) -> String {
 
 /// Formats a Duration as Timing::Fixed code.
 fn format_duration_as_timing(d: Duration) -> String {
+    format!("Timing::Fixed({})", format_duration(d))
+}
+
+/// Formats a Duration as `Duration::from_secs`/`from_millis` code.
+fn format_duration(d: Duration) -> String {
     let millis = d.as_millis();
-    if millis % 1000 == 0 {
-        format!("Timing::Fixed(Duration::from_secs({}))", millis / 1000)
+    if millis.is_multiple_of(1000) {
+        format!("Duration::from_secs({})", millis / 1000)
     } else {
-        format!("Timing::Fixed(Duration::from_millis({}))", millis)
+        format!("Duration::from_millis({})", millis)
     }
 }
 
@@ -220,4 +296,4 @@ fn format_padding(p: Padding) -> String {
 }
 
 // FILE: src/notifications/functions/fnc_generate_code.rs - Code generation for notifications
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.14.0
@@ -1,12 +1,13 @@
 // FILE: src/notifications/functions/fnc_get_level_icon.rs - Returns icon string for notification level
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Success level and level themes
+// CLOG: Add Success icon
 
 use crate::notifications::types::Level;
 
 // Icon constants
 const ICON_INFO: &str = " ℹ";
+const ICON_SUCCESS: &str = " ✔";
 const ICON_WARN: &str = " ⚠";
 const ICON_ERROR: &str = " ✖";
 const ICON_DEBUG: &str = " 🐞";
@@ -36,6 +37,7 @@ const ICON_TRACE: &str = " ⊙";
 pub fn get_level_icon(level: Option<Level>) -> Option<&'static str> {
     match level {
         Some(Level::Info) => Some(ICON_INFO),
+        Some(Level::Success) => Some(ICON_SUCCESS),
         Some(Level::Warn) => Some(ICON_WARN),
         Some(Level::Error) => Some(ICON_ERROR),
         Some(Level::Debug) => Some(ICON_DEBUG),
@@ -45,4 +47,4 @@ pub fn get_level_icon(level: Option<Level>) -> Option<&'static str> {
 }
 
 // FILE: src/notifications/functions/fnc_get_level_icon.rs - Returns icon string for notification level
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
@@ -0,0 +1,53 @@
+// FILE: src/notifications/functions/fnc_layout_actions.rs - Places action buttons along a row
+// VERSION: 1.0.0
+// WCTX: Notification action buttons
+// CLOG: Initial creation
+
+use ratatui::layout::Rect;
+use unicode_width::UnicodeWidthStr;
+
+use crate::notifications::types::ActionId;
+
+/// Lays out action buttons left to right along `row`.
+///
+/// Each button is drawn as `[label]`, one cell apart. Buttons that would not
+/// fit in full are left out, along with any after them.
+///
+/// # Arguments
+///
+/// * `actions` - `(label, id)` pairs in display order
+/// * `row` - The one-row area the buttons go in
+///
+/// # Returns
+///
+/// The rect of each button that fits, with its ID, in order.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::functions::fnc_layout_actions::layout_actions;
+/// use ratatui_notifications::notifications::ActionId;
+///
+/// let actions = [("Retry".to_string(), ActionId(1)), ("Dismiss".to_string(), ActionId(2))];
+/// let buttons = layout_actions(&actions, Rect::new(0, 0, 20, 1));
+/// assert_eq!(buttons, vec![(Rect::new(0, 0, 7, 1), ActionId(1)), (Rect::new(8, 0, 9, 1), ActionId(2))]);
+/// ```
+pub fn layout_actions(actions: &[(String, ActionId)], row: Rect) -> Vec<(Rect, ActionId)> {
+    let mut x = row.x;
+    let mut buttons = Vec::with_capacity(actions.len());
+
+    for (label, id) in actions {
+        let width = u16::try_from(label.width() + 2).unwrap_or(u16::MAX);
+        if x.saturating_add(width) > row.right() {
+            break;
+        }
+        buttons.push((Rect { x, width, height: 1, ..row }, *id));
+        x = x.saturating_add(width + 1);
+    }
+
+    buttons
+}
+
+// FILE: src/notifications/functions/fnc_layout_actions.rs - Places action buttons along a row
+// END OF VERSION: 1.0.0
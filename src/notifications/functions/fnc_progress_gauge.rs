@@ -0,0 +1,45 @@
+// FILE: src/notifications/functions/fnc_progress_gauge.rs - Builds the text of a one-row progress gauge
+// VERSION: 1.0.0
+// WCTX: Adding progress gauges
+// CLOG: Initial creation
+
+/// Builds a one-row progress gauge exactly `width` cells wide.
+///
+/// The bar fills `█` cells in proportion to `progress` over `░` cells,
+/// followed by a percentage label when there is room for it and at least
+/// two bar cells.
+///
+/// # Arguments
+///
+/// * `progress` - Completion fraction, clamped to `0.0..=1.0`
+/// * `width` - Cells available for the gauge
+///
+/// # Returns
+///
+/// The gauge text, empty when `width` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_progress_gauge::progress_gauge;
+///
+/// assert_eq!(progress_gauge(0.5, 10), "███░░░ 50%");
+/// assert_eq!(progress_gauge(0.5, 4), "██░░");
+/// ```
+pub fn progress_gauge(progress: f32, width: u16) -> String {
+    let progress = progress.clamp(0.0, 1.0);
+    let label = format!(" {}%", (progress * 100.0).round() as u16);
+
+    let width = usize::from(width);
+    let (bar, label) = if width >= label.len() + 2 {
+        (width - label.len(), label)
+    } else {
+        (width, String::new())
+    };
+
+    let filled = (progress * bar as f32).round() as usize;
+    format!("{}{}{}", "█".repeat(filled), "░".repeat(bar - filled), label)
+}
+
+// FILE: src/notifications/functions/fnc_progress_gauge.rs - Builds the text of a one-row progress gauge
+// END OF VERSION: 1.0.0
@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_resolve_styles.rs - Resolves notification styles based on level and custom overrides
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Success level and level themes
+// CLOG: Add Success border colour
 
 use crate::notifications::types::Level;
 use ratatui::style::{Color, Style};
@@ -13,6 +13,7 @@ const DEFAULT_BORDER_STYLE: Style = Style::new().fg(Color::DarkGray);
 
 // Level-based border styles
 const INFO_BORDER_STYLE: Style = Style::new().fg(Color::Green);
+const SUCCESS_BORDER_STYLE: Style = Style::new().fg(Color::LightGreen);
 const WARN_BORDER_STYLE: Style = Style::new().fg(Color::Yellow);
 const ERROR_BORDER_STYLE: Style = Style::new().fg(Color::Red);
 const DEBUG_BORDER_STYLE: Style = Style::new().fg(Color::Blue);
@@ -52,6 +53,7 @@ pub fn resolve_styles(
     if let Some(lvl) = level {
         let level_border_style = match lvl {
             Level::Info => INFO_BORDER_STYLE,
+            Level::Success => SUCCESS_BORDER_STYLE,
             Level::Warn => WARN_BORDER_STYLE,
             Level::Error => ERROR_BORDER_STYLE,
             Level::Debug => DEBUG_BORDER_STYLE,
@@ -81,4 +83,4 @@ pub fn resolve_styles(
 }
 
 // FILE: src/notifications/functions/fnc_resolve_styles.rs - Resolves notification styles based on level and custom overrides
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
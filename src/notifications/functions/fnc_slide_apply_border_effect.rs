@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_slide_apply_border_effect.rs - Applies vanishing edge border effect during slide animation
-// VERSION: 1.0.0
-// WCTX: Implementing slide animation functions with TDD
-// CLOG: Extracted from slide.rs:150-448, initial creation
+// VERSION: 1.0.1
+// WCTX: Motionless slides
+// CLOG: Handle SlideDirection::None
 
 use crate::notifications::functions::fnc_slide_offscreen_position::slide_offscreen_position;
 use crate::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction;
@@ -227,7 +227,7 @@ pub fn slide_apply_border_effect<'a>(
                 (2.0, 0.0)
             }
         }
-        SlideDirection::Default => (2.0, 0.0),
+        SlideDirection::Default | SlideDirection::None => (2.0, 0.0),
     };
 
     let apply_effect = match phase {
@@ -347,4 +347,4 @@ pub fn slide_apply_border_effect<'a>(
 }
 
 // FILE: src/notifications/functions/fnc_slide_apply_border_effect.rs - Applies vanishing edge border effect during slide animation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.0.1
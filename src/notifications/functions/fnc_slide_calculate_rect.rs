@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_slide_calculate_rect.rs - Calculates visible rect during slide animation
-// VERSION: 1.0.0
-// WCTX: Implementing slide animation functions with TDD
-// CLOG: Extracted from slide.rs:77-148, initial creation
+// VERSION: 1.0.1
+// WCTX: Rendering within a region
+// CLOG: Clamp to the area's right and bottom edges
 
 use crate::notifications::functions::fnc_slide_offscreen_position::slide_offscreen_position;
 use crate::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction;
@@ -107,8 +107,8 @@ pub fn slide_calculate_rect(
     let final_rect = Rect {
         x: final_x,
         y: final_y,
-        width: final_width.min(frame_area.width.saturating_sub(final_x)),
-        height: final_height.min(frame_area.height.saturating_sub(final_y)),
+        width: final_width.min(frame_area.right().saturating_sub(final_x)),
+        height: final_height.min(frame_area.bottom().saturating_sub(final_y)),
     };
 
     if final_rect.width > 0 && final_rect.height > 0 {
@@ -119,4 +119,4 @@ pub fn slide_calculate_rect(
 }
 
 // FILE: src/notifications/functions/fnc_slide_calculate_rect.rs - Calculates visible rect during slide animation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.0.1
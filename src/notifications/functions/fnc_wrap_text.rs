@@ -0,0 +1,132 @@
+// FILE: src/notifications/functions/fnc_wrap_text.rs - Word-wraps styled text to a cell width
+// VERSION: 1.1.0
+// WCTX: Display-width text layout
+// CLOG: Segment and measure with unicode-segmentation and unicode-width
+
+use ratatui::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Word-wraps `text` so that no line is wider than `width` cells.
+///
+/// Lines are split on whitespace and measured per grapheme cluster in
+/// display cells, so wide CJK characters and emoji count as two cells, and
+/// neither they nor combining marks are ever cut in half. Words longer than
+/// `width` are broken at grapheme boundaries. Whitespace at a wrap point is
+/// dropped. Span styles and line alignment carry over to the wrapped lines.
+///
+/// # Arguments
+///
+/// * `text` - The text to wrap
+/// * `width` - Maximum line width in cells
+///
+/// # Returns
+///
+/// The wrapped text (unchanged when `width` is 0).
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::Text;
+/// use ratatui_notifications::notifications::functions::fnc_wrap_text::wrap_text;
+///
+/// let wrapped = wrap_text(&Text::from("héllo wörld"), 6);
+/// assert_eq!(wrapped.lines.len(), 2);
+/// assert_eq!(wrapped.lines[1].to_string(), "wörld");
+/// ```
+pub fn wrap_text(text: &Text<'static>, width: u16) -> Text<'static> {
+    if width == 0 {
+        return text.clone();
+    }
+
+    let lines = text
+        .lines
+        .iter()
+        .flat_map(|line| {
+            wrap_line(line, usize::from(width))
+                .into_iter()
+                .map(|graphemes| to_line(graphemes, line))
+        })
+        .collect::<Vec<_>>();
+
+    Text { lines, ..text.clone() }
+}
+
+/// Splits one line into rows of `(grapheme, style)` pairs.
+fn wrap_line<'a>(line: &'a Line<'static>, width: usize) -> Vec<Vec<(&'a str, Style)>> {
+    let mut rows = vec![Vec::new()];
+    let mut row_width = 0;
+    let mut word: Vec<(&str, Style, usize)> = Vec::new();
+    let mut gap: Vec<(&str, Style, usize)> = Vec::new();
+
+    let graphemes = line.spans.iter().flat_map(|span| {
+        span.content
+            .graphemes(true)
+            .filter(|symbol| *symbol != "\n")
+            .map(|symbol| (symbol, span.style, symbol.width()))
+    });
+
+    for grapheme in graphemes.chain(std::iter::once((" ", Style::default(), 1))) {
+        if !grapheme.0.trim().is_empty() {
+            word.push(grapheme);
+            continue;
+        }
+
+        // A word just ended: place it after the gap before it, or on a new row
+        if !word.is_empty() {
+            let word_width: usize = word.iter().map(|g| g.2).sum();
+            let gap_width: usize = gap.iter().map(|g| g.2).sum();
+
+            if row_width == 0 {
+                // Indentation survives on the first row only
+                if rows.len() == 1 && gap_width + word_width <= width {
+                    place(&mut rows, &mut row_width, gap.drain(..), width);
+                }
+            } else if row_width + gap_width + word_width <= width {
+                place(&mut rows, &mut row_width, gap.drain(..), width);
+            } else {
+                rows.push(Vec::new());
+                row_width = 0;
+            }
+            gap.clear();
+            place(&mut rows, &mut row_width, word.drain(..), width);
+        }
+        gap.push(grapheme);
+    }
+
+    rows
+}
+
+/// Appends graphemes to the current row, starting new rows as it fills.
+fn place<'a>(
+    rows: &mut Vec<Vec<(&'a str, Style)>>,
+    row_width: &mut usize,
+    graphemes: impl Iterator<Item = (&'a str, Style, usize)>,
+    width: usize,
+) {
+    for (symbol, style, grapheme_width) in graphemes {
+        if *row_width + grapheme_width > width && *row_width > 0 {
+            rows.push(Vec::new());
+            *row_width = 0;
+        }
+        if let Some(row) = rows.last_mut() {
+            row.push((symbol, style));
+        }
+        *row_width += grapheme_width;
+    }
+}
+
+/// Rebuilds a row into a line, merging runs of equally styled graphemes.
+fn to_line(graphemes: Vec<(&str, Style)>, source: &Line<'static>) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (symbol, style) in graphemes {
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(symbol),
+            _ => spans.push(Span::styled(symbol.to_string(), style)),
+        }
+    }
+    Line { spans, style: source.style, alignment: source.alignment }
+}
+
+// FILE: src/notifications/functions/fnc_wrap_text.rs - Word-wraps styled text to a cell width
+// END OF VERSION: 1.1.0
@@ -1,21 +1,31 @@
 // FILE: src/notifications/functions/mod.rs - Functions module
-// VERSION: 1.14.0
-// WCTX: Adding code generation feature
-// CLOG: Added fnc_generate_code module
+// VERSION: 1.24.0
+// WCTX: Height-capped notifications
+// CLOG: Add fnc_clip_rows
 
+pub mod fnc_accessible_text;
+pub mod fnc_animated_icon_frame;
+pub mod fnc_apply_easing;
 pub mod fnc_calculate_anchor_position;
 pub mod fnc_calculate_rect;
 pub mod fnc_calculate_size;
+pub mod fnc_clip_rows;
+pub mod fnc_collapse_calculate_rect;
+pub mod fnc_connector_cells;
+pub mod fnc_dim_style;
 pub mod fnc_expand_calculate_rect;
 pub mod fnc_fade_calculate_rect;
 pub mod fnc_fade_interpolate_color;
 pub mod fnc_generate_code;
 pub mod fnc_get_level_icon;
+pub mod fnc_layout_actions;
+pub mod fnc_progress_gauge;
 pub mod fnc_resolve_styles;
 pub mod fnc_slide_apply_border_effect;
 pub mod fnc_slide_calculate_rect;
 pub mod fnc_slide_offscreen_position;
 pub mod fnc_slide_resolve_direction;
+pub mod fnc_wrap_text;
 
 // FILE: src/notifications/functions/mod.rs - Functions module
-// END OF VERSION: 1.14.0
+// END OF VERSION: 1.24.0
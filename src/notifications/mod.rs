@@ -1,7 +1,7 @@
 // FILE: src/notifications/mod.rs - Notifications module
-// VERSION: 1.7.0
-// WCTX: Adding code generation feature
-// CLOG: Added generate_code re-export
+// VERSION: 1.27.0
+// WCTX: Typed notification handles
+// CLOG: Export NotificationHandle
 
 pub mod types;
 pub mod functions;
@@ -9,13 +9,18 @@ pub(crate) mod classes;
 pub mod orc_stacking;
 pub mod orc_render;
 pub mod orc_manager;
+pub mod traits;
 
 // Re-export main types for convenient access
-pub use classes::{Notification, NotificationBuilder};
+pub use classes::{Notification, NotificationBuilder, NotificationHandle, NotificationState, RecordedEvent, Recording};
+#[cfg(feature = "tracing")]
+pub use classes::NotificationLayer;
 pub use orc_manager::Notifications;
+pub use traits::{NotificationRenderer, NotificationsExt};
 pub use types::{
-    Anchor, Animation, AnimationPhase, AutoDismiss, Level,
-    NotificationError, Overflow, SlideDirection, SizeConstraint, Timing,
+    ActionId, AddOutcome, Anchor, Animation, AnimationPhase, AutoDismiss, ClickTrigger, DebugStats, DismissReason, DismissalMetrics,
+    Easing, Hit, HitRegion, InitialRender, KindMode, Level, LevelStyle, LevelTheme, NotificationError, Overflow,
+    PhaseEvent, Shape, SlideDirection, SizeConstraint, StackDirection, Timing,
 };
 
 // Re-export layout utilities for custom positioning
@@ -27,4 +32,4 @@ pub use functions::fnc_calculate_size::calculate_size;
 pub use functions::fnc_generate_code::generate_code;
 
 // FILE: src/notifications/mod.rs - Notifications module
-// END OF VERSION: 1.7.0
+// END OF VERSION: 1.27.0
@@ -1,14 +1,106 @@
 // FILE: src/notifications/orc_manager.rs - Notifications manager orchestrator
-// VERSION: 1.0.0
-// WCTX: Implementing Notifications manager orchestrator using TDD
-// CLOG: Initial creation with manager coordination logic
+// VERSION: 1.73.1
+// WCTX: Dismiss callbacks run once
+// CLOG: Keep coalesced repeat callbacks
 
-use crate::notifications::classes::{Notification, NotificationState, ManagerDefaults};
-use crate::notifications::orc_render::render_notifications;
-use crate::notifications::types::{Anchor, NotificationError, Overflow};
-use ratatui::prelude::{Frame, Rect};
-use std::collections::HashMap;
-use std::time::Duration;
+use crate::notifications::classes::{
+    Notification, NotificationBuilder, NotificationHandle, NotificationState, ManagerDefaults, RecordedEvent, Recording,
+};
+#[cfg(feature = "tracing")]
+use crate::notifications::classes::NotificationLayer;
+use crate::notifications::functions::fnc_accessible_text::accessible_text;
+use crate::notifications::functions::fnc_calculate_anchor_position::calculate_anchor_position;
+use crate::notifications::functions::fnc_calculate_rect::calculate_rect;
+use crate::notifications::functions::fnc_connector_cells::connector_cells;
+use crate::notifications::orc_render::{render_notifications, RenderOptions};
+use crate::notifications::orc_stacking::{calculate_directed_stacking_positions, StackedNotification};
+use crate::notifications::traits::NotificationRenderer;
+use crate::notifications::types::{
+    ActionId, AddOutcome, Anchor, Animation, AnimationPhase, ClickTrigger, DebugStats, DismissReason, DismissalMetrics, Easing, Hit, HitRegion, InitialRender, KindMode, Level, LevelTheme,
+    NotificationError, Overflow, PhaseEvent, StackDirection,
+};
+use crossterm::event::{KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::prelude::{Frame, Line, Position, Rect, Style, Text};
+use ratatui::widgets::BorderType;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+#[cfg(feature = "tracing")]
+use std::sync::mpsc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Most phase events held between `take_events` calls; older ones are dropped.
+const MAX_PHASE_EVENTS: usize = 1024;
+
+/// Callback consulted before a notification auto-dismisses.
+struct DismissGuard(Box<dyn FnMut(&Notification) -> bool + Send>);
+
+impl std::fmt::Debug for DismissGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DismissGuard(..)")
+    }
+}
+
+/// Rates notifications for `Overflow::Scored`; the lowest is evicted.
+struct OverflowScorer(Box<dyn Fn(&Notification) -> i64 + Send>);
+
+impl std::fmt::Debug for OverflowScorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("OverflowScorer(..)")
+    }
+}
+
+/// Per-anchor budget of additions per window, clocked by `tick`.
+#[derive(Debug, Clone, Copy)]
+struct RateLimit {
+    max: usize,
+    window: Duration,
+    elapsed: Duration,
+    used: usize,
+}
+
+impl RateLimit {
+    /// Whether any additions are left this window.
+    fn has_budget(&self) -> bool {
+        self.used < self.max
+    }
+
+    /// Spends one addition from this window's budget.
+    fn take(&mut self) {
+        self.used += 1;
+    }
+
+    /// Advances the window clock, refilling the budget when it rolls over.
+    fn advance(&mut self, delta: Duration) {
+        self.elapsed += delta;
+        if self.elapsed >= self.window {
+            self.elapsed = Duration::ZERO;
+            self.used = 0;
+        }
+    }
+}
+
+/// Rewrites a notification's content lines each time it is drawn.
+#[allow(clippy::type_complexity)]
+struct LineTransform(Box<dyn FnMut(&Notification, Vec<Line<'static>>) -> Vec<Line<'static>> + Send>);
+
+impl std::fmt::Debug for LineTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LineTransform(..)")
+    }
+}
+
+/// Draws notifications in place of the built-in look.
+struct CustomRenderer(Box<dyn NotificationRenderer>);
+
+impl std::fmt::Debug for CustomRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomRenderer(..)")
+    }
+}
+
+/// Source of `Notifications::instance`, unique per manager in the process
+static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
 
 /// Manager for animated notifications.
 ///
@@ -45,8 +137,141 @@ pub struct Notifications {
     /// Maximum concurrent notifications per anchor (None = unlimited)
     max_concurrent: Option<usize>,
 
+    /// Maximum notifications across all anchors (None = unlimited)
+    max_total: Option<usize>,
+
     /// Overflow behavior when max_concurrent is reached
     overflow: Overflow,
+
+    /// Scoring function used by `Overflow::Scored`
+    overflow_scorer: Option<OverflowScorer>,
+
+    /// Whether overflow evictions are counted in a summary notification
+    overflow_summary: bool,
+
+    /// Summary notification ID and eviction count per anchor
+    summaries: HashMap<Anchor, (u64, usize)>,
+
+    /// Maximum notifications shown at once per anchor (extras wait in Pending)
+    max_visible: HashMap<Anchor, usize>,
+
+    /// Additions allowed per window at each rate-limited anchor
+    rate_limits: HashMap<Anchor, RateLimit>,
+
+    /// Entry animation for notifications that waited for a visible slot
+    promotion_animation: Option<Animation>,
+
+    /// Anchors drawn last when regions overlap, later entries on top
+    anchor_z_order: Vec<Anchor>,
+
+    /// Whether `add` measures the layout immediately instead of at first render
+    precompute: bool,
+
+    /// Frame area used for layouts measured at `add` time
+    precompute_area: Rect,
+
+    /// Anchor for notifications whose builder did not set one
+    default_anchor: Anchor,
+
+    /// Border for notifications whose builder didn't choose one
+    default_border: Option<BorderType>,
+
+    /// Where each notification was drawn by the last render, in draw order
+    drawn: Vec<StackedNotification>,
+
+    /// Bounding box of everything the last render drew, connectors included
+    damage: Option<Rect>,
+
+    /// Veto over auto-dismissal, consulted when a dwell timer expires
+    dismiss_guard: Option<DismissGuard>,
+
+    /// Rewrites content lines in the render path
+    line_transform: Option<LineTransform>,
+
+    /// Per-notification drawing installed by `with_renderer` (None = built-in)
+    renderer: Option<CustomRenderer>,
+
+    /// Dim applied per step back in a stack (0.0 = off)
+    depth_fade: f32,
+
+    /// Opacity below which depth-faded entries collapse (0.0 = off)
+    collapse_below: f32,
+
+    /// Draw without colours, tagging levels with text instead
+    monochrome: bool,
+
+    /// Set while `batch` runs; `add` then skips limit enforcement
+    in_batch: bool,
+
+    /// Mouse event that dismisses a clicked notification
+    click_trigger: ClickTrigger,
+
+    /// Whether hit-testing finds notifications still entering or exiting
+    hit_animating: bool,
+
+    /// Notification under the last left-button press, awaiting release
+    pressed: Option<u64>,
+
+    /// Global gate on drawing; ticking continues either way
+    render_enabled: bool,
+
+    /// Whether `tick` is frozen; drawing continues either way
+    paused: bool,
+
+    /// Whether the terminal has focus; `tick` is frozen while it doesn't
+    focused: bool,
+
+    /// Active count above which notifications render compactly, without motion
+    degrade_above: Option<usize>,
+
+    /// Growth direction overrides per anchor
+    stack_directions: HashMap<Anchor, StackDirection>,
+
+    /// Base style per anchor, beneath level and per-notification styles
+    anchor_styles: HashMap<Anchor, Style>,
+
+    /// Per-level colours and icons
+    level_theme: LevelTheme,
+
+    /// Operations captured since `start_recording`, if recording
+    recording: Option<Recording>,
+
+    /// Duration and easing of moves between stack positions (None = snap)
+    reflow: Option<(Duration, Easing)>,
+
+    /// Duration and easing of gauge fills after `set_progress` (None = snap)
+    progress_easing: Option<(Duration, Easing)>,
+
+    /// Notifications per page at each anchor (None = no paging)
+    page_size: Option<usize>,
+
+    /// Page shown at every anchor, clamped to each anchor's last page
+    page: usize,
+
+    /// Whether a notification of an existing kind replaces or updates it
+    kind_mode: KindMode,
+
+    /// Whether identical notifications at an anchor merge into one with a count
+    coalesce: bool,
+
+    /// How notifications added since the last tick are drawn
+    initial_render: InitialRender,
+
+    /// Counts of how notifications were removed since the last reset
+    dismissal_metrics: DismissalMetrics,
+
+    /// Notification with keyboard focus, moved by `focus_next`/`focus_prev`
+    focused_notification: Option<u64>,
+
+    /// Phase transitions from `tick`, drained by `take_events`
+    phase_events: VecDeque<PhaseEvent>,
+
+    /// Channel from `tracing_layer` layers, drained by `drain_pending`
+    #[cfg(feature = "tracing")]
+    pending: Option<(mpsc::Sender<Notification>, mpsc::Receiver<Notification>)>,
+
+    /// Identifies this manager in the handles it issues
+    instance: u64,
 }
 
 impl Notifications {
@@ -63,19 +288,117 @@ impl Notifications {
             next_id: 0,
             defaults: ManagerDefaults::default(),
             max_concurrent: None,
+            max_total: None,
             overflow: Overflow::default(),
+            overflow_scorer: None,
+            overflow_summary: false,
+            summaries: HashMap::new(),
+            max_visible: HashMap::new(),
+            rate_limits: HashMap::new(),
+            promotion_animation: None,
+            anchor_z_order: Vec::new(),
+            precompute: false,
+            precompute_area: Rect::new(0, 0, 80, 24),
+            default_anchor: Anchor::default(),
+            default_border: Some(BorderType::Rounded),
+            drawn: Vec::new(),
+            damage: None,
+            dismiss_guard: None,
+            line_transform: None,
+            renderer: None,
+            depth_fade: 0.0,
+            collapse_below: 0.0,
+            monochrome: false,
+            in_batch: false,
+            click_trigger: ClickTrigger::default(),
+            hit_animating: false,
+            pressed: None,
+            render_enabled: true,
+            paused: false,
+            focused: true,
+            degrade_above: None,
+            stack_directions: HashMap::new(),
+            anchor_styles: HashMap::new(),
+            level_theme: LevelTheme::default(),
+            recording: None,
+            reflow: None,
+            progress_easing: None,
+            page_size: None,
+            page: 0,
+            kind_mode: KindMode::default(),
+            coalesce: false,
+            initial_render: InitialRender::default(),
+            dismissal_metrics: DismissalMetrics::default(),
+            focused_notification: None,
+            phase_events: VecDeque::new(),
+            #[cfg(feature = "tracing")]
+            pending: None,
+            instance: NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed),
         }
     }
 
     /// Check if there is anything to be rendered, this is helpful for applications
     /// that generally have a low frame rate but want to speed that up when displaying
     /// a notification to enable smooth animations.
+    /// It stays `true` while a notification sits idle in its display phase;
+    /// see `needs_fast_frames` to speed up only while something animates.
     pub fn has_notification(&self) -> bool {
         self.states
             .iter()
             .any(|(_, v)| v.current_phase != crate::notifications::types::AnimationPhase::Finished)
     }
 
+    /// Check if a notification is moving, so frames need to come quickly.
+    ///
+    /// Finer-grained than `has_notification`, which stays `true` for as long
+    /// as anything is on screen: this is `true` only while a notification is
+    /// entering or exiting, about to start entering, or gliding to a new
+    /// stack position. Once every notification is sitting in its display
+    /// phase it returns `false` and a low frame rate can count the display
+    /// time down; it turns `true` again on the tick that starts an exit, so
+    /// keep slow frames short enough for that tick to come on time.
+    /// Animated icons and `time_color` only advance as fast as the caller
+    /// ticks. Always `false` while paused or unfocused, as `tick` does nothing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new();
+    /// let frame_time = if manager.needs_fast_frames() {
+    ///     Duration::from_millis(16)
+    /// } else {
+    ///     Duration::from_millis(250)
+    /// };
+    /// ```
+    pub fn needs_fast_frames(&self) -> bool {
+        if self.paused || !self.focused {
+            return false;
+        }
+
+        // Held and off-page notifications do not tick, so they cannot move
+        let mut held = self.find_held_pending();
+        held.extend(self.paginate().0);
+        self.states
+            .iter()
+            .filter(|(id, _)| !held.contains(id))
+            .any(|(_, state)| {
+                state.reflow.is_some()
+                    || state.progress_motion.is_some()
+                    || !matches!(state.current_phase, AnimationPhase::Dwelling | AnimationPhase::Finished)
+            })
+    }
+
+    /// Returns the number of notifications that have not finished,
+    /// including ones still waiting to enter.
+    pub fn active_count(&self) -> usize {
+        self.states
+            .values()
+            .filter(|state| state.current_phase != AnimationPhase::Finished)
+            .count()
+    }
+
     /// Sets the maximum number of concurrent notifications per anchor.
     ///
     /// # Arguments
@@ -93,6 +416,30 @@ impl Notifications {
         self
     }
 
+    /// Sets the maximum number of notifications across all anchors.
+    ///
+    /// Applies on top of `max_concurrent`, whichever limit is hit first. When
+    /// an add would take the total past `max`, the overflow behavior picks
+    /// its victim among every anchor, e.g. the oldest notification anywhere
+    /// with `Overflow::DiscardOldest`. With `Overflow::Queue`, extras wait
+    /// until the total drops below `max`.
+    ///
+    /// # Arguments
+    /// * `max` - Maximum notifications at once (None = unlimited)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new()
+    ///     .max_concurrent(Some(3))
+    ///     .max_total(Some(5));
+    /// ```
+    pub fn max_total(mut self, max: Option<usize>) -> Self {
+        self.max_total = max;
+        self
+    }
+
     /// Sets the overflow behavior when max_concurrent is reached.
     ///
     /// # Arguments
@@ -110,207 +457,2789 @@ impl Notifications {
         self
     }
 
-    /// Adds a notification and returns its unique ID.
+    /// Sets the scoring function used by `Overflow::Scored`.
     ///
-    /// If max_concurrent limit is reached for the notification's anchor,
-    /// applies the configured overflow behavior.
+    /// When an anchor overflows, the notification with the lowest score is
+    /// evicted, oldest first among equal scores. Combine whatever matters
+    /// (level severity, content, ...) into a single number.
     ///
-    /// # Arguments
-    /// * `notification` - The notification to add
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Overflow, Level};
     ///
-    /// # Returns
-    /// * `Ok(u64)` - The unique ID assigned to the notification
-    /// * `Err(NotificationError)` - If the notification is invalid
+    /// let manager = Notifications::new()
+    ///     .max_concurrent(Some(3))
+    ///     .overflow(Overflow::Scored)
+    ///     .overflow_scorer(Box::new(|n| match n.level() {
+    ///         Some(Level::Error) => 100,
+    ///         Some(Level::Warn) => 50,
+    ///         _ => 0,
+    ///     }));
+    /// ```
+    pub fn overflow_scorer(mut self, scorer: Box<dyn Fn(&Notification) -> i64 + Send>) -> Self {
+        self.overflow_scorer = Some(OverflowScorer(scorer));
+        self
+    }
+
+    /// Reports notifications that overflow discards in a summary notification.
+    ///
+    /// The first eviction at an anchor adds a warning there reading
+    /// "1 notification suppressed"; each further one raises the count and
+    /// restarts its display time. The summary is never evicted and does not
+    /// count toward `max_concurrent` or `max_total`. Once drops stop and it
+    /// times out or is removed, the count starts over.
     ///
     /// # Example
     /// ```no_run
-    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use ratatui_notifications::notifications::Notifications;
     ///
-    /// let mut manager = Notifications::new();
-    /// let notif = NotificationBuilder::new("Hello!").build().unwrap();
-    /// let id = manager.add(notif).unwrap();
+    /// let manager = Notifications::new()
+    ///     .max_concurrent(Some(3))
+    ///     .overflow_summary(true);
     /// ```
-    pub fn add(&mut self, notification: Notification) -> Result<u64, NotificationError> {
-        // Generate ID
-        let id = self.next_id;
-        self.next_id = self.next_id.checked_add(1).unwrap_or(0);
-
-        let anchor = notification.anchor;
-
-        // Check and enforce limits
-        self.enforce_limit(anchor);
-
-        // Create state
-        let state = NotificationState::new(id, notification, &self.defaults);
-
-        // Add to maps
-        self.states.insert(id, state);
-        self.by_anchor.entry(anchor).or_default().push(id);
+    pub fn overflow_summary(mut self, enabled: bool) -> Self {
+        self.overflow_summary = enabled;
+        self
+    }
 
-        Ok(id)
+    /// Sets the maximum number of notifications shown at once for an anchor.
+    ///
+    /// Unlike `max_concurrent`, this never discards anything: notifications
+    /// beyond the limit stay in state and wait (their animation and dismiss
+    /// timer do not start) until a visible one finishes, then they are
+    /// promoted oldest first. An overflow summary is drawn on top of the limit.
+    ///
+    /// # Arguments
+    /// * `anchor` - The anchor to limit
+    /// * `max` - Maximum notifications drawn at once at that anchor
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    ///
+    /// let manager = Notifications::new()
+    ///     .max_visible(Anchor::TopRight, 3);
+    /// ```
+    pub fn max_visible(mut self, anchor: Anchor, max: usize) -> Self {
+        self.max_visible.insert(anchor, max);
+        self
     }
 
-    /// Removes a notification by ID.
+    /// Limits how many notifications an anchor accepts per time window.
+    ///
+    /// Once `count` notifications have been added at `anchor`, further adds
+    /// fail with `NotificationError::RateLimited` until `window` has passed
+    /// since the window began. Time is measured by `tick`, so the window
+    /// does not roll over while paused. Every accepted `add` at the anchor
+    /// counts, including coalesced repeats and updates of a kind already
+    /// showing; one turned away as `Outranked` does not.
     ///
     /// # Arguments
-    /// * `id` - The notification ID to remove
+    /// * `anchor` - The anchor to throttle
+    /// * `max_per` - At most this many adds per this much ticked time
     ///
-    /// # Returns
-    /// * `true` - If the notification existed and was removed
-    /// * `false` - If the notification didn't exist
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new()
+    ///     .rate_limit(Anchor::BottomRight, (5, Duration::from_secs(2)));
+    /// ```
+    pub fn rate_limit(mut self, anchor: Anchor, max_per: (usize, Duration)) -> Self {
+        let (max, window) = max_per;
+        self.rate_limits.insert(anchor, RateLimit { max, window, elapsed: Duration::ZERO, used: 0 });
+        self
+    }
+
+    /// Sets the entry animation for notifications promoted after waiting.
+    ///
+    /// Notifications held back by `max_visible` enter with this animation
+    /// once a slot frees up, signalling that they queued, instead of their
+    /// own. Their exit animation is unchanged.
     ///
     /// # Example
     /// ```no_run
-    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use ratatui_notifications::notifications::{Notifications, Anchor, Animation};
     ///
-    /// let mut manager = Notifications::new();
-    /// let notif = NotificationBuilder::new("Test").build().unwrap();
-    /// let id = manager.add(notif).unwrap();
-    /// assert!(manager.remove(id));
+    /// let manager = Notifications::new()
+    ///     .max_visible(Anchor::TopRight, 3)
+    ///     .promotion_animation(Animation::ExpandCollapse);
     /// ```
-    pub fn remove(&mut self, id: u64) -> bool {
-        if let Some(state) = self.states.remove(&id) {
-            // Remove from anchor map
-            let anchor = state.notification.anchor;
-            if let Some(ids) = self.by_anchor.get_mut(&anchor) {
-                ids.retain(|&existing_id| existing_id != id);
-            }
-            true
-        } else {
-            false
-        }
+    pub fn promotion_animation(mut self, animation: Animation) -> Self {
+        self.promotion_animation = Some(animation);
+        self
     }
 
-    /// Removes all notifications.
+    /// Degrades rendering while more than `max` notifications are active.
+    ///
+    /// Above the threshold every notification is drawn as a one-row
+    /// `Shape::Pill` and appears in place instead of sliding or expanding,
+    /// so a burst stays cheap and compact. Normal rendering returns as soon
+    /// as the active count drops back to `max`.
     ///
     /// # Example
     /// ```no_run
     /// use ratatui_notifications::notifications::Notifications;
     ///
-    /// let mut manager = Notifications::new();
-    /// // ... add notifications ...
-    /// manager.clear();
+    /// let manager = Notifications::new().degrade_above(5);
     /// ```
-    pub fn clear(&mut self) {
-        self.states.clear();
-        self.by_anchor.clear();
+    pub fn degrade_above(mut self, max: usize) -> Self {
+        self.degrade_above = Some(max);
+        self
     }
 
-    /// Updates all notification animations.
+    /// Dims older notifications in each stack to give a sense of depth.
     ///
-    /// Call this once per frame with the elapsed time since the last update.
-    ///
-    /// # Arguments
-    /// * `delta` - Time elapsed since last tick
+    /// The newest notification at an anchor is drawn as-is; each older one
+    /// has its foreground blended a further `step` toward the background, so
+    /// with `0.25` the third-newest is half dimmed. The dim stops at 80%,
+    /// so even the oldest entries stay legible; use `collapse_faded` to drop
+    /// them instead. `0.0` turns it off.
     ///
     /// # Example
     /// ```no_run
     /// use ratatui_notifications::notifications::Notifications;
-    /// use std::time::Duration;
     ///
-    /// let mut manager = Notifications::new();
-    /// manager.tick(Duration::from_millis(16)); // ~60 FPS
+    /// let manager = Notifications::new().depth_fade(0.25);
     /// ```
-    pub fn tick(&mut self, delta: Duration) {
-        // Update all notification states
-        let states_to_update: Vec<u64> = self.states.keys().copied().collect();
-
-        for id in states_to_update {
-            if let Some(state) = self.states.get_mut(&id) {
-                state.update(delta);
-            }
-        }
-
-        // Remove finished notifications
-        let finished: Vec<u64> = self.states
-            .iter()
-            .filter_map(|(id, state)| {
-                if state.current_phase == crate::notifications::types::AnimationPhase::Finished {
-                    Some(*id)
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        for id in finished {
-            self.remove(id);
-        }
+    pub fn depth_fade(mut self, step: f32) -> Self {
+        self.depth_fade = step.max(0.0);
+        self
     }
 
-    /// Renders all active notifications to the frame.
+    /// Collapses notifications that `depth_fade` has dimmed past readability.
     ///
-    /// # Arguments
-    /// * `frame` - The frame to render to
-    /// * `area` - The area to render within
+    /// Entries whose opacity (`1.0` minus their accumulated fade) falls below
+    /// `min_opacity` are left out of the stack and counted in a "+N more"
+    /// label on the far edge of the farthest notification still shown. The
+    /// newest notification is never collapsed. `0.0` turns it off.
     ///
     /// # Example
     /// ```no_run
     /// use ratatui_notifications::notifications::Notifications;
-    /// use ratatui::backend::TestBackend;
-    /// use ratatui::Terminal;
-    ///
-    /// let mut manager = Notifications::new();
-    /// let backend = TestBackend::new(80, 24);
-    /// let mut terminal = Terminal::new(backend).unwrap();
     ///
-    /// terminal.draw(|frame| {
-    ///     manager.render(frame, frame.area());
-    /// }).unwrap();
+    /// // Keep the newest three: opacities 1.0, 0.75, 0.5
+    /// let manager = Notifications::new().depth_fade(0.25).collapse_faded(0.5);
     /// ```
-    pub fn render(&mut self, frame: &mut Frame<'_>, _area: Rect) {
-        render_notifications(&mut self.states, &self.by_anchor, frame, self.max_concurrent);
+    pub fn collapse_faded(mut self, min_opacity: f32) -> Self {
+        self.collapse_below = min_opacity.clamp(0.0, 1.0);
+        self
     }
 
-    /// Enforces max_concurrent limit for the given anchor.
+    /// Draws notifications without any colour, for terminals that lack it.
     ///
-    /// Removes oldest or newest notification as needed based on overflow behavior.
-    fn enforce_limit(&mut self, anchor: Anchor) {
-        if let Some(max) = self.max_concurrent {
-            let current_count = self.by_anchor
-                .get(&anchor)
-                .map_or(0, |ids| ids.len());
-
-            if current_count >= max {
-                // Remove one notification based on overflow behavior
-                let id_to_remove = match self.overflow {
-                    Overflow::DiscardOldest => self.find_oldest_at_anchor(anchor),
-                    Overflow::DiscardNewest => self.find_newest_at_anchor(anchor),
-                };
-
-                if let Some(id) = id_to_remove {
-                    self.remove(id);
-                }
-            }
-        }
+    /// Every cell a notification covers is left with the terminal's default
+    /// foreground and background, including text styled by the caller and
+    /// custom renderers. Levels are told apart by a tag such as `[ERROR]`
+    /// or `[WARN]` at the start of the title, or of the message when there
+    /// is no title. Modifiers such as bold are kept.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let no_color = std::env::var_os("NO_COLOR").is_some();
+    /// let manager = Notifications::new().monochrome(no_color);
+    /// ```
+    pub fn monochrome(mut self, enabled: bool) -> Self {
+        self.monochrome = enabled;
+        self
     }
 
-    /// Finds the oldest notification at the given anchor.
-    fn find_oldest_at_anchor(&self, anchor: Anchor) -> Option<u64> {
-        self.by_anchor
-            .get(&anchor)?
-            .iter()
-            .filter_map(|id| {
-                self.states
-                    .get(id)
-                    .map(|state| (id, state.created_at))
-            })
-            .min_by_key(|&(_, created_at)| created_at)
-            .map(|(&id, _)| id)
+    /// Sets whether `handle_click` dismisses on button press or release.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, ClickTrigger};
+    ///
+    /// let manager = Notifications::new().click_trigger(ClickTrigger::Release);
+    /// ```
+    pub fn click_trigger(mut self, trigger: ClickTrigger) -> Self {
+        self.click_trigger = trigger;
+        self
     }
 
-    /// Finds the newest notification at the given anchor.
-    fn find_newest_at_anchor(&self, anchor: Anchor) -> Option<u64> {
-        self.by_anchor
+    /// Sets whether notifications still entering or exiting can be hit.
+    ///
+    /// Off by default: `hit`, and with it `handle_click`, `action_at` and
+    /// `notification_at`, only find notifications in their display phase,
+    /// so a click on one that is sliding in or out is ignored rather than
+    /// dismissing it by accident.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new().hit_animating(true);
+    /// ```
+    pub fn hit_animating(mut self, enabled: bool) -> Self {
+        self.hit_animating = enabled;
+        self
+    }
+
+    /// Sets the anchor used for notifications that don't set one.
+    ///
+    /// An anchor set on the builder always overrides this. Defaults to
+    /// `Anchor::BottomRight`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    ///
+    /// let manager = Notifications::new()
+    ///     .default_anchor(Anchor::TopRight);
+    /// ```
+    pub fn default_anchor(mut self, anchor: Anchor) -> Self {
+        self.default_anchor = anchor;
+        self
+    }
+
+    /// Sets the border for notifications that don't choose one.
+    ///
+    /// `border_type()` or `borderless()` on the builder always override
+    /// this. Defaults to `Some(BorderType::Rounded)`; `None` draws
+    /// notifications as padded text.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui::widgets::BorderType;
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new()
+    ///     .default_border(Some(BorderType::Double));
+    /// ```
+    pub fn default_border(mut self, border: Option<BorderType>) -> Self {
+        self.default_border = border;
+        self
+    }
+
+    /// Sets the colours and icon used for each level.
+    ///
+    /// Notifications draw their level's border colour, body style and icon
+    /// unless they set `border_style`, `block_style` or `title_style`
+    /// themselves. Levels the theme does not remap keep the built-in look.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui_notifications::notifications::{Level, LevelStyle, LevelTheme, Notifications};
+    ///
+    /// let warn = LevelStyle { border: Style::new().fg(Color::Rgb(255, 200, 0)), ..LevelStyle::builtin(Level::Warn) };
+    /// let manager = Notifications::new().level_theme(LevelTheme::default().set(Level::Warn, warn));
+    /// ```
+    pub fn level_theme(mut self, theme: LevelTheme) -> Self {
+        self.level_theme = theme;
+        self
+    }
+
+    /// Sets a base style shared by every notification at an anchor.
+    ///
+    /// The style sits underneath everything else: level colours and styles
+    /// set on the notification itself patch over it, so it mostly supplies
+    /// whatever they leave unset (background, modifiers, content colour).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    /// use ratatui::style::{Color, Modifier, Style};
+    ///
+    /// let manager = Notifications::new()
+    ///     .anchor_style(Anchor::BottomCenter, Style::new().bg(Color::Black).add_modifier(Modifier::DIM));
+    /// ```
+    pub fn anchor_style(mut self, anchor: Anchor, style: Style) -> Self {
+        self.anchor_styles.insert(anchor, style);
+        self
+    }
+
+    /// Overrides which way the stack at an anchor grows.
+    ///
+    /// By default bottom anchors stack `Up` and all others `Down`. A middle
+    /// anchor grows either way from its middle row. A top anchor has no room
+    /// above it, so stacking it `Up` rests the stack on the bottom edge of
+    /// the same column, newest lowest (and a bottom anchor stacked `Down`
+    /// hangs from the top edge). Slides and collapses follow the moved
+    /// baseline, so new notifications push the others away from it. Limits,
+    /// styles and everything else keyed by anchor still apply to `anchor`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor, StackDirection};
+    ///
+    /// let manager = Notifications::new()
+    ///     .stack_direction(Anchor::MiddleRight, StackDirection::Up);
+    /// ```
+    pub fn stack_direction(mut self, anchor: Anchor, direction: StackDirection) -> Self {
+        self.stack_directions.insert(anchor, direction);
+        self
+    }
+
+    /// Animates notifications moving to a new stack position.
+    ///
+    /// A stack reflows when a notification leaves it, or when a new one
+    /// pushes the others along. With `Some((duration, easing))` the moved
+    /// notifications glide from where they were shown to their new position;
+    /// `None` (the default) snaps them there. Moves start at render time and
+    /// advance with `tick`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Easing};
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new()
+    ///     .reflow(Some((Duration::from_millis(200), Easing::EaseOutCubic)));
+    /// ```
+    pub fn reflow(mut self, reflow: Option<(Duration, Easing)>) -> Self {
+        self.reflow = reflow;
+        self
+    }
+
+    /// Animates progress gauges toward values set with `set_progress`.
+    ///
+    /// With `Some((duration, easing))` a gauge fills (or drains) from the
+    /// value it shows to the new one over `duration`, advancing with `tick`;
+    /// `None` (the default) snaps it. Only the drawn fill eases: reaching
+    /// `1.0` still completes the notification at once.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Easing};
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new()
+    ///     .progress_easing(Some((Duration::from_millis(300), Easing::EaseOutCubic)));
+    /// ```
+    pub fn progress_easing(mut self, easing: Option<(Duration, Easing)>) -> Self {
+        self.progress_easing = easing;
+        self
+    }
+
+    /// Splits each anchor's notifications into pages of `size`.
+    ///
+    /// Only the current page is drawn, with a "Page n/m" label (just "n/m"
+    /// on narrow boxes) on the notification nearest the anchor, so nothing
+    /// has to be discarded to keep stacks short. Off-page notifications are
+    /// frozen: their animation and dismiss timer resume when their page is
+    /// shown again. Pages run oldest first. `None` (the default) disables
+    /// paging.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new().page_size(Some(3));
+    /// // On a key press:
+    /// manager.next_page();
+    /// ```
+    pub fn page_size(mut self, size: Option<usize>) -> Self {
+        self.page_size = size.filter(|&size| size > 0);
+        self
+    }
+
+    /// Sets what `add` does with a notification whose kind is already showing.
+    ///
+    /// `KindMode::Replace` (the default) swaps the old notification for the
+    /// new one; `KindMode::Update` rewrites the old one in place, so repeated
+    /// status updates don't replay the entry animation. Notifications
+    /// without a kind are unaffected.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder, KindMode};
+    ///
+    /// let mut manager = Notifications::new().kind_mode(KindMode::Update);
+    /// let first = manager.add(NotificationBuilder::new("Saving 10%").kind("saving").build().unwrap()).unwrap();
+    /// let second = manager.add(NotificationBuilder::new("Saving 60%").kind("saving").build().unwrap()).unwrap();
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn kind_mode(mut self, mode: KindMode) -> Self {
+        self.kind_mode = mode;
+        self
+    }
+
+    /// Sets how a render between `add` and the next `tick` draws the new notification.
+    ///
+    /// By default (`InitialRender::Hidden`) it is skipped until the tick
+    /// starts its entry animation. `AtRest` shows it in place straight away
+    /// without an entry animation; `Animating` starts the entry animation at
+    /// render time. Notifications held back by `max_visible` or
+    /// `Overflow::Queue` stay hidden either way.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{InitialRender, Notifications};
+    ///
+    /// let manager = Notifications::new().initial_render(InitialRender::AtRest);
+    /// ```
+    pub fn initial_render(mut self, mode: InitialRender) -> Self {
+        self.initial_render = mode;
+        self
+    }
+
+    /// Merges repeats of a notification that is already showing.
+    ///
+    /// With coalescing on, `add` checks for a notification at the same anchor
+    /// with identical content and level that is not exiting. Instead of a new
+    /// entry, that one's repeat count goes up (drawn as an `(xN)` badge) and
+    /// its dismiss timer restarts; `add` returns its ID. Removing it removes
+    /// every repeat at once.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new().coalesce(true);
+    /// let first = manager.add(NotificationBuilder::new("Connection lost").build().unwrap()).unwrap();
+    /// let again = manager.add(NotificationBuilder::new("Connection lost").build().unwrap()).unwrap();
+    /// assert_eq!(first, again);
+    /// ```
+    pub fn coalesce(mut self, enable: bool) -> Self {
+        self.coalesce = enable;
+        self
+    }
+
+    /// Sets the order anchors are drawn in when their regions overlap.
+    ///
+    /// Later-listed anchors draw over earlier ones. Anchors not in the list
+    /// are drawn first, beneath every listed anchor.
+    ///
+    /// # Arguments
+    /// * `order` - Anchors from bottom-most to top-most
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    ///
+    /// // TopCenter notifications always paint over TopLeft ones
+    /// let manager = Notifications::new()
+    ///     .anchor_z_order(vec![Anchor::TopLeft, Anchor::TopCenter]);
+    /// ```
+    pub fn anchor_z_order(mut self, order: Vec<Anchor>) -> Self {
+        self.anchor_z_order = order;
+        self
+    }
+
+    /// Enables measuring each notification's layout when it is added.
+    ///
+    /// Wrapping and sizing then run once in `add`, against the area set by
+    /// `precompute_area` (80x24 by default), rather than lazily on the first
+    /// render. The cached size is reused while the render area has the same
+    /// dimensions, and the wrapped lines while the box's inner width is the
+    /// same; either is redone when it differs.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let manager = Notifications::new()
+    ///     .precompute(true)
+    ///     .precompute_area(Rect::new(0, 0, 120, 40));
+    /// ```
+    pub fn precompute(mut self, enable: bool) -> Self {
+        self.precompute = enable;
+        self
+    }
+
+    /// Sets the frame area used to measure layouts at `add` time.
+    ///
+    /// Only used when `precompute` is enabled; match it to your terminal size
+    /// so the first render hits the cache.
+    pub fn precompute_area(mut self, area: Rect) -> Self {
+        self.precompute_area = area;
+        self
+    }
+
+    /// Sets a callback that can veto auto-dismissal.
+    ///
+    /// The guard is called from `tick` when a notification's display time
+    /// runs out. Returning `true` lets it exit as usual; returning `false`
+    /// keeps it on screen and restarts its full display time, after which
+    /// the guard is asked again. Replaces any previously set guard.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Level};
+    ///
+    /// let mut manager = Notifications::new();
+    /// // Keep errors up until something else removes them
+    /// manager.set_dismiss_guard(Box::new(|n| n.level() != Some(Level::Error)));
+    /// ```
+    pub fn set_dismiss_guard(&mut self, guard: Box<dyn FnMut(&Notification) -> bool + Send>) {
+        self.dismiss_guard = Some(DismissGuard(guard));
+    }
+
+    /// Sets a function that rewrites each notification's content lines as it is drawn.
+    ///
+    /// Called every render with the notification and its content lines; the
+    /// returned lines are drawn instead (wrapping still applies). The stored
+    /// content and measured size are unchanged, so transforms should keep
+    /// line widths roughly the same. Useful for highlighting or redacting
+    /// secrets. Replaces any previously set transform.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui::text::{Line, Span};
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.set_line_transform(Box::new(|_, lines| {
+    ///     lines
+    ///         .into_iter()
+    ///         .map(|line| {
+    ///             let text = line.to_string().replace("hunter2", "*******");
+    ///             Line::from(Span::styled(text, line.style))
+    ///         })
+    ///         .collect()
+    /// }));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn set_line_transform(
+        &mut self,
+        transform: Box<dyn FnMut(&Notification, Vec<Line<'static>>) -> Vec<Line<'static>> + Send>,
+    ) {
+        self.line_transform = Some(LineTransform(transform));
+    }
+
+    /// Draws each notification with `renderer` instead of the built-in look.
+    ///
+    /// The manager still sizes, stacks and animates notifications; the
+    /// renderer is handed each one's rect and draws everything inside it.
+    /// Settings that only style the built-in look (level themes, depth fade,
+    /// line transforms, badges) are left to the renderer.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui::prelude::*;
+    /// use ratatui::widgets::{Block, Clear};
+    /// use ratatui_notifications::notifications::{Notification, NotificationRenderer, NotificationState, Notifications};
+    ///
+    /// struct Outline;
+    ///
+    /// impl NotificationRenderer for Outline {
+    ///     fn render(&self, notification: &Notification, _state: &NotificationState, frame: &mut Frame, rect: Rect) {
+    ///         frame.render_widget(Clear, rect);
+    ///         let block = Block::bordered().title(notification.content().to_string());
+    ///         frame.render_widget(block, rect);
+    ///     }
+    /// }
+    ///
+    /// let manager = Notifications::new().with_renderer(Outline);
+    /// ```
+    pub fn with_renderer(mut self, renderer: impl NotificationRenderer + 'static) -> Self {
+        self.renderer = Some(CustomRenderer(Box::new(renderer)));
+        self
+    }
+
+    /// Returns the size measured at `add` time for a notification, if any.
+    ///
+    /// `None` if the notification does not exist or `precompute` was off
+    /// when it was added.
+    pub fn precomputed_size(&self, id: u64) -> Option<(u16, u16)> {
+        self.states
+            .get(&id)?
+            .layout_cache
+            .map(|(_, size)| size)
+    }
+
+    /// Returns a notification's content as wrapped at `add` time, if any.
+    ///
+    /// Rendering draws these lines without wrapping again while the box's
+    /// inner width matches; a different width wraps (and caches) afresh.
+    /// `None` under the same conditions as `precomputed_size`, and for pills.
+    pub fn precomputed_lines(&self, id: u64) -> Option<&Text<'static>> {
+        self.states.get(&id)?.wrap_cache.as_ref().map(|(_, text)| text)
+    }
+
+    /// Adds a notification and returns its unique ID.
+    ///
+    /// If max_concurrent limit is reached for the notification's anchor,
+    /// applies the configured overflow behavior.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification to add
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The unique ID assigned to the notification
+    /// * `Err(NotificationError)` - If the notification is invalid
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Hello!").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    /// ```
+    pub fn add(&mut self, notification: Notification) -> Result<u64, NotificationError> {
+        self.add_with_eviction(notification).map(|outcome| outcome.id)
+    }
+
+    /// Adds a notification and returns a handle tied to this manager.
+    ///
+    /// Same as `add`, but the handle's `dismiss`, `remove` and `is_active`
+    /// do nothing when given another manager, where the bare ID could
+    /// match an unrelated notification.
+    ///
+    /// # Returns
+    /// * `Ok(NotificationHandle)` - Handle to the added notification
+    /// * `Err(NotificationError)` - If the notification is invalid
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let handle = manager.add_handle(NotificationBuilder::new("Hello!").build().unwrap()).unwrap();
+    /// assert!(handle.is_active(&manager));
+    /// ```
+    pub fn add_handle(&mut self, notification: Notification) -> Result<NotificationHandle, NotificationError> {
+        let id = self.add(notification)?;
+        Ok(NotificationHandle { id, manager: self.instance })
+    }
+
+    /// Adds a notification, also reporting which one overflow removed.
+    ///
+    /// Same as `add`, but returns the ID of the notification evicted to keep
+    /// the anchor within `max_concurrent` (or the total within `max_total`), or
+    /// `None` when nothing had to go.
+    /// Inside `batch` the limit is enforced when the batch ends, so no
+    /// eviction is reported here.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new().max_concurrent(Some(1));
+    /// manager.add(NotificationBuilder::new("First").build().unwrap()).unwrap();
+    /// let outcome = manager.add_with_eviction(NotificationBuilder::new("Second").build().unwrap()).unwrap();
+    /// if let Some(evicted) = outcome.evicted {
+    ///     println!("{evicted} made room for {}", outcome.id);
+    /// }
+    /// ```
+    pub fn add_with_eviction(&mut self, notification: Notification) -> Result<AddOutcome, NotificationError> {
+        // Over its anchor's rate budget: dropped
+        let anchor = notification.anchor.unwrap_or(self.default_anchor);
+        if self.rate_limits.get(&anchor).is_some_and(|limit| !limit.has_budget()) {
+            return Err(NotificationError::RateLimited);
+        }
+
+        // Only an accepted add spends the budget
+        let outcome = self.admit(notification)?;
+        if let Some(limit) = self.rate_limits.get_mut(&anchor) {
+            limit.take();
+        }
+        Ok(outcome)
+    }
+
+    /// Adds a notification that is within its rate budget: updates or
+    /// replaces its kind, coalesces a repeat, or inserts it under the limits.
+    fn admit(&mut self, notification: Notification) -> Result<AddOutcome, NotificationError> {
+        // A notification of a kind already showing replaces or updates it
+        let same_kind = self.find_kind(notification.kind());
+        if let (Some(id), KindMode::Update) = (same_kind, self.kind_mode) {
+            if let Some(recording) = self.recording.as_mut() {
+                recording.push(RecordedEvent::Add(id, Box::new(notification.clone())));
+            }
+            let mut notification = notification;
+            if !notification.border_explicit {
+                notification.border_type = self.default_border;
+            }
+            if let Some(state) = self.states.get_mut(&id) {
+                state.update_notification(notification, &self.defaults);
+                if self.precompute {
+                    state.precompute_layout(self.precompute_area);
+                }
+            }
+            return Ok(AddOutcome { id, evicted: None });
+        }
+
+        // A full anchor turns away notifications it would have to evict its
+        // betters for; checked before the same-kind one is replaced, counting
+        // its slot as free
+        let repeat = self.find_repeat(&notification, same_kind);
+        if repeat.is_none() && !self.in_batch && self.is_outranked(&notification, same_kind) {
+            return Err(NotificationError::Outranked);
+        }
+        if let Some(id) = same_kind {
+            self.remove_state(id, DismissReason::Replaced);
+        }
+
+        // A repeat of a showing notification bumps its count instead
+        if let Some(id) = repeat {
+            let mut notification = notification;
+            let callback = notification.on_dismiss.take();
+            if let Some(recording) = self.recording.as_mut() {
+                recording.push(RecordedEvent::Add(id, Box::new(notification)));
+            }
+            if let Some(state) = self.states.get_mut(&id) {
+                state.repeat();
+                // The repeat's callback runs when the one it folded into leaves
+                state.notification.on_dismiss = match (state.notification.on_dismiss.take(), callback) {
+                    (Some(existing), Some(callback)) => Some(existing.then(callback)),
+                    (existing, callback) => existing.or(callback),
+                };
+            }
+            return Ok(AddOutcome { id, evicted: None });
+        }
+
+        // Generate ID
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1).unwrap_or(0);
+
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::Add(id, Box::new(notification.clone())));
+        }
+
+        let mut notification = notification;
+        let anchor = *notification.anchor.get_or_insert(self.default_anchor);
+        if !notification.border_explicit {
+            notification.border_type = self.default_border;
+        }
+
+        // Check and enforce limits (deferred to the end of a batch)
+        let evicted = if self.in_batch { None } else { self.enforce_limit(anchor) };
+
+        self.insert_state(id, notification);
+        Ok(AddOutcome { id, evicted })
+    }
+
+    /// Creates the state for a new notification and puts it at the end of
+    /// its anchor's stack.
+    fn insert_state(&mut self, id: u64, notification: Notification) {
+        let anchor = notification.anchor();
+        let mut state = NotificationState::new(id, notification, &self.defaults);
+        state.set_monochrome(self.monochrome);
+        if self.precompute {
+            state.precompute_layout(self.precompute_area);
+        }
+
+        self.states.insert(id, state);
+        self.by_anchor.entry(anchor).or_default().push(id);
+    }
+
+    /// Counts a notification evicted at `anchor` in that anchor's summary,
+    /// adding the summary if none is showing there.
+    fn note_suppressed(&mut self, anchor: Anchor) {
+        let live = self
+            .summaries
+            .get(&anchor)
+            .copied()
+            .filter(|(id, _)| self.by_anchor.get(&anchor).is_some_and(|ids| ids.contains(id)))
+            .filter(|(id, _)| self.states.get(id).is_some_and(|state| !state.is_exiting()));
+        let count = live.map_or(1, |(_, count)| count + 1);
+        let message = match count {
+            1 => "1 notification suppressed".to_string(),
+            count => format!("{count} notifications suppressed"),
+        };
+
+        if let Some((id, _)) = live {
+            self.modify_in_place(id, |notification| notification.content = Text::from(message));
+            if let Some(state) = self.states.get_mut(&id).filter(|state| state.current_phase == AnimationPhase::Dwelling) {
+                state.restart_dwell();
+            }
+            self.summaries.insert(anchor, (id, count));
+            return;
+        }
+
+        let Ok(mut summary) = NotificationBuilder::new(message).anchor(anchor).level(Level::Warn).build() else {
+            return;
+        };
+        summary.border_type = self.default_border;
+        let id = self.next_id;
+        self.next_id = self.next_id.checked_add(1).unwrap_or(0);
+        self.insert_state(id, summary);
+        self.summaries.insert(anchor, (id, count));
+    }
+
+    /// Adds several notifications in order, returning each one's result.
+    ///
+    /// Equivalent to calling `add` for each notification in turn: overflow
+    /// is applied after every add, so extending a full anchor evicts exactly
+    /// what the loop would, and an item that fails does not stop the rest.
+    /// Wrap the call in `batch` to enforce `max_concurrent` once at the end
+    /// instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let results = manager.extend(
+    ///     ["Build finished", "12 tests passed", "Deployed"]
+    ///         .map(|message| NotificationBuilder::new(message).build().unwrap()),
+    /// );
+    /// assert!(results.iter().all(Result::is_ok));
+    /// ```
+    pub fn extend(
+        &mut self,
+        notifications: impl IntoIterator<Item = Notification>,
+    ) -> Vec<Result<u64, NotificationError>> {
+        notifications.into_iter().map(|notification| self.add(notification)).collect()
+    }
+
+    /// Reports how much state the manager is holding, for diagnostics.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new();
+    /// let stats = manager.debug_stats();
+    /// assert_eq!(stats.states, 0);
+    /// ```
+    pub fn debug_stats(&self) -> DebugStats {
+        DebugStats {
+            states: self.states.len(),
+            queued: self.find_held_pending().len(),
+            anchor_entries: self.by_anchor.values().map(Vec::len).sum(),
+            ids_issued: self.next_id,
+        }
+    }
+
+    /// Returns counts of how notifications were removed, by reason.
+    ///
+    /// Every removal counts once (expiry, `remove`, clicks, overflow,
+    /// `clear`, kind replacement), plus each expiry the dismiss guard vetoed.
+    /// Counts accumulate until `reset_dismissal_metrics`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new();
+    /// let metrics = manager.dismissal_metrics();
+    /// println!("{} of {} expired", metrics.expired, metrics.total());
+    /// ```
+    pub fn dismissal_metrics(&self) -> DismissalMetrics {
+        self.dismissal_metrics
+    }
+
+    /// Sets every dismissal count back to zero, e.g. after exporting them.
+    pub fn reset_dismissal_metrics(&mut self) {
+        self.dismissal_metrics = DismissalMetrics::default();
+    }
+
+    /// Drains the animation phase transitions recorded by `tick`.
+    ///
+    /// Every tick that moves a notification to a new phase records one
+    /// `PhaseEvent`, oldest first, including the final move to `Finished`
+    /// just before the notification is removed. A dwell restarted by the
+    /// dismiss guard records nothing. The buffer is emptied by each call and
+    /// holds at most the newest 1024 events, so an app that never polls
+    /// loses the oldest ones rather than growing without bound.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.tick(Duration::from_millis(16));
+    /// for event in manager.take_events() {
+    ///     if event.to == AnimationPhase::Dwelling {
+    ///         println!("notification {} is fully shown", event.id);
+    ///     }
+    /// }
+    /// ```
+    pub fn take_events(&mut self) -> Vec<PhaseEvent> {
+        self.phase_events.drain(..).collect()
+    }
+
+    /// Returns each active notification as unstyled text, oldest first.
+    ///
+    /// Lines look like `"Error: Disk full"` and are meant for screen
+    /// readers, text-to-speech or logs. Hidden and finished notifications
+    /// are left out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder, Level};
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.add(NotificationBuilder::new("Disk full").level(Level::Error).build().unwrap()).unwrap();
+    /// assert_eq!(manager.accessible_text(), vec!["Error: Disk full"]);
+    /// ```
+    pub fn accessible_text(&self) -> Vec<String> {
+        let mut states: Vec<&NotificationState> = self
+            .states
+            .values()
+            .filter(|state| state.visible && state.current_phase != AnimationPhase::Finished)
+            .collect();
+        states.sort_unstable_by_key(|state| state.id);
+
+        states
+            .into_iter()
+            .map(|state| accessible_text(&state.notification))
+            .collect()
+    }
+
+    /// Iterates over the notifications that have not finished, oldest first.
+    ///
+    /// Each item carries the ID, the notification and its live state (phase
+    /// and remaining display time), e.g. to mirror the toasts in a history
+    /// panel. Ties in creation time are ordered by ID, so the order is stable
+    /// from frame to frame.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new();
+    /// for (id, notification, state) in manager.iter() {
+    ///     println!("{id}: {:?} {:?}", notification.level(), state.phase());
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &Notification, &NotificationState)> {
+        let mut states: Vec<&NotificationState> = self
+            .states
+            .values()
+            .filter(|state| state.current_phase != AnimationPhase::Finished)
+            .collect();
+        states.sort_unstable_by_key(|state| (state.created_at, state.id));
+
+        states.into_iter().map(|state| (state.id, &state.notification, state))
+    }
+
+    /// Returns the number of notifications `iter` yields; same as `active_count`.
+    pub fn len(&self) -> usize {
+        self.active_count()
+    }
+
+    /// Returns whether there are no active notifications.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates a `tracing` layer whose events become notifications here.
+    ///
+    /// Events are queued on a channel, never applied directly, so the layer
+    /// can run on any thread; they are added on the thread that calls
+    /// `drain_pending`. Every layer from the same manager shares one queue.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use tracing_subscriber::layer::SubscriberExt;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let subscriber = tracing_subscriber::registry().with(manager.tracing_layer());
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn tracing_layer(&mut self) -> NotificationLayer {
+        let (sender, _) = self.pending.get_or_insert_with(mpsc::channel);
+        NotificationLayer::new(sender.clone())
+    }
+
+    /// Adds the notifications queued by `tracing_layer` layers, returning how many.
+    ///
+    /// Call it once per frame, on the UI thread, before `tick`.
+    #[cfg(feature = "tracing")]
+    pub fn drain_pending(&mut self) -> usize {
+        let queued: Vec<Notification> = match &self.pending {
+            Some((_, receiver)) => receiver.try_iter().collect(),
+            None => return 0,
+        };
+        queued.into_iter().filter_map(|notification| self.add(notification).ok()).count()
+    }
+
+    /// Copies the notifications that are still showing or waiting, oldest first.
+    ///
+    /// Notifications already exiting are left out. Animation timers are not
+    /// part of a `Notification`, so passing the snapshot to `restore` shows
+    /// each one afresh with its entry animation and full display time.
+    /// Copies share their `on_dismiss` callback with the originals, and it
+    /// runs only for whichever leaves a manager first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let old = Notifications::new();
+    /// // ... before a reload: keep only what the user pinned ...
+    /// let kept: Vec<_> = old.snapshot().into_iter().filter(|n| n.sticky()).collect();
+    ///
+    /// let mut reloaded = Notifications::new();
+    /// reloaded.restore(kept).unwrap();
+    /// ```
+    pub fn snapshot(&self) -> Vec<Notification> {
+        self.iter()
+            .filter(|(_, _, state)| !state.is_exiting())
+            .map(|(_, notification, _)| notification.clone())
+            .collect()
+    }
+
+    /// Adds each notification from a `snapshot`, in order, returning the new IDs.
+    ///
+    /// # Errors
+    /// Returns the first error raised by `add`; notifications before it stay added.
+    pub fn restore(&mut self, notifications: Vec<Notification>) -> Result<Vec<u64>, NotificationError> {
+        notifications.into_iter().map(|notification| self.add(notification)).collect()
+    }
+
+    /// Returns the IDs of notifications matching a predicate, in ascending order.
+    ///
+    /// Useful for bulk operations such as removing every error notification.
+    ///
+    /// # Arguments
+    /// * `predicate` - Called with each notification's configuration
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Level};
+    ///
+    /// let mut manager = Notifications::new();
+    /// for id in manager.find_by(|n| n.level() == Some(Level::Error)) {
+    ///     manager.remove(id);
+    /// }
+    /// ```
+    pub fn find_by(&self, predicate: impl Fn(&Notification) -> bool) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .states
+            .values()
+            .filter(|state| predicate(&state.notification))
+            .map(|state| state.id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the IDs of notifications of the given kind, in ascending order.
+    ///
+    /// Shorthand for `find_by(|n| n.kind() == Some(kind))`. Besides the one
+    /// showing, this includes any of that kind still exiting.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// for id in manager.by_kind("upload") {
+    ///     manager.dismiss(id);
+    /// }
+    /// ```
+    pub fn by_kind(&self, kind: &str) -> Vec<u64> {
+        self.find_by(|notification| notification.kind() == Some(kind))
+    }
+
+    /// Returns how long a notification stays before it starts exiting.
+    ///
+    /// The countdown is the display time that runs only while dwelling, so
+    /// it holds at the full budget during the entry animation and is zero
+    /// once the exit has begun. Returns `None` for unknown IDs and for
+    /// notifications that never auto-dismiss (sticky or `AutoDismiss::Never`).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    /// if let Some(left) = manager.remaining(id) {
+    ///     println!("closing in {:.1}s", left.as_secs_f32());
+    /// }
+    /// ```
+    pub fn remaining(&self, id: u64) -> Option<Duration> {
+        let state = self.states.get(&id)?;
+        let remaining = state.remaining_display_time?;
+        Some(if state.is_exiting() { Duration::ZERO } else { remaining })
+    }
+
+    /// Returns `remaining` as a share of the display time, from `1.0` down
+    /// to `0.0`, e.g. to size a countdown gauge.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    /// let bar_width = manager.remaining_fraction(id).map(|left| (left * 20.0).round() as u16);
+    /// ```
+    pub fn remaining_fraction(&self, id: u64) -> Option<f32> {
+        let remaining = self.remaining(id)?;
+        let total = self.states.get(&id)?.display_time?;
+        if total.is_zero() {
+            return Some(0.0);
+        }
+        Some((remaining.as_secs_f32() / total.as_secs_f32()).clamp(0.0, 1.0))
+    }
+
+    /// Ticks exactly as far as needed for a notification to reach `phase`.
+    ///
+    /// Meant for tests: instead of summing animation and dwell times by
+    /// hand, advance straight to a transition and assert on it. Each step
+    /// ticks the time left in the current phase, so other notifications
+    /// advance by the same amount. Reaching `Finished` removes the
+    /// notification, as a normal tick would.
+    ///
+    /// # Returns
+    /// The total time ticked, or `None` if the phase cannot be reached: the
+    /// ID is unknown, the phase is behind the current one or belongs to a
+    /// different animation, or the notification is held (paused manager,
+    /// queued, frozen or never dismissing). Time ticked before that turned
+    /// out is not undone.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AnimationPhase, Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    /// manager.tick_to_phase(id, AnimationPhase::Dwelling);
+    /// ```
+    pub fn tick_to_phase(&mut self, id: u64, phase: AnimationPhase) -> Option<Duration> {
+        // Pending, entry, dwell and exit take one step each
+        const MAX_STEPS: usize = 4;
+
+        let mut total = Duration::ZERO;
+        for _ in 0..MAX_STEPS {
+            let Some(state) = self.states.get(&id) else {
+                return (phase == AnimationPhase::Finished).then_some(total);
+            };
+            let current = state.current_phase;
+            if current == phase {
+                return Some(total);
+            }
+            if phase_rank(current) >= phase_rank(phase) {
+                return None;
+            }
+
+            let step = match current {
+                AnimationPhase::Pending => Duration::ZERO,
+                AnimationPhase::Dwelling if state.frozen => return None,
+                AnimationPhase::Dwelling => state.remaining_display_time?,
+                AnimationPhase::Finished => return None,
+                _ => {
+                    let duration = if state.is_exiting() { state.actual_exit_duration } else { state.actual_entry_duration };
+                    let progress = state.animation_progress;
+                    if progress <= 0.0 {
+                        duration
+                    } else {
+                        // Float progress can land just short of 1.0; round the step up
+                        duration.mul_f64(1.0 - f64::from(progress)) + Duration::from_micros(1)
+                    }
+                }
+            };
+
+            self.tick(step);
+            if self.states.get(&id).is_some_and(|state| state.current_phase == current) {
+                return None;
+            }
+            total += step;
+        }
+        None
+    }
+
+    /// Returns how many more notifications fit at an anchor before
+    /// `max_concurrent` is exceeded and the overflow behavior kicks in.
+    ///
+    /// Every notification not yet finished counts, including exiting and
+    /// queued ones. Returns `None` when the anchor is unlimited.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor};
+    ///
+    /// let manager = Notifications::new().max_concurrent(Some(3));
+    /// if manager.remaining_capacity(Anchor::BottomRight) == Some(1) {
+    ///     // show "1 slot left"
+    /// }
+    /// ```
+    pub fn remaining_capacity(&self, anchor: Anchor) -> Option<usize> {
+        let used = self
+            .by_anchor
+            .get(&anchor)
+            .into_iter()
+            .flatten()
+            .filter(|id| self.states.get(id).is_some_and(|state| state.current_phase != AnimationPhase::Finished))
+            .count();
+        let at_anchor = self.max_concurrent.map(|max| max.saturating_sub(used));
+        let overall = self.max_total.map(|max| max.saturating_sub(self.active_count()));
+        at_anchor.into_iter().chain(overall).min()
+    }
+
+    /// Returns how many notifications at an anchor are waiting for a slot.
+    ///
+    /// Non-zero with `Overflow::Queue` once `max_concurrent` is reached, or
+    /// with `max_visible`. Useful for a "+3 more" indicator.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Anchor, Overflow};
+    ///
+    /// let manager = Notifications::new().max_concurrent(Some(3)).overflow(Overflow::Queue);
+    /// let waiting = manager.queued_len(Anchor::BottomRight);
+    /// ```
+    pub fn queued_len(&self, anchor: Anchor) -> usize {
+        let Some(ids) = self.by_anchor.get(&anchor) else {
+            return 0;
+        };
+        let held = self.find_held_pending();
+        ids.iter().filter(|id| held.contains(id)).count()
+    }
+
+    /// Shows or hides a notification without removing it.
+    ///
+    /// Only drawing is affected: a hidden notification keeps animating and
+    /// its dismiss timer keeps running, so it still expires on schedule.
+    /// Hidden notifications take no space in their anchor's stack.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Hidden").build().unwrap()).unwrap();
+    /// manager.set_visible(id, false);
+    /// ```
+    pub fn set_visible(&mut self, id: u64, visible: bool) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.visible = visible;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shows the next page when paging is enabled.
+    ///
+    /// Stops at the last page of the anchor with the most pages; anchors with
+    /// fewer pages stay on their last one.
+    pub fn next_page(&mut self) {
+        let last = self.page_count().saturating_sub(1);
+        self.page = (self.page + 1).min(last);
+    }
+
+    /// Shows the previous page when paging is enabled, stopping at the first.
+    pub fn prev_page(&mut self) {
+        self.page = self.page.saturating_sub(1).min(self.page_count().saturating_sub(1));
+    }
+
+    /// Picks the anchor whose notifications would cover `rect` the least.
+    ///
+    /// Each anchor's region is its current stack plus room for a new
+    /// notification a third of `area` in each direction. The anchor whose
+    /// region overlaps `rect` least wins; ties go to the anchor farthest
+    /// from `rect`, then to declaration order.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let editor = Rect::new(40, 0, 40, 12);
+    /// let anchor = manager.best_anchor_avoiding(editor, Rect::new(0, 0, 80, 24));
+    /// manager.add(NotificationBuilder::new("Built").anchor(anchor).build().unwrap()).unwrap();
+    /// ```
+    pub fn best_anchor_avoiding(&self, rect: Rect, area: Rect) -> Anchor {
+        const ANCHORS: [Anchor; 9] = [
+            Anchor::TopLeft,
+            Anchor::TopCenter,
+            Anchor::TopRight,
+            Anchor::MiddleLeft,
+            Anchor::MiddleCenter,
+            Anchor::MiddleRight,
+            Anchor::BottomLeft,
+            Anchor::BottomCenter,
+            Anchor::BottomRight,
+        ];
+
+        // Centres in doubled coordinates to stay in integers
+        let center = |r: Rect| (2 * i64::from(r.x) + i64::from(r.width), 2 * i64::from(r.y) + i64::from(r.height));
+        let (avoid_x, avoid_y) = center(rect);
+        let (probe_width, probe_height) = ((area.width / 3).max(1), (area.height / 3).max(1));
+
+        ANCHORS
+            .into_iter()
+            .min_by_key(|&anchor| {
+                let direction = self.stack_direction_at(anchor);
+                let baseline = direction.baseline_anchor(anchor);
+                let probe = calculate_rect(
+                    baseline,
+                    calculate_anchor_position(baseline, area),
+                    probe_width,
+                    probe_height,
+                    area,
+                    0,
+                );
+                let region = self
+                    .by_anchor
+                    .get(&anchor)
+                    .map(|ids| {
+                        calculate_directed_stacking_positions(&self.states, anchor, direction, ids, area, self.max_concurrent)
+                    })
+                    .unwrap_or_default()
+                    .iter()
+                    .fold(probe, |region, stacked| region.union(stacked.rect));
+
+                let (x, y) = center(region);
+                let distance = (x - avoid_x).pow(2) + (y - avoid_y).pow(2);
+                (region.intersection(rect).area(), Reverse(distance))
+            })
+            .unwrap_or(self.default_anchor)
+    }
+
+    /// Pins a notification to the anchor end of its stack, or unpins it.
+    ///
+    /// Pinned notifications are placed first at their anchor whatever their
+    /// age, and are never the ones dropped by `max_concurrent` at render
+    /// time. Several pinned notifications keep their relative order by age,
+    /// oldest nearest the anchor.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder, AutoDismiss};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let recording = NotificationBuilder::new("● REC")
+    ///     .auto_dismiss(AutoDismiss::Never)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(recording).unwrap();
+    /// manager.pin_top(id, true);
+    /// ```
+    pub fn pin_top(&mut self, id: u64, pinned: bool) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.pinned_top = pinned;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Updates a progress notification's completion fraction.
+    ///
+    /// The value is clamped to `0.0..=1.0` and drawn as a gauge, easing
+    /// toward it if `progress_easing` is set. Reaching
+    /// `1.0` starts the exit, as if the notification had been dismissed;
+    /// after its `progress_linger` if set, or never for `sticky` ones
+    /// without a linger.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Uploading").progress(0.0).build().unwrap()).unwrap();
+    /// manager.set_progress(id, 0.4);
+    /// ```
+    pub fn set_progress(&mut self, id: u64, progress: f32) -> bool {
+        self.set_progress_many(&[(id, progress)]) == 1
+    }
+
+    /// Applies several progress updates in one call.
+    ///
+    /// Every value is stored first; notifications that reached `1.0` then
+    /// complete together (see `set_progress`), so a completed entry never reflows the
+    /// stack in the middle of the batch. Unknown IDs are skipped.
+    ///
+    /// # Returns
+    /// The number of notifications updated
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let a = manager.add(NotificationBuilder::new("a.txt").progress(0.0).build().unwrap()).unwrap();
+    /// let b = manager.add(NotificationBuilder::new("b.txt").progress(0.0).build().unwrap()).unwrap();
+    /// manager.set_progress_many(&[(a, 0.5), (b, 1.0)]);
+    /// ```
+    pub fn set_progress_many(&mut self, updates: &[(u64, f32)]) -> usize {
+        let mut updated = 0;
+        let mut completed = Vec::new();
+
+        for &(id, progress) in updates {
+            if let Some(state) = self.states.get_mut(&id) {
+                let progress = progress.clamp(0.0, 1.0);
+                state.set_progress(progress, self.progress_easing);
+                if progress >= 1.0 {
+                    completed.push(id);
+                }
+                updated += 1;
+            }
+        }
+
+        for id in completed {
+            if let Some(state) = self.states.get_mut(&id) {
+                state.complete_progress();
+            }
+        }
+
+        updated
+    }
+
+    /// Changes a notification's level in place.
+    ///
+    /// Only the level (and with it the colours and icon) changes: the
+    /// notification keeps its timers, phase and anchor. Cheaper than `update`
+    /// for one-attribute tweaks such as keybindings in a demo.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Disk 85% full").build().unwrap()).unwrap();
+    /// manager.retint(id, Level::Warn);
+    /// ```
+    pub fn retint(&mut self, id: u64, level: Level) -> bool {
+        self.modify_in_place(id, |notification| notification.level = Some(level))
+    }
+
+    /// Replaces a notification's body text in place.
+    ///
+    /// Unlike `update`, the dwell countdown is not restarted and nothing else
+    /// about the notification changes. The size is measured again on the
+    /// next render, so longer text grows the box instead of being clipped.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Connecting").build().unwrap()).unwrap();
+    /// manager.set_message(id, "Connected to build server");
+    /// ```
+    pub fn set_message(&mut self, id: u64, message: impl Into<Text<'static>>) -> bool {
+        let message = message.into();
+        self.modify_in_place(id, |notification| notification.content = message)
+    }
+
+    /// Applies `change` to a stored notification and forgets its measured size.
+    fn modify_in_place(&mut self, id: u64, change: impl FnOnce(&mut Notification)) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+
+        change(&mut state.notification);
+        state.reset_layout();
+        if self.precompute {
+            state.precompute_layout(self.precompute_area);
+        }
+        true
+    }
+
+    /// Draws a connector from a notification toward a screen cell.
+    ///
+    /// The line leaves the box edge facing the target and stops just short
+    /// of it, following the box as it animates. Cells outside the frame are
+    /// skipped. Useful for tooltips that refer to something on screen.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Unsaved").build().unwrap()).unwrap();
+    /// manager.point_at(id, 12, 3);
+    /// ```
+    pub fn point_at(&mut self, id: u64, col: u16, row: u16) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.pointer = Some(Position::new(col, row));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies several mutations, enforcing `max_concurrent` once at the end.
+    ///
+    /// Normally each `add` evicts immediately when its anchor is full, even
+    /// if a later `remove` in the same update would have made room. Inside
+    /// `batch` the closure's adds never evict; afterwards each anchor is
+    /// trimmed back to the limit using the configured overflow behavior.
+    ///
+    /// # Returns
+    /// Whatever the closure returns
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new().max_concurrent(Some(2));
+    /// let stale = manager.add(NotificationBuilder::new("Stale").build().unwrap()).unwrap();
+    /// manager.add(NotificationBuilder::new("Keep").build().unwrap()).unwrap();
+    ///
+    /// manager.batch(|m| {
+    ///     m.add(NotificationBuilder::new("Fresh").build().unwrap()).unwrap();
+    ///     m.remove(stale);
+    /// });
+    /// ```
+    pub fn batch<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let was_in_batch = std::mem::replace(&mut self.in_batch, true);
+        let result = f(self);
+        self.in_batch = was_in_batch;
+
+        if !self.in_batch {
+            let anchors: Vec<Anchor> = self.by_anchor.keys().copied().collect();
+            for anchor in anchors {
+                self.trim_to_limit(anchor);
+            }
+            self.trim_to_total();
+        }
+
+        result
+    }
+
+    /// Turns all drawing on or off without pausing anything.
+    ///
+    /// While disabled, `render` draws nothing (and `hit` finds nothing), but
+    /// `tick` keeps animating and expiring notifications on schedule. Useful
+    /// for a "hold key to show notifications" toggle.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.render_enabled(false);
+    /// ```
+    pub fn render_enabled(&mut self, enabled: bool) {
+        self.render_enabled = enabled;
+    }
+
+    /// Freezes every notification's animation and dismiss timer.
+    ///
+    /// While paused, `tick` does nothing, so notifications stay exactly as
+    /// they are and keep rendering. Useful while a modal covers them.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.pause(); // modal opened
+    /// manager.resume(); // modal closed
+    /// ```
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes ticking from where `pause` froze it.
+    ///
+    /// Time spent paused is never counted: the next `tick` advances by its
+    /// own delta only.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns whether the manager is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Freezes timers while the terminal is unfocused and resumes on focus.
+    ///
+    /// Behaves like `pause`/`resume` but is tracked separately, so regaining
+    /// focus never resumes a manager the app paused itself, and repeated
+    /// calls with the same value change nothing.
+    ///
+    /// # Arguments
+    /// * `focused` - Whether the terminal currently has focus
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::Event;
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// # let event = Event::FocusLost;
+    /// match event {
+    ///     Event::FocusLost => manager.set_focused(false),
+    ///     Event::FocusGained => manager.set_focused(true),
+    ///     _ => {}
+    /// }
+    /// ```
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns whether the manager considers the terminal focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Tells the manager the terminal was resized.
+    ///
+    /// Every notification is measured and stacked afresh on the next render,
+    /// so boxes sized for the old terminal shrink to fit the new one instead
+    /// of keeping stale rects. Click targets and the damage rect from the
+    /// last render are dropped until the next one. With `precompute` enabled, layouts are measured
+    /// again for the new size, which also becomes the `precompute_area`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // On crossterm::event::Event::Resize(width, height):
+    /// manager.on_resize(100, 30);
+    /// ```
+    pub fn on_resize(&mut self, width: u16, height: u16) {
+        self.drawn.clear();
+        self.damage = None;
+        if self.precompute {
+            self.precompute_area = Rect::new(0, 0, width, height);
+        }
+
+        for state in self.states.values_mut() {
+            state.reset_layout();
+            if self.precompute {
+                state.precompute_layout(self.precompute_area);
+            }
+        }
+    }
+
+    /// Starts capturing `add`, `update`, `tick`, `remove`, `clear` and
+    /// `force_clear` calls. Any recording already in progress is discarded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.start_recording();
+    /// manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    /// manager.tick(Duration::from_millis(16));
+    /// let recording = manager.stop_recording();
+    ///
+    /// let mut replayed = Notifications::new();
+    /// replayed.replay(&recording).unwrap();
+    /// ```
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Recording::default());
+    }
+
+    /// Stops recording and returns what was captured.
+    ///
+    /// Returns an empty recording if `start_recording` was never called.
+    pub fn stop_recording(&mut self) -> Recording {
+        self.recording.take().unwrap_or_default()
+    }
+
+    /// Re-applies a recording to this manager.
+    ///
+    /// Intended for a freshly created manager configured like the one that
+    /// was recorded; replaying onto a manager with different settings or
+    /// existing notifications will diverge. Notification IDs from the
+    /// recording are mapped onto the IDs this manager issues.
+    ///
+    /// # Errors
+    /// Returns the first error raised by a replayed `add`.
+    pub fn replay(&mut self, recording: &Recording) -> Result<(), NotificationError> {
+        let mut ids: HashMap<u64, u64> = HashMap::new();
+
+        for (_, event) in recording.events() {
+            match event {
+                RecordedEvent::Add(recorded_id, notification) => {
+                    let id = self.add(Notification::clone(notification))?;
+                    ids.insert(*recorded_id, id);
+                }
+                RecordedEvent::Update(recorded_id, notification) => {
+                    if let Some(&id) = ids.get(recorded_id) {
+                        self.update(id, Notification::clone(notification));
+                    }
+                }
+                RecordedEvent::Tick(delta) => self.tick(*delta),
+                RecordedEvent::Remove(recorded_id) => {
+                    if let Some(&id) = ids.get(recorded_id) {
+                        self.remove(id);
+                    }
+                }
+                RecordedEvent::Clear => self.clear(),
+                RecordedEvent::ForceClear => self.force_clear(),
+                RecordedEvent::ClearAnchor(anchor) => self.clear_anchor(*anchor),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces an existing notification's content without restarting it.
+    ///
+    /// The notification keeps its ID, age and animation phase, so it neither
+    /// re-enters nor flickers; a dwelling one gets a full display time again.
+    /// If `notification` sets a different anchor the notification moves
+    /// there; without an anchor it stays where it is.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Uploading… 40%").build().unwrap()).unwrap();
+    /// manager.update(id, NotificationBuilder::new("Uploading… 80%").build().unwrap());
+    /// ```
+    pub fn update(&mut self, id: u64, mut notification: Notification) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::Update(id, Box::new(notification.clone())));
+        }
+
+        let from = state.notification.anchor();
+        let to = notification.anchor.unwrap_or(from);
+        if !notification.border_explicit {
+            notification.border_type = self.default_border;
+        }
+        state.update_notification(notification, &self.defaults);
+        state.notification.anchor = Some(to);
+        if self.precompute {
+            state.precompute_layout(self.precompute_area);
+        }
+
+        if to != from {
+            if let Some(ids) = self.by_anchor.get_mut(&from) {
+                ids.retain(|&existing_id| existing_id != id);
+            }
+            self.by_anchor.entry(to).or_default().push(id);
+        }
+
+        true
+    }
+
+    /// Removes a notification by ID.
+    ///
+    /// Removal is idempotent: removing an ID twice, or removing one that
+    /// `tick()` already cleaned up, is a no-op that returns `false`.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID to remove
+    ///
+    /// # Returns
+    /// * `true` - If the notification existed and was removed
+    /// * `false` - If the notification didn't exist
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Test").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    /// assert!(manager.remove(id));
+    /// ```
+    pub fn remove(&mut self, id: u64) -> bool {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::Remove(id));
+        }
+
+        self.remove_state(id, DismissReason::Manual)
+    }
+
+    /// Starts a notification's exit animation.
+    ///
+    /// Unlike `remove`, the notification animates out before it is cleaned
+    /// up, the same as when its display time runs out. A notification that
+    /// is already exiting carries on unchanged.
+    ///
+    /// # Returns
+    /// * `true` - If the notification exists
+    /// * `false` - If the notification didn't exist
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Test").build().unwrap()).unwrap();
+    /// assert!(manager.dismiss(id));
+    /// ```
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+        state.begin_dismiss(DismissReason::Manual);
+        self.refresh_focus();
+        true
+    }
+
+    /// Whether `handle` was issued by this manager and its notification remains.
+    pub(crate) fn owns(&self, handle: NotificationHandle) -> bool {
+        handle.manager == self.instance && self.states.contains_key(&handle.id)
+    }
+
+    /// Moves keyboard focus to the next notification, wrapping around.
+    ///
+    /// Notifications are cycled oldest first, in `iter` order, skipping
+    /// hidden ones and any already exiting. With nothing focused, the oldest
+    /// takes focus. The focused notification is drawn with a bold border.
+    /// This is unrelated to `set_focused`, which tracks terminal focus.
+    ///
+    /// # Returns
+    /// The newly focused ID, or `None` if no notification can take focus
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::{self, Event, KeyCode};
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// if let Ok(Event::Key(key)) = event::read() {
+    ///     match key.code {
+    ///         KeyCode::Tab => { manager.focus_next(); }
+    ///         KeyCode::BackTab => { manager.focus_prev(); }
+    ///         KeyCode::Esc => { manager.dismiss_focused(); }
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn focus_next(&mut self) -> Option<u64> {
+        self.step_focus(false)
+    }
+
+    /// Moves keyboard focus to the previous notification, wrapping around.
+    ///
+    /// The reverse of `focus_next`; with nothing focused, the newest takes focus.
+    pub fn focus_prev(&mut self) -> Option<u64> {
+        self.step_focus(true)
+    }
+
+    /// Returns the ID of the notification with keyboard focus, if any.
+    ///
+    /// When the focused notification starts exiting or is removed, focus
+    /// passes to the next live notification at the same anchor (or the
+    /// previous one if it was the newest there), or is cleared if none is left.
+    pub fn focused_id(&self) -> Option<u64> {
+        self.focused_notification
+    }
+
+    /// Starts the exit animation of the focused notification.
+    ///
+    /// Focus moves on to the next live notification at the same anchor.
+    ///
+    /// # Returns
+    /// The ID of the notification that started exiting, if one was focused
+    pub fn dismiss_focused(&mut self) -> Option<u64> {
+        let id = self.focused_notification?;
+        self.states.get_mut(&id)?.begin_dismiss(DismissReason::Manual);
+        self.refresh_focus();
+        Some(id)
+    }
+
+    /// Maps a key press to an action button of the focused notification.
+    ///
+    /// Looks the key up in the focused notification's `action_keys` and
+    /// returns the index of the action with the bound label. Releases and
+    /// repeats are ignored, as are keys with no binding.
+    ///
+    /// # Returns
+    /// `(id, index)` into `actions()` of the focused notification, or `None`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::{self, Event};
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// if let Ok(Event::Key(key)) = event::read() {
+    ///     if let Some((id, index)) = manager.handle_key(key) {
+    ///         if let Some((_, notification, _)) = manager.iter().find(|(other, _, _)| *other == id) {
+    ///             println!("{} on {id}", notification.actions()[index].0);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn handle_key(&mut self, event: KeyEvent) -> Option<(u64, usize)> {
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+        let id = self.focused_notification?;
+        let notification = &self.states.get(&id)?.notification;
+        let (label, _) = notification.action_keys.iter().find(|(_, key)| *key == event.code)?;
+        let index = notification.actions.iter().position(|(action, _)| action == label)?;
+        Some((id, index))
+    }
+
+    fn step_focus(&mut self, backward: bool) -> Option<u64> {
+        let ids: Vec<u64> = self
+            .iter()
+            .filter(|&(id, _, _)| self.is_focusable(id))
+            .map(|(id, _, _)| id)
+            .collect();
+        let current = self.focused_notification.and_then(|focused| ids.iter().position(|&id| id == focused));
+
+        let next = match current {
+            Some(index) if backward => (index + ids.len() - 1) % ids.len(),
+            Some(index) => (index + 1) % ids.len(),
+            None if backward => ids.len().saturating_sub(1),
+            None => 0,
+        };
+        self.focused_notification = ids.get(next).copied();
+        self.focused_notification
+    }
+
+    fn is_focusable(&self, id: u64) -> bool {
+        self.states.get(&id).is_some_and(|state| {
+            state.visible && !state.is_exiting() && state.current_phase != AnimationPhase::Finished
+        })
+    }
+
+    /// Passes focus on if the focused notification can no longer hold it.
+    fn refresh_focus(&mut self) {
+        if let Some(id) = self.focused_notification.filter(|&id| !self.is_focusable(id)) {
+            self.focused_notification = self.focus_successor(id);
+        }
+    }
+
+    /// Finds the live notification after `id` at its anchor, else the one before it.
+    fn focus_successor(&self, id: u64) -> Option<u64> {
+        let ids = self.by_anchor.values().find(|ids| ids.contains(&id))?;
+        let at = ids.iter().position(|&other| other == id)?;
+        ids[at + 1..]
+            .iter()
+            .chain(ids[..at].iter().rev())
+            .copied()
+            .find(|&other| self.is_focusable(other))
+    }
+
+    /// Drops a notification's state without recording it; used for
+    /// removals the manager makes on its own (cleanup, overflow). Runs the
+    /// notification's dismiss callback with `reason`.
+    fn remove_state(&mut self, id: u64, reason: DismissReason) -> bool {
+        if self.focused_notification == Some(id) {
+            self.focused_notification = self.focus_successor(id);
+        }
+        if let Some(state) = self.states.remove(&id) {
+            // Remove from anchor map
+            let anchor = state.notification.anchor();
+            if let Some(ids) = self.by_anchor.get_mut(&anchor) {
+                ids.retain(|&existing_id| existing_id != id);
+            }
+            self.dismissal_metrics.record(reason);
+            if let Some(callback) = state.notification.on_dismiss.as_ref() {
+                callback.call(id, reason);
+            }
+            self.summaries.retain(|_, &mut (summary, _)| summary != id);
+            if reason == DismissReason::Overflow && self.overflow_summary {
+                self.note_suppressed(anchor);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes all notifications except protected ones.
+    ///
+    /// Notifications built with `.protected(true)` are left in place; use
+    /// `force_clear()` to remove everything.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... add notifications ...
+    /// manager.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::Clear);
+        }
+
+        let cleared: Vec<u64> = self
+            .states
+            .values()
+            .filter(|state| !state.notification.protected)
+            .map(|state| state.id)
+            .collect();
+        for id in cleared {
+            self.remove_state(id, DismissReason::Cleared);
+        }
+    }
+
+    /// Removes all notifications, including protected ones.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... add notifications ...
+    /// manager.force_clear();
+    /// ```
+    pub fn force_clear(&mut self) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::ForceClear);
+        }
+
+        let cleared: Vec<u64> = self.states.keys().copied().collect();
+        for id in cleared {
+            self.remove_state(id, DismissReason::Cleared);
+        }
+        self.by_anchor.clear();
+    }
+
+    /// Removes the notifications at one anchor, leaving other anchors alone.
+    ///
+    /// Like `clear()`, protected notifications stay; each removed one runs its
+    /// dismiss callback with `DismissReason::Cleared`.
+    ///
+    /// # Arguments
+    /// * `anchor` - The screen region to clear
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Anchor, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... switching screens: drop the transient status messages ...
+    /// manager.clear_anchor(Anchor::TopRight);
+    /// ```
+    pub fn clear_anchor(&mut self, anchor: Anchor) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::ClearAnchor(anchor));
+        }
+
+        let cleared: Vec<u64> = self
+            .by_anchor
+            .get(&anchor)
+            .into_iter()
+            .flatten()
+            .filter(|id| self.states.get(id).is_some_and(|state| !state.notification.protected))
+            .copied()
+            .collect();
+        for id in cleared {
+            self.remove_state(id, DismissReason::Cleared);
+        }
+    }
+
+    /// Returns the number of notifications at an anchor that have not
+    /// finished, including ones still waiting to enter.
+    ///
+    /// # Arguments
+    /// * `anchor` - The screen region to count
+    pub fn count_at(&self, anchor: Anchor) -> usize {
+        self.by_anchor
+            .get(&anchor)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.states.get(id))
+            .filter(|state| state.current_phase != AnimationPhase::Finished)
+            .count()
+    }
+
+    /// Updates all notification animations.
+    ///
+    /// Call this once per frame with the elapsed time since the last update.
+    /// Does nothing while the manager is paused or unfocused.
+    ///
+    /// # Arguments
+    /// * `delta` - Time elapsed since last tick
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.tick(Duration::from_millis(16)); // ~60 FPS
+    /// ```
+    pub fn tick(&mut self, delta: Duration) {
+        if self.paused || !self.focused {
+            return;
+        }
+
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(RecordedEvent::Tick(delta));
+        }
+
+        for limit in self.rate_limits.values_mut() {
+            limit.advance(delta);
+        }
+
+        // Update all notification states, except those waiting for a visible slot or off-page
+        let mut held = self.find_held_pending();
+        if let Some(animation) = self.promotion_animation {
+            for id in &held {
+                if let Some(state) = self.states.get_mut(id) {
+                    state.entry_animation = Some(animation);
+                }
+            }
+        }
+        held.extend(self.paginate().0);
+        let states_to_update: Vec<u64> = self
+            .states
+            .keys()
+            .copied()
+            .filter(|id| !held.contains(id))
+            .collect();
+
+        for id in states_to_update {
+            if let Some(state) = self.states.get_mut(&id) {
+                let from = state.current_phase;
+
+                // App-state condition met: start exiting this tick
+                let condition = state.notification.dismiss_when.as_ref();
+                if !state.is_exiting() && condition.is_some_and(|c| c.is_met()) {
+                    state.begin_dismiss(DismissReason::Manual);
+                }
+
+                let was_dwelling = state.current_phase == AnimationPhase::Dwelling;
+                state.update(delta);
+
+                // The dwell timer just expired; let the guard keep it around
+                if was_dwelling && state.is_exiting() {
+                    if let Some(DismissGuard(guard)) = self.dismiss_guard.as_mut() {
+                        if !guard(&state.notification) {
+                            state.restart_dwell();
+                            self.dismissal_metrics.vetoed += 1;
+                        }
+                    }
+                }
+
+                if state.current_phase != from {
+                    if self.phase_events.len() == MAX_PHASE_EVENTS {
+                        self.phase_events.pop_front();
+                    }
+                    self.phase_events.push_back(PhaseEvent { id, from, to: state.current_phase });
+                }
+            }
+        }
+
+        self.refresh_focus();
+
+        // Remove finished notifications
+        let finished: Vec<u64> = self.states
+            .iter()
+            .filter_map(|(id, state)| {
+                if state.current_phase == crate::notifications::types::AnimationPhase::Finished {
+                    Some(*id)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        for id in finished {
+            let reason = self.states.get(&id).map_or(DismissReason::Expired, |state| state.dismiss_reason);
+            self.remove_state(id, reason);
+        }
+    }
+
+    /// Renders all active notifications to the frame.
+    ///
+    /// Anchors are positions within `area`, and notifications are sized,
+    /// stacked and clipped to it, so passing a pane's rect places them over
+    /// that pane (e.g. `Anchor::MiddleCenter` centres them on it). Layout is
+    /// recomputed on every call, so the area may change between frames.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame to render to
+    /// * `area` - The area to render within, usually `frame.area()`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::Terminal;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let backend = TestBackend::new(80, 24);
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    ///
+    /// terminal.draw(|frame| {
+    ///     manager.render(frame, frame.area());
+    /// }).unwrap();
+    /// ```
+    pub fn render(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        if !self.render_enabled {
+            self.drawn.clear();
+            self.damage = None;
+            return;
+        }
+
+        // Notifications not ticked since being added show per `initial_render`
+        let mode = self.initial_render;
+        if mode != InitialRender::Hidden {
+            let held = self.find_held_pending();
+            let fresh = self
+                .states
+                .values_mut()
+                .filter(|state| state.current_phase == AnimationPhase::Pending && !held.contains(&state.id));
+            for state in fresh {
+                match mode {
+                    InitialRender::AtRest => state.skip_entry(),
+                    InitialRender::Animating => state.start_entry(),
+                    InitialRender::Hidden => {}
+                }
+            }
+        }
+
+        // Switch compact rendering as the active count crosses the threshold
+        let active = self.active_count();
+        let compact = self.degrade_above.is_some_and(|max| active > max);
+        for state in self.states.values_mut() {
+            state.set_compact(compact);
+            state.set_monochrome(self.monochrome);
+        }
+
+        let (off_page, page_labels) = self.paginate();
+        let summaries: HashSet<u64> = self.summaries.values().map(|&(id, _)| id).collect();
+        let options = RenderOptions {
+            max_concurrent: self.max_concurrent,
+            anchor_order: &self.anchor_z_order,
+            depth_fade: self.depth_fade,
+            collapse_below: self.collapse_below,
+            anchor_styles: &self.anchor_styles,
+            level_theme: &self.level_theme,
+            reflow: self.reflow,
+            off_page: &off_page,
+            summaries: &summaries,
+            page_labels: &page_labels,
+            focused: self.focused_notification,
+            stack_directions: &self.stack_directions,
+            monochrome: self.monochrome,
+        };
+        let mut transform = self
+            .line_transform
+            .as_mut()
+            .map(|LineTransform(transform)| {
+                move |state: &NotificationState, lines| transform(&state.notification, lines)
+            });
+        let mut custom_draw = self.renderer.as_ref().map(|CustomRenderer(renderer)| {
+            move |state: &NotificationState, frame: &mut Frame<'_>, rect: Rect| {
+                renderer.render(&state.notification, state, frame, rect);
+            }
+        });
+        self.drawn = render_notifications(
+            &mut self.states,
+            &self.by_anchor,
+            frame,
+            area,
+            &options,
+            transform.as_mut().map(|transform| transform as &mut dyn FnMut(&NotificationState, _) -> _),
+            custom_draw.as_mut().map(|draw| draw as &mut dyn FnMut(&NotificationState, &mut Frame<'_>, Rect)),
+        );
+
+        let area = area.intersection(frame.area());
+        let states = &self.states;
+        self.damage = self
+            .drawn
+            .iter()
+            .flat_map(|drawn| {
+                let pointer = states.get(&drawn.id).and_then(|state| state.pointer);
+                let connector = pointer
+                    .map(|target| connector_cells(drawn.rect, target))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|&(position, _)| area.contains(position))
+                    .map(|(position, _)| Rect::new(position.x, position.y, 1, 1));
+                std::iter::once(drawn.rect).chain(connector)
+            })
+            .filter(|rect| !rect.is_empty())
+            .reduce(|union, rect| union.union(rect));
+    }
+
+    /// Returns the bounding box of everything the last render drew.
+    ///
+    /// Covers every notification box plus any `point_at` connectors, for
+    /// compositors that redraw only damaged regions. Returns `None` if the
+    /// last render drew nothing, or rendering is disabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new();
+    /// // After rendering:
+    /// if let Some(damage) = manager.damage_rect() {
+    ///     // redraw `damage`
+    /// }
+    /// ```
+    pub fn damage_rect(&self) -> Option<Rect> {
+        self.damage
+    }
+
+    /// Finds the notification drawn at a screen position in the last render.
+    ///
+    /// Where notifications overlap, the one drawn on top wins. Notifications
+    /// removed since the last render are never returned, nor are ones
+    /// entering or exiting unless `hit_animating` is on. Points on an action
+    /// button report `HitRegion::Action`, the rest of the box `HitRegion::Body`.
+    ///
+    /// # Arguments
+    /// * `col` - Screen column, e.g. from a mouse event
+    /// * `row` - Screen row
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, HitRegion};
+    ///
+    /// let mut manager = Notifications::new();
+    /// // After rendering, on a mouse click at (col, row):
+    /// if let Some(hit) = manager.hit(10, 5) {
+    ///     if hit.region == HitRegion::Body {
+    ///         manager.remove(hit.id);
+    ///     }
+    /// }
+    /// ```
+    pub fn hit(&self, col: u16, row: u16) -> Option<Hit> {
+        let point = Position::new(col, row);
+        let drawn = self
+            .drawn
+            .iter()
+            .rev()
+            .find(|drawn| drawn.rect.contains(point) && self.states.contains_key(&drawn.id))?;
+
+        // An animating notification still covers what is beneath it
+        let state = self.states.get(&drawn.id)?;
+        if !self.hit_animating && state.current_phase != AnimationPhase::Dwelling {
+            return None;
+        }
+        let action = state.action_rects.iter().find(|(rect, _)| rect.contains(point)).map(|&(_, action)| action);
+
+        Some(Hit {
+            id: drawn.id,
+            region: action.map_or(HitRegion::Body, HitRegion::Action),
+        })
+    }
+
+    /// Returns the notification and action button drawn at a screen position
+    /// in the last render, if the point is on a button. Shorthand for
+    /// matching `HitRegion::Action` on `hit(col, row)`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::{self, Event, MouseEventKind};
+    /// use ratatui_notifications::notifications::{ActionId, Notifications};
+    ///
+    /// const RETRY: ActionId = ActionId(1);
+    ///
+    /// let mut manager = Notifications::new();
+    /// if let Ok(Event::Mouse(mouse)) = event::read() {
+    ///     if let MouseEventKind::Down(_) = mouse.kind {
+    ///         if let Some((id, action)) = manager.action_at(mouse.column, mouse.row) {
+    ///             if action == RETRY {
+    ///                 // start the upload again
+    ///             }
+    ///             manager.remove(id);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn action_at(&self, col: u16, row: u16) -> Option<(u64, ActionId)> {
+        match self.hit(col, row)? {
+            Hit { id, region: HitRegion::Action(action) } => Some((id, action)),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the notification drawn at a screen position in the
+    /// last render, if any. Shorthand for `hit(col, row).map(|hit| hit.id)`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::{self, Event};
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let mut hovered = None;
+    /// // Keep the hovered notification up while the pointer rests on it
+    /// if let Ok(Event::Mouse(mouse)) = event::read() {
+    ///     let under = manager.notification_at(mouse.column, mouse.row);
+    ///     if under != hovered {
+    ///         hovered.map(|id| manager.unfreeze(id));
+    ///         under.map(|id| manager.freeze(id));
+    ///         hovered = under;
+    ///     }
+    /// }
+    /// ```
+    pub fn notification_at(&self, col: u16, row: u16) -> Option<u64> {
+        self.hit(col, row).map(|hit| hit.id)
+    }
+
+    /// Holds a notification's dismiss countdown, e.g. while it is hovered.
+    ///
+    /// Its animations keep running and it can still be removed or
+    /// dismissed; only the auto-dismiss timer stops until `unfreeze`.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    pub fn freeze(&mut self, id: u64) -> bool {
+        self.set_frozen(id, true)
+    }
+
+    /// Restarts a frozen notification's dismiss countdown where it stopped.
+    ///
+    /// # Returns
+    /// `true` if the notification exists, `false` otherwise
+    pub fn unfreeze(&mut self, id: u64) -> bool {
+        self.set_frozen(id, false)
+    }
+
+    /// Dismisses the notification clicked with the left mouse button.
+    ///
+    /// Feed every mouse event here. With `ClickTrigger::Press` the
+    /// notification under a left press starts exiting immediately. With
+    /// `ClickTrigger::Release` the press target is remembered and dismissed
+    /// only if the release lands on the same notification. Positions are
+    /// resolved with `hit` against the last render.
+    ///
+    /// # Returns
+    /// The ID of the notification that started exiting, if any
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::{self, Event};
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// if let Ok(Event::Mouse(mouse)) = event::read() {
+    ///     manager.handle_click(&mouse);
+    /// }
+    /// ```
+    pub fn handle_click(&mut self, event: &MouseEvent) -> Option<u64> {
+        let target = self.hit(event.column, event.row).map(|hit| hit.id);
+
+        let dismissed = match (event.kind, self.click_trigger) {
+            (MouseEventKind::Down(MouseButton::Left), ClickTrigger::Press) => target,
+            (MouseEventKind::Down(MouseButton::Left), ClickTrigger::Release) => {
+                self.pressed = target;
+                None
+            }
+            (MouseEventKind::Up(MouseButton::Left), ClickTrigger::Release) => {
+                self.pressed.take().filter(|&pressed| Some(pressed) == target)
+            }
+            _ => None,
+        }?;
+
+        let state = self.states.get_mut(&dismissed)?;
+        state.begin_dismiss(DismissReason::Manual);
+        self.refresh_focus();
+        Some(dismissed)
+    }
+
+    fn set_frozen(&mut self, id: u64, frozen: bool) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.frozen = frozen;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Enforces max_concurrent limit for the given anchor.
+    ///
+    /// Removes oldest or newest notification as needed based on overflow behavior,
+    /// returning the ID removed.
+    fn enforce_limit(&mut self, anchor: Anchor) -> Option<u64> {
+        // Remove one notification based on overflow behavior; freeing a slot
+        // at the anchor also frees one toward max_total
+        let id = self.find_limit_victim(anchor, None)?;
+        self.remove_state(id, DismissReason::Overflow);
+        Some(id)
+    }
+
+    /// Finds the notification an add at `anchor` would evict: from the
+    /// anchor if it is at `max_concurrent`, else from anywhere if the total
+    /// is at `max_total`. `None` if neither is full or overflow queues.
+    /// `excluding` is treated as already removed.
+    fn find_limit_victim(&self, anchor: Anchor, excluding: Option<u64>) -> Option<u64> {
+        if self.overflow == Overflow::Queue {
+            return None;
+        }
+        let excluded = excluding.and_then(|id| self.states.get(&id));
+        let excluded_here = excluded.is_some_and(|state| state.notification.anchor() == anchor);
+        if self
+            .max_concurrent
+            .is_some_and(|max| self.limited_count_at(anchor).saturating_sub(usize::from(excluded_here)) >= max)
+        {
+            return self.find_overflow_victim(anchor, excluding);
+        }
+        if self.max_total.is_some_and(|max| self.limited_count().saturating_sub(usize::from(excluded.is_some())) >= max) {
+            return self.find_global_overflow_victim(excluding);
+        }
+        None
+    }
+
+    /// Notifications at `anchor` counted toward `max_concurrent`; an overflow
+    /// summary sits on top of the limit.
+    fn limited_count_at(&self, anchor: Anchor) -> usize {
+        self.by_anchor.get(&anchor).map_or(0, |ids| ids.iter().filter(|&&id| !self.is_summary(id)).count())
+    }
+
+    /// Notifications counted toward `max_total`, leaving out overflow summaries.
+    fn limited_count(&self) -> usize {
+        self.states.keys().filter(|&&id| !self.is_summary(id)).count()
+    }
+
+    /// Whether `id` is an overflow summary, wherever it has since moved.
+    fn is_summary(&self, id: u64) -> bool {
+        self.summaries.values().any(|&(summary, _)| summary == id)
+    }
+
+    /// Removes notifications from an anchor until it is within max_concurrent.
+    fn trim_to_limit(&mut self, anchor: Anchor) {
+        if self.overflow == Overflow::Queue {
+            return;
+        }
+        if let Some(max) = self.max_concurrent {
+            while self.limited_count_at(anchor) > max {
+                match self.find_overflow_victim(anchor, None) {
+                    Some(id) => {
+                        self.remove_state(id, DismissReason::Overflow);
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Removes notifications from any anchor until the total is within max_total.
+    fn trim_to_total(&mut self) {
+        if self.overflow == Overflow::Queue {
+            return;
+        }
+        if let Some(max) = self.max_total {
+            while self.limited_count() > max {
+                match self.find_global_overflow_victim(None) {
+                    Some(id) => {
+                        self.remove_state(id, DismissReason::Overflow);
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Finds pending notifications that must keep waiting because their anchor
+    /// already shows `max_visible` notifications (or `max_concurrent` ones
+    /// with `Overflow::Queue`), or, with `Overflow::Queue`, because
+    /// `max_total` are showing across all anchors.
+    ///
+    /// Free slots go to the oldest pending notifications first.
+    fn find_held_pending(&self) -> HashSet<u64> {
+        let mut held = HashSet::new();
+        let queue_limit = self.max_concurrent.filter(|_| self.overflow == Overflow::Queue);
+
+        for (anchor, ids) in &self.by_anchor {
+            let Some(limit) = [self.max_visible.get(anchor).copied(), queue_limit].into_iter().flatten().min() else {
+                continue;
+            };
+
+            let mut visible = 0;
+            let mut pending = Vec::new();
+
+            // Overflow summaries neither wait for nor take a slot
+            for id in ids.iter().filter(|&&id| !self.is_summary(id)) {
+                if let Some(state) = self.states.get(id) {
+                    match state.current_phase {
+                        AnimationPhase::Pending => pending.push((state.created_at, *id)),
+                        AnimationPhase::Finished => {}
+                        _ => visible += 1,
+                    }
+                }
+            }
+
+            pending.sort_unstable();
+            let free_slots = limit.saturating_sub(visible);
+            held.extend(pending.into_iter().skip(free_slots).map(|(_, id)| id));
+        }
+
+        if let Some(limit) = self.max_total.filter(|_| self.overflow == Overflow::Queue) {
+            let visible = self
+                .states
+                .values()
+                .filter(|state| !self.is_summary(state.id))
+                .filter(|state| !matches!(state.current_phase, AnimationPhase::Pending | AnimationPhase::Finished))
+                .count();
+            let mut pending: Vec<(Instant, u64)> = self
+                .states
+                .values()
+                .filter(|state| !self.is_summary(state.id))
+                .filter(|state| state.current_phase == AnimationPhase::Pending && !held.contains(&state.id))
+                .map(|state| (state.created_at, state.id))
+                .collect();
+            pending.sort_unstable();
+            let free_slots = limit.saturating_sub(visible);
+            held.extend(pending.into_iter().skip(free_slots).map(|(_, id)| id));
+        }
+
+        held
+    }
+
+    /// Finds the showing (not exiting) notification of the given kind.
+    fn find_kind(&self, kind: Option<&str>) -> Option<u64> {
+        let kind = kind?;
+        self.states
+            .values()
+            .filter(|state| !state.is_exiting() && state.current_phase != AnimationPhase::Finished)
+            .find(|state| state.notification.kind() == Some(kind))
+            .map(|state| state.id)
+    }
+
+    /// Finds the showing notification that `notification` repeats, if
+    /// coalescing, other than `excluding`.
+    fn find_repeat(&self, notification: &Notification, excluding: Option<u64>) -> Option<u64> {
+        if !self.coalesce {
+            return None;
+        }
+        let anchor = notification.anchor.unwrap_or(self.default_anchor);
+        self.by_anchor
             .get(&anchor)?
             .iter()
-            .filter_map(|id| {
-                self.states
-                    .get(id)
-                    .map(|state| (id, state.created_at))
+            .filter_map(|id| self.states.get(id))
+            .filter(|state| !state.is_exiting() && state.current_phase != AnimationPhase::Finished)
+            .filter(|state| Some(state.id) != excluding)
+            .find(|state| {
+                state.notification.content == notification.content
+                    && state.notification.headline == notification.headline
+                    && state.notification.level == notification.level
             })
-            .max_by_key(|&(_, created_at)| created_at)
-            .map(|(&id, _)| id)
+            .map(|state| state.id)
+    }
+
+    /// Direction the stack at `anchor` grows in, honouring overrides.
+    fn stack_direction_at(&self, anchor: Anchor) -> StackDirection {
+        self.stack_directions.get(&anchor).copied().unwrap_or_else(|| StackDirection::for_anchor(anchor))
+    }
+
+    /// Number of pages at the anchor with the most notifications (0 without paging).
+    fn page_count(&self) -> usize {
+        match self.page_size {
+            Some(size) => self.by_anchor.values().map(|ids| ids.len().div_ceil(size)).max().unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    /// Splits anchors into pages, returning the off-page IDs and the current
+    /// page and page count (1-based) of each anchor with more than one page.
+    fn paginate(&self) -> (HashSet<u64>, HashMap<Anchor, (usize, usize)>) {
+        let mut off_page = HashSet::new();
+        let mut labels = HashMap::new();
+
+        if let Some(size) = self.page_size {
+            for (&anchor, ids) in &self.by_anchor {
+                let pages = ids.len().div_ceil(size);
+                if pages <= 1 {
+                    continue;
+                }
+
+                let page = self.page.min(pages - 1);
+                for (index, &id) in ids.iter().enumerate() {
+                    if index / size != page {
+                        off_page.insert(id);
+                    }
+                }
+                labels.insert(anchor, (page + 1, pages));
+            }
+        }
+
+        (off_page, labels)
+    }
+
+    /// Picks the notification to evict from a full anchor per the overflow
+    /// behavior, never `excluding`.
+    fn find_overflow_victim(&self, anchor: Anchor, excluding: Option<u64>) -> Option<u64> {
+        let states = self
+            .by_anchor
+            .get(&anchor)?
+            .iter()
+            .filter(|&&id| Some(id) != excluding)
+            .filter_map(|id| self.states.get(id))
+            .collect();
+        self.pick_overflow_victim(states)
+    }
+
+    /// Finds the notification to evict across every anchor for `max_total`,
+    /// never `excluding`.
+    fn find_global_overflow_victim(&self, excluding: Option<u64>) -> Option<u64> {
+        self.pick_overflow_victim(self.states.values().filter(|state| Some(state.id) != excluding).collect())
+    }
+
+    /// Applies the overflow behavior to choose one of `states` to evict.
+    fn pick_overflow_victim(&self, states: Vec<&NotificationState>) -> Option<u64> {
+        // Overflow summaries report evictions and are never evicted themselves
+        let states: Vec<&NotificationState> = states
+            .into_iter()
+            .filter(|state| !self.is_summary(state.id))
+            .collect();
+
+        // Protected notifications only go once nothing unprotected is left
+        let states: Vec<&NotificationState> = if states.iter().any(|state| !state.notification.protected) {
+            states.into_iter().filter(|state| !state.notification.protected).collect()
+        } else {
+            states
+        };
+
+        // Only the lowest priority among them is up for eviction
+        let lowest = states.iter().map(|state| state.notification.priority).min()?;
+        let candidates = states.into_iter().filter(|state| state.notification.priority == lowest);
+
+        match (self.overflow, &self.overflow_scorer) {
+            (Overflow::DiscardNewest, _) => Self::find_newest(candidates),
+            (Overflow::Scored, Some(OverflowScorer(score))) => candidates
+                .min_by_key(|state| (score(&state.notification), state.created_at, state.id))
+                .map(|state| state.id),
+            _ => Self::find_oldest(candidates),
+        }
+    }
+
+    /// Whether adding `notification` would overflow a limit where every
+    /// notification it could evict has a higher priority. `replacing`, the
+    /// same-kind notification it is about to replace, counts as gone.
+    fn is_outranked(&self, notification: &Notification, replacing: Option<u64>) -> bool {
+        let anchor = notification.anchor.unwrap_or(self.default_anchor);
+        self.find_limit_victim(anchor, replacing)
+            .and_then(|id| self.states.get(&id))
+            .is_some_and(|victim| victim.notification.priority > notification.priority)
+    }
+
+    /// Finds the oldest of the given states; on equal creation times the
+    /// lowest ID counts as oldest.
+    fn find_oldest<'a>(states: impl Iterator<Item = &'a NotificationState>) -> Option<u64> {
+        states.min_by_key(|state| (state.created_at, state.id)).map(|state| state.id)
+    }
+
+    /// Finds the newest of the given states; on equal creation times the
+    /// highest ID counts as newest.
+    fn find_newest<'a>(states: impl Iterator<Item = &'a NotificationState>) -> Option<u64> {
+        states.max_by_key(|state| (state.created_at, state.id)).map(|state| state.id)
+    }
+}
+
+/// Position of a phase in the lifecycle; entry and exit variants share one.
+fn phase_rank(phase: AnimationPhase) -> u8 {
+    match phase {
+        AnimationPhase::Pending => 0,
+        AnimationPhase::SlidingIn | AnimationPhase::Expanding | AnimationPhase::FadingIn => 1,
+        AnimationPhase::Dwelling => 2,
+        AnimationPhase::SlidingOut | AnimationPhase::Collapsing | AnimationPhase::FadingOut => 3,
+        AnimationPhase::Finished => 4,
     }
 }
 
@@ -321,4 +3250,4 @@ impl Default for Notifications {
 }
 
 // FILE: src/notifications/orc_manager.rs - Notifications manager orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.73.1
@@ -1,20 +1,30 @@
 // FILE: src/notifications/orc_render.rs - Orchestrates notification rendering
-// VERSION: 1.1.0
-// WCTX: Fixing fade animation overshoot bug
-// CLOG: Fixed fade discontinuity by applying fade interpolation during Dwelling phase
+// VERSION: 1.31.0
+// WCTX: Precomputed layout
+// CLOG: Reuse cached wrapped content at rest
 
+use crate::notifications::functions::fnc_clip_rows::clip_rows;
+use crate::notifications::functions::fnc_connector_cells::connector_cells;
+use crate::notifications::functions::fnc_dim_style::dim_style;
+use crate::notifications::functions::fnc_progress_gauge::progress_gauge;
 use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+use crate::notifications::functions::fnc_layout_actions::layout_actions;
 use crate::notifications::functions::fnc_resolve_styles::resolve_styles;
-use crate::notifications::orc_stacking::calculate_stacking_positions;
-use crate::notifications::types::{Anchor, AnimationPhase, Level};
+use crate::notifications::functions::fnc_wrap_text::wrap_text;
+use crate::notifications::orc_stacking::{calculate_directed_stacking_positions, StackedNotification};
+use crate::notifications::types::{ActionId, Anchor, AnimationPhase, Easing, Level, LevelTheme, Shape, StackDirection};
 use ratatui::{
     layout::Alignment,
     prelude::*,
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// Deepest dim `depth_fade` applies, so the oldest entry stays legible.
+const MAX_DEPTH_DIM: f32 = 0.8;
 
 /// Trait for renderable notification state.
 ///
@@ -23,8 +33,67 @@ use std::collections::HashMap;
 pub trait RenderableNotification: crate::notifications::orc_stacking::StackableNotification {
     fn level(&self) -> Option<Level>;
     fn title(&self) -> Option<Line<'static>>;
+    /// Icon drawn before the title. Defaults to the level icon.
+    fn icon(&self) -> Option<String> {
+        get_level_icon(self.level()).map(str::to_string)
+    }
+    /// Whether the icon animates, in which case it is drawn even without a title.
+    fn has_animated_icon(&self) -> bool {
+        false
+    }
+    /// Whether the notification draws at all. Hidden ones take no stack space.
+    fn is_visible(&self) -> bool {
+        true
+    }
+    /// Cell a connector line is drawn toward, if any.
+    fn pointer(&self) -> Option<Position> {
+        None
+    }
+    /// Starts moving from `from` to the current stack position.
+    fn begin_reflow(&mut self, _from: Rect, _duration: Duration, _easing: Easing) {}
+    /// Where a notification stacked at `target` is shown mid-reflow.
+    fn reflow_rect(&self, target: Rect) -> Rect {
+        target
+    }
+    /// Outline drawn around the notification.
+    fn shape(&self) -> Shape {
+        Shape::Box
+    }
+    /// Foreground override reflecting remaining display time, if any.
+    fn time_color(&self) -> Option<Color> {
+        None
+    }
+    /// Whether a "NEW" tag is drawn in the top border this frame.
+    fn show_new_badge(&self) -> bool {
+        false
+    }
+    /// Times the notification was added while showing; above 1 an `(xN)` badge is drawn.
+    fn repeat_count(&self) -> u32 {
+        1
+    }
+    /// Completion fraction drawn as a gauge on the bottom inner row, if any.
+    fn progress(&self) -> Option<f32> {
+        None
+    }
+    /// `(label, id)` action buttons drawn on the bottom inner row.
+    fn actions(&self) -> &[(String, ActionId)] {
+        &[]
+    }
+    /// Records where the action buttons were drawn, for hit-testing.
+    fn set_action_rects(&mut self, _rects: Vec<(Rect, ActionId)>) {}
     fn content(&self) -> Text<'static>;
+    /// `content()` already wrapped to `width`, if an earlier frame or
+    /// precompute left it cached.
+    fn wrapped_content(&self, _width: u16) -> Option<Text<'static>> {
+        None
+    }
+    /// Keeps `content()` wrapped to `width` for later frames.
+    fn cache_wrapped_content(&mut self, _width: u16, _wrapped: Text<'static>) {}
     fn border_type(&self) -> BorderType;
+    /// Sides to draw the border on.
+    fn borders(&self) -> Borders {
+        Borders::ALL
+    }
     fn fade_effect(&self) -> bool;
     fn animation_type(&self) -> crate::notifications::types::Animation;
     fn animation_progress(&self) -> f32;
@@ -33,6 +102,10 @@ pub trait RenderableNotification: crate::notifications::orc_stacking::StackableN
     fn title_style(&self) -> Option<Style>;
     fn padding(&self) -> ratatui::widgets::Padding;
     fn set_full_rect(&mut self, rect: Rect);
+    /// Anchor the stack is laid out from, which slides and collapses move
+    /// relative to. Differs from the notification's own anchor when a stack
+    /// direction override moves the baseline.
+    fn set_baseline_anchor(&mut self, _anchor: Anchor) {}
 
     // Animation handler methods - avoid dyn compatibility issues by including them directly
     fn calculate_animation_rect(&self, frame_area: Rect) -> Rect;
@@ -41,16 +114,50 @@ pub trait RenderableNotification: crate::notifications::orc_stacking::StackableN
     fn interpolate_content_foreground(&self, base_fg: Option<Color>, phase: AnimationPhase, progress: f32) -> Option<Color>;
 }
 
+/// Manager-wide settings that affect how notifications are drawn.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions<'a> {
+    /// Optional limit on concurrent visible notifications per anchor
+    pub max_concurrent: Option<usize>,
+    /// Anchors drawn last, later entries on top
+    pub anchor_order: &'a [Anchor],
+    /// Dim applied per step back in a stack (0.0 = off)
+    pub depth_fade: f32,
+    /// Opacity below which depth-faded entries fold into a "+N" label (0.0 = off)
+    pub collapse_below: f32,
+    /// Base styles per anchor, beneath level and per-notification styles
+    pub anchor_styles: &'a HashMap<Anchor, Style>,
+    /// Duration and easing of moves between stack positions (None = snap)
+    pub reflow: Option<(Duration, Easing)>,
+    /// IDs left out because they are not on the current page
+    pub off_page: &'a HashSet<u64>,
+    /// Overflow summary IDs, drawn on top of `max_concurrent`
+    pub summaries: &'a HashSet<u64>,
+    /// Current page and page count (1-based) of each paged anchor
+    pub page_labels: &'a HashMap<Anchor, (usize, usize)>,
+    /// Per-level colours and icons
+    pub level_theme: &'a LevelTheme,
+    /// Notification with keyboard focus, drawn with a bold border
+    pub focused: Option<u64>,
+    /// Growth direction overrides per anchor (others use the anchor's default)
+    pub stack_directions: &'a HashMap<Anchor, StackDirection>,
+    /// Leave every drawn cell in the terminal's default colours
+    pub monochrome: bool,
+}
+
 /// Renders all notifications to the frame.
 ///
 /// This is the main orchestration function that:
-/// 1. Iterates through each anchor's notifications
-/// 2. Calls calculate_stacking_positions for each anchor
+/// 1. Iterates through each anchor's notifications, drawing anchors listed in
+///    `anchor_order` last (in list order) so they paint over the others
+/// 2. Calls calculate_stacking_positions for each anchor, relative to `area`
 /// 3. For each stacked notification:
 ///    - Updates state.full_rect with stacked position
 ///    - Gets animation handler and calculates current rect
 ///    - Resolves styles
-///    - Applies fade effect if enabled
+///    - Applies fade effect if enabled, then depth fade for older entries
+///    - Folds entries faded below `collapse_below` into a "+N more" label on
+///      the far edge of the stack
 ///    - Builds Block with border, title, icon
 ///    - Renders Clear at stacked position, then Paragraph at animated position
 ///
@@ -59,53 +166,156 @@ pub trait RenderableNotification: crate::notifications::orc_stacking::StackableN
 /// * `notifications` - Mutable HashMap of all notification states
 /// * `notifications_by_anchor` - Mapping of anchors to notification IDs
 /// * `frame` - The frame to render to
-/// * `max_concurrent` - Optional limit on concurrent visible notifications
+/// * `area` - Region to lay notifications out in and clip them to
+/// * `options` - Manager-wide render settings
+/// * `line_transform` - Rewrites each notification's content lines before drawing
+/// * `custom_draw` - Draws each notification into its animated rect instead of the built-in look
+///
+/// # Returns
+///
+/// The rect each notification was drawn at, in draw order (later entries on top)
 ///
 /// # Type Parameters
 ///
 /// * `T` - Any type implementing RenderableNotification trait
+#[allow(clippy::type_complexity)]
 pub fn render_notifications<T: RenderableNotification>(
     notifications: &mut HashMap<u64, T>,
     notifications_by_anchor: &HashMap<Anchor, Vec<u64>>,
     frame: &mut Frame<'_>,
-    max_concurrent: Option<usize>,
-) {
-    let frame_area = frame.area();
+    area: Rect,
+    options: &RenderOptions<'_>,
+    mut line_transform: Option<&mut dyn FnMut(&T, Vec<Line<'static>>) -> Vec<Line<'static>>>,
+    mut custom_draw: Option<&mut dyn FnMut(&T, &mut Frame<'_>, Rect)>,
+) -> Vec<StackedNotification> {
+    let anchor_order = options.anchor_order;
+    let area = area.intersection(frame.area());
+    let mut drawn = Vec::new();
 
-    for (anchor, ids_at_anchor) in notifications_by_anchor.iter() {
+    // Unlisted anchors first, then listed ones so later entries draw on top
+    let draw_order = notifications_by_anchor
+        .keys()
+        .filter(|anchor| !anchor_order.contains(anchor))
+        .chain(anchor_order.iter());
+
+    for anchor in draw_order {
+        let Some(ids_at_anchor) = notifications_by_anchor.get(anchor) else {
+            continue;
+        };
+
+        // Hidden notifications keep ticking but are left out of the stack, as are other pages
+        let ids_at_anchor: Vec<u64> = ids_at_anchor
+            .iter()
+            .copied()
+            .filter(|id| !options.off_page.contains(id))
+            .filter(|id| notifications.get(id).is_some_and(|state| state.is_visible()))
+            .collect();
         if ids_at_anchor.is_empty() {
             continue;
         }
 
         // Calculate stacking positions for this anchor
-        let stacked_notifications = calculate_stacking_positions(
+        let direction = options
+            .stack_directions
+            .get(anchor)
+            .copied()
+            .unwrap_or_else(|| StackDirection::for_anchor(*anchor));
+        let summaries = ids_at_anchor.iter().filter(|id| options.summaries.contains(id)).count();
+        let mut stacked_notifications = calculate_directed_stacking_positions(
             notifications,
             *anchor,
-            ids_at_anchor,
-            frame_area,
-            max_concurrent,
+            direction,
+            &ids_at_anchor,
+            area,
+            options.max_concurrent.map(|max| max + summaries),
         );
 
+        // Newest first: a notification's depth is how many newer ones share its stack
+        let mut by_age: Vec<(Instant, u64)> = stacked_notifications
+            .iter()
+            .filter_map(|stacked| notifications.get(&stacked.id).map(|state| (state.created_at(), stacked.id)))
+            .collect();
+        by_age.sort_unstable_by(|a, b| b.cmp(a));
+
+        // Entries faded past the threshold collapse; the rest restack without them
+        let kept = by_age
+            .iter()
+            .enumerate()
+            .take_while(|&(depth, _)| {
+                options.collapse_below <= 0.0 || 1.0 - options.depth_fade * depth as f32 >= options.collapse_below
+            })
+            .count();
+        let collapsed = by_age.len() - kept;
+        if collapsed > 0 {
+            by_age.truncate(kept);
+            let kept_ids: Vec<u64> = by_age.iter().map(|&(_, id)| id).collect();
+            stacked_notifications =
+                calculate_directed_stacking_positions(notifications, *anchor, direction, &kept_ids, area, None);
+        }
+        let far_end = stacked_notifications.len().saturating_sub(1);
+
         // Render each stacked notification
-        for stacked in stacked_notifications {
+        for (position, stacked) in stacked_notifications.into_iter().enumerate() {
             if let Some(state) = notifications.get_mut(&stacked.id) {
+                // Glide from where it was shown if its stack position moved
+                let previous = state.full_rect();
+                if let Some((duration, easing)) = options.reflow.filter(|(duration, _)| !duration.is_zero()) {
+                    if !previous.is_empty() && previous.as_position() != stacked.rect.as_position() {
+                        let shown = state.reflow_rect(previous);
+                        state.begin_reflow(shown, duration, easing);
+                    }
+                }
+
                 // Update the state's full_rect with stacked position
                 state.set_full_rect(stacked.rect);
+                state.set_baseline_anchor(direction.baseline_anchor(*anchor));
 
-                // Calculate current rect using animation
-                let current_rect = state.calculate_animation_rect(frame_area);
+                // Calculate current rect using animation, offset by any reflow in progress
+                let shown = state.reflow_rect(stacked.rect);
+                let shift = |rect: Rect| {
+                    if shown == stacked.rect {
+                        return rect;
+                    }
+                    Rect {
+                        x: (i32::from(rect.x) + i32::from(shown.x) - i32::from(stacked.rect.x)).max(0) as u16,
+                        y: (i32::from(rect.y) + i32::from(shown.y) - i32::from(stacked.rect.y)).max(0) as u16,
+                        ..rect
+                    }
+                    .intersection(area)
+                };
+                let current_rect = shift(state.calculate_animation_rect(area)).intersection(area);
 
                 if current_rect.width == 0 || current_rect.height == 0 {
                     continue;
                 }
 
-                // Resolve styles
+                // A custom renderer draws the whole notification itself
+                if let Some(draw) = custom_draw.as_mut() {
+                    state.set_action_rects(Vec::new());
+                    draw(state, frame, current_rect);
+                    if options.monochrome {
+                        strip_colors(frame.buffer_mut(), current_rect);
+                    }
+                    drawn.push(StackedNotification { id: stacked.id, rect: current_rect });
+                    continue;
+                }
+
+                // Resolve styles from the level theme, layered over the anchor's base style
+                let level_style = state.level().map(|level| options.level_theme.style(level));
                 let (base_block_style, base_border_style, base_title_style) = resolve_styles(
-                    state.level(),
-                    state.block_style(),
-                    state.border_style(),
+                    None,
+                    state.block_style().or(level_style.map(|style| style.body)),
+                    state.border_style().or(level_style.map(|style| style.border)),
                     state.title_style(),
                 );
+                let icon = match level_style.filter(|_| !state.has_animated_icon()) {
+                    Some(style) => style.icon.map(|glyph| format!(" {glyph}")),
+                    None => state.icon(),
+                };
+                let anchor_style = options.anchor_styles.get(anchor).copied().unwrap_or_default();
+                let base_block_style = anchor_style.patch(base_block_style);
+                let base_border_style = anchor_style.patch(base_border_style);
+                let base_title_style = anchor_style.patch(base_title_style);
 
                 // Apply fade effect if enabled
                 let (final_block_style, final_border_style, final_title_style, final_content_style) =
@@ -116,41 +326,244 @@ pub fn render_notifications<T: RenderableNotification>(
                         base_title_style,
                     );
 
+                // Shift colour with remaining time while dwelling (entry/exit keep their fade)
+                let (final_border_style, final_title_style, final_content_style) =
+                    match state.time_color().filter(|_| state.current_phase() == AnimationPhase::Dwelling) {
+                        Some(color) => (
+                            final_border_style.fg(color),
+                            final_title_style.fg(color),
+                            final_content_style.fg(color),
+                        ),
+                        None => (final_border_style, final_title_style, final_content_style),
+                    };
+
+                // Dim older notifications progressively
+                let (final_border_style, final_title_style, final_content_style) =
+                    if options.depth_fade > 0.0 {
+                        let depth = by_age.iter().position(|&(_, id)| id == stacked.id).unwrap_or(0);
+                        let amount = (options.depth_fade * depth as f32).min(MAX_DEPTH_DIM);
+                        (
+                            dim_style(final_border_style, amount),
+                            dim_style(final_title_style, amount),
+                            dim_style(final_content_style, amount),
+                        )
+                    } else {
+                        (final_border_style, final_title_style, final_content_style)
+                    };
+
+                // Highlight the keyboard-focused notification
+                let final_border_style = if options.focused == Some(stacked.id) {
+                    final_border_style.add_modifier(Modifier::BOLD)
+                } else {
+                    final_border_style
+                };
+
+                // Empty notifications without a title show just their icon as the body
+                let icon_only = state.title().is_none() && state.content().width() == 0;
+                let body = match icon.as_ref().filter(|_| icon_only) {
+                    Some(icon) => Text::from(Span::styled(icon.trim_start().to_string(), final_border_style))
+                        .alignment(Alignment::Center),
+                    None => {
+                        let mut content = state.content();
+                        if let Some(transform) = line_transform.as_mut() {
+                            content.lines = transform(state, content.lines);
+                        }
+                        content
+                    }
+                };
+
+                // Pills skip the bordered block entirely, carrying any repeat badge inline
+                if state.shape() == Shape::Pill {
+                    let mut body = body;
+                    if let Some(line) = body.lines.first_mut().filter(|_| state.repeat_count() > 1) {
+                        line.spans.push(Span::styled(format!(" (x{})", state.repeat_count()), final_title_style));
+                    }
+                    if stacked.rect.width > 0 && stacked.rect.height > 0 {
+                        frame.render_widget(Clear, shift(stacked.rect).intersection(area));
+                    }
+                    state.set_action_rects(Vec::new());
+                    render_pill(
+                        frame,
+                        current_rect,
+                        body,
+                        (final_block_style, final_border_style, final_content_style),
+                    );
+                    if options.monochrome {
+                        strip_colors(frame.buffer_mut(), current_rect.intersection(area));
+                    }
+                    drawn.push(StackedNotification {
+                        id: stacked.id,
+                        rect: current_rect.intersection(area),
+                    });
+                    continue;
+                }
+
                 // Build the block
                 let mut block = Block::default()
                     .style(final_block_style)
-                    .borders(Borders::ALL)
+                    .borders(state.borders())
                     .border_type(state.border_type())
                     .border_style(final_border_style)
                     .padding(state.padding());
 
-                // Add title with icon if present
-                if let Some(mut title_line) = state.title() {
-                    if let Some(icon_str) = get_level_icon(state.level()) {
+                // Add title with icon if present (animated icons show even without a title)
+                let title = state
+                    .title()
+                    .or_else(|| (state.has_animated_icon() && !icon_only).then(Line::default));
+                if let Some(mut title_line) = title {
+                    if let Some(icon_str) = icon {
                         let icon_span = Span::styled(icon_str, final_border_style);
                         title_line.spans.insert(0, icon_span);
                     }
                     block = block.title(title_line.alignment(Alignment::Center).style(final_title_style));
                 }
 
+                // Tag notifications still entering
+                if state.show_new_badge() {
+                    block = block.title(
+                        Line::from(" NEW ")
+                            .alignment(Alignment::Right)
+                            .style(final_title_style.add_modifier(Modifier::BOLD)),
+                    );
+                }
+
+                // Repeat badge for coalesced notifications
+                if state.repeat_count() > 1 {
+                    block = block.title(
+                        Line::from(format!(" (x{}) ", state.repeat_count()))
+                            .alignment(Alignment::Right)
+                            .style(final_title_style),
+                    );
+                }
+
+                // Page indicator on the notification nearest the anchor
+                // (falls back to a bare "n/m" when the box is too narrow for it)
+                if let Some((page, pages)) = options.page_labels.get(anchor).filter(|_| position == 0) {
+                    let mut label = format!(" Page {page}/{pages} ");
+                    if usize::from(current_rect.width) < label.len() + 2 {
+                        label = format!(" {page}/{pages} ");
+                    }
+                    block = block.title_bottom(
+                        Line::from(label).alignment(Alignment::Right).style(final_title_style),
+                    );
+                }
+
+                // Collapsed count on the far edge of the entry farthest from the baseline
+                if collapsed > 0 && position == far_end {
+                    let summary = Line::from(format!(" +{collapsed} more "))
+                        .alignment(Alignment::Left)
+                        .style(final_title_style);
+                    block = if direction == StackDirection::Up {
+                        block.title_top(summary)
+                    } else {
+                        block.title_bottom(summary)
+                    };
+                }
+
                 // Apply block effect from animation
                 let border_set = get_border_set(state.border_type());
-                block = state.apply_animation_block_effect(block, frame_area, &border_set);
+                block = state.apply_animation_block_effect(block, area, &border_set);
+
+                let inner = block.inner(current_rect);
+
+                // Rows left for content at full size, under any gauge and action row
+                let action_rows = u16::from(!state.actions().is_empty());
+                let gauge_rows = u16::from(state.progress().is_some());
+                let content_rows = block.inner(stacked.rect).height.saturating_sub(action_rows + gauge_rows);
 
-                // Create the paragraph
-                let paragraph = Paragraph::new(state.content())
-                    .wrap(Wrap { trim: true })
+                // Create the paragraph, wrapping plain content at rest only once
+                let cacheable = !icon_only && line_transform.is_none() && block.inner(stacked.rect).width == inner.width;
+                let wrapped = match state.wrapped_content(inner.width).filter(|_| cacheable) {
+                    Some(wrapped) => wrapped,
+                    None => {
+                        let wrapped = wrap_text(&body, inner.width);
+                        if cacheable {
+                            state.cache_wrapped_content(inner.width, wrapped.clone());
+                        }
+                        wrapped
+                    }
+                };
+                let body = clip_rows(wrapped, content_rows, final_border_style);
+                let paragraph = Paragraph::new(body)
                     .style(final_content_style)
                     .block(block);
 
                 // Render: Clear at stacked position, then Paragraph at animated position
                 if stacked.rect.width > 0 && stacked.rect.height > 0 {
-                    frame.render_widget(Clear, stacked.rect.intersection(frame_area));
+                    frame.render_widget(Clear, shift(stacked.rect).intersection(area));
                 }
                 frame.render_widget(paragraph, current_rect);
+
+                // Action buttons over the bottom inner row
+                let mut action_rects = Vec::new();
+                if action_rows > 0 && inner.height > 0 {
+                    let row = Rect { y: inner.bottom() - 1, height: 1, ..inner };
+                    let button_style = final_border_style.add_modifier(Modifier::BOLD);
+                    for ((rect, id), (label, _)) in layout_actions(state.actions(), row).into_iter().zip(state.actions()) {
+                        frame.render_widget(Line::styled(format!("[{label}]"), button_style), rect);
+                        action_rects.push((rect, id));
+                    }
+                }
+                state.set_action_rects(action_rects);
+
+                // Progress gauge over the bottom inner row, above any action buttons
+                if let Some(progress) = state.progress().filter(|_| inner.height > action_rows) {
+                    let row = Rect { y: inner.bottom() - 1 - action_rows, height: 1, ..inner };
+                    let gauge = Line::styled(progress_gauge(progress, row.width), final_border_style);
+                    frame.render_widget(Paragraph::new(gauge), row.intersection(area));
+                }
+
+                // Connector toward the pointed-at cell, clipped to the render area
+                if let Some(target) = state.pointer() {
+                    let buffer = frame.buffer_mut();
+                    for (position, symbol) in connector_cells(current_rect, target) {
+                        if area.contains(position) {
+                            buffer[position].set_symbol(symbol).set_style(final_border_style);
+                            if options.monochrome {
+                                strip_colors(buffer, Rect::new(position.x, position.y, 1, 1));
+                            }
+                        }
+                    }
+                }
+                if options.monochrome {
+                    strip_colors(frame.buffer_mut(), current_rect.intersection(area));
+                }
+                drawn.push(StackedNotification {
+                    id: stacked.id,
+                    rect: current_rect.intersection(area),
+                });
             }
         }
     }
+
+    drawn
+}
+
+/// Draws a `Shape::Pill` notification: the first content line centred between
+/// rounded end caps, on one row.
+fn render_pill(frame: &mut Frame, rect: Rect, content: Text<'static>, styles: (Style, Style, Style)) {
+    let (block_style, cap_style, content_style) = styles;
+    let rect = rect.intersection(frame.area());
+    if rect.width < 2 || rect.height == 0 {
+        return;
+    }
+    let row = Rect { height: 1, ..rect };
+
+    let line = content.lines.into_iter().next().unwrap_or_default();
+    let inner = Rect { x: row.x + 1, width: row.width - 2, ..row };
+    frame.render_widget(Block::default().style(block_style), row);
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Center).style(content_style), inner);
+
+    let buffer = frame.buffer_mut();
+    buffer[(row.x, row.y)].set_symbol("(").set_style(cap_style);
+    buffer[(row.right() - 1, row.y)].set_symbol(")").set_style(cap_style);
+}
+
+/// Resets the colours of every cell in `rect`, keeping symbols and modifiers.
+fn strip_colors(buffer: &mut Buffer, rect: Rect) {
+    for position in rect.intersection(buffer.area).positions() {
+        buffer[position].set_fg(Color::Reset).set_bg(Color::Reset);
+    }
 }
 
 /// Helper to apply fade effect if needed
@@ -222,4 +635,4 @@ fn get_border_set(border_type: BorderType) -> border::Set {
 
 
 // FILE: src/notifications/orc_render.rs - Orchestrates notification rendering
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.31.0
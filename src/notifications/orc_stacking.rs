@@ -1,11 +1,11 @@
 // FILE: src/notifications/orc_stacking.rs - Orchestrates notification stacking logic
-// VERSION: 1.0.1
-// WCTX: TDD implementation of OFPF render orchestrators
-// CLOG: Fixed result ordering to maintain chronological order
+// VERSION: 1.2.0
+// WCTX: Stack growth direction override
+// CLOG: Add calculate_directed_stacking_positions
 
 use crate::notifications::functions::fnc_calculate_anchor_position::calculate_anchor_position;
 use crate::notifications::functions::fnc_calculate_rect::calculate_rect;
-use crate::notifications::types::{Anchor, AnimationPhase};
+use crate::notifications::types::{Anchor, AnimationPhase, StackDirection};
 use ratatui::prelude::*;
 use std::collections::HashMap;
 use std::time::Instant;
@@ -33,14 +33,18 @@ pub trait StackableNotification {
     /// Calculate the notification's content size based on frame area.
     /// Returns (width, height) tuple.
     fn calculate_content_size(&self, frame_area: Rect) -> (u16, u16);
+    /// Whether the notification is pinned to the anchor end of its stack.
+    fn is_pinned_top(&self) -> bool {
+        false
+    }
 }
 
 /// Calculate stacking positions for notifications at a given anchor.
 ///
 /// This function implements the core stacking algorithm:
 /// 1. Filters to visible notifications (excludes Pending and Finished)
-/// 2. Sorts by creation time (oldest first)
-/// 3. Applies max_concurrent limit (keeps newest N)
+/// 2. Sorts by creation time (oldest first, ties broken by ID)
+/// 3. Applies max_concurrent limit (keeps pinned, then newest N)
 /// 4. Determines stacking direction based on anchor
 /// 5. Calculates accumulated heights and positions
 /// 6. Returns list of (id, final_stacked_rect) pairs
@@ -66,6 +70,24 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     ids_at_anchor: &[u64],
     frame_area: Rect,
     max_concurrent: Option<usize>,
+) -> Vec<StackedNotification> {
+    let direction = StackDirection::for_anchor(anchor);
+    calculate_directed_stacking_positions(notifications, anchor, direction, ids_at_anchor, frame_area, max_concurrent)
+}
+
+/// Calculate stacking positions for a stack growing in `direction`.
+///
+/// Like `calculate_stacking_positions`, which uses the anchor's default
+/// direction. The anchor still sets the horizontal alignment; an upward
+/// stack at a top anchor (or a downward one at a bottom anchor) rests on the
+/// opposite edge of the frame in the same column.
+pub fn calculate_directed_stacking_positions<T: StackableNotification>(
+    notifications: &HashMap<u64, T>,
+    anchor: Anchor,
+    direction: StackDirection,
+    ids_at_anchor: &[u64],
+    frame_area: Rect,
+    max_concurrent: Option<usize>,
 ) -> Vec<StackedNotification> {
     // 1. Filter to visible states and collect data (ID, Creation Time, Calculated Height, Width)
     let mut visible_states_data: Vec<(u64, Instant, u16, u16)> = ids_at_anchor
@@ -93,19 +115,22 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
         })
         .collect();
 
-    // 2. Sort by creation time (oldest first)
-    visible_states_data.sort_unstable_by_key(|&(_, created_at, _, _)| created_at);
+    // 2. Sort by creation time (oldest first), lower IDs first on equal times
+    visible_states_data.sort_unstable_by_key(|&(id, created_at, _, _)| (created_at, id));
 
-    // 3. Apply max_concurrent limit (take the newest N items)
+    // 3. Apply max_concurrent limit (pinned items always stay, newest others fill the rest)
     let max_concurrent = max_concurrent.unwrap_or(usize::MAX);
-    let num_to_render = visible_states_data.len().min(max_concurrent);
-    let candidate_data = &visible_states_data[visible_states_data.len() - num_to_render..];
-
-    // 4. Determine stacking direction & available height
-    let is_stacking_up = matches!(
-        anchor,
-        Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight
-    );
+    let (mut pinned, unpinned): (Vec<_>, Vec<_>) = visible_states_data
+        .iter()
+        .partition(|(id, ..)| notifications.get(id).is_some_and(|state| state.is_pinned_top()));
+    pinned.truncate(max_concurrent);
+    let unpinned_slots = (max_concurrent - pinned.len()).min(unpinned.len());
+    let candidate_data = &unpinned[unpinned.len() - unpinned_slots..];
+    let num_to_render = pinned.len() + candidate_data.len();
+
+    // 4. Determine stacking direction, baseline & available height
+    let is_stacking_up = direction == StackDirection::Up;
+    let anchor = direction.baseline_anchor(anchor);
     let anchor_pos = calculate_anchor_position(anchor, frame_area);
     let available_height = if is_stacking_up {
         anchor_pos.y.saturating_sub(frame_area.y)
@@ -122,10 +147,11 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     let mut result_list: Vec<StackedNotification> = Vec::with_capacity(num_to_render);
 
     // Create iterator in correct order for visual stacking
+    // Pinned items come first in either direction, oldest pinned nearest the anchor
     let iter_order: Box<dyn Iterator<Item = &(u64, Instant, u16, u16)>> = if is_stacking_up {
-        Box::new(candidate_data.iter().rev()) // Newest first visually appears at bottom
+        Box::new(pinned.iter().chain(candidate_data.iter().rev()).copied()) // Newest first visually appears at bottom
     } else {
-        Box::new(candidate_data.iter()) // Oldest first visually appears at top
+        Box::new(pinned.iter().chain(candidate_data.iter()).copied()) // Oldest first visually appears at top
     };
 
     for &(id, _, height, width) in iter_order {
@@ -189,4 +215,4 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
 }
 
 // FILE: src/notifications/orc_stacking.rs - Orchestrates notification stacking logic
-// END OF VERSION: 1.0.1
+// END OF VERSION: 1.2.0
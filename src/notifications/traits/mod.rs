@@ -0,0 +1,13 @@
+// FILE: src/notifications/traits/mod.rs - Traits module
+// VERSION: 1.1.0
+// WCTX: Custom renderers
+// CLOG: Export NotificationRenderer
+
+mod notification_renderer;
+mod notifications_ext;
+
+pub use notification_renderer::NotificationRenderer;
+pub use notifications_ext::NotificationsExt;
+
+// FILE: src/notifications/traits/mod.rs - Traits module
+// END OF VERSION: 1.1.0
@@ -0,0 +1,45 @@
+// FILE: src/notifications/traits/notification_renderer.rs - Custom per-notification drawing
+// VERSION: 1.0.0
+// WCTX: Custom renderers
+// CLOG: Initial creation
+
+use crate::notifications::classes::{Notification, NotificationState};
+use ratatui::prelude::{Frame, Rect};
+
+/// Draws a single notification in place of the built-in look.
+///
+/// Installed with `Notifications::with_renderer`. The manager still owns
+/// layout: it sizes, stacks and animates every notification, then calls
+/// `render` with the rect to draw into this frame (already moved and
+/// clipped by the entry or exit animation). Everything inside that rect,
+/// including clearing what lies beneath, is up to the renderer. Hit-testing
+/// and `damage_rect` use the same rect.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::prelude::*;
+/// use ratatui::widgets::{Clear, Paragraph};
+/// use ratatui_notifications::notifications::{Notification, NotificationRenderer, NotificationState, Notifications};
+///
+/// struct Minimal;
+///
+/// impl NotificationRenderer for Minimal {
+///     fn render(&self, notification: &Notification, _state: &NotificationState, frame: &mut Frame, rect: Rect) {
+///         frame.render_widget(Clear, rect);
+///         frame.render_widget(Paragraph::new(notification.content().clone()).reversed(), rect);
+///     }
+/// }
+///
+/// let notifications = Notifications::new().with_renderer(Minimal);
+/// ```
+pub trait NotificationRenderer: Send {
+    /// Draws `notification` into `rect`.
+    ///
+    /// `state` carries the animation phase and progress, for renderers that
+    /// want their own fades or highlights.
+    fn render(&self, notification: &Notification, state: &NotificationState, frame: &mut Frame, rect: Rect);
+}
+
+// FILE: src/notifications/traits/notification_renderer.rs - Custom per-notification drawing
+// END OF VERSION: 1.0.0
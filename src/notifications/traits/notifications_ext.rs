@@ -0,0 +1,89 @@
+// FILE: src/notifications/traits/notifications_ext.rs - One-liner notification shortcuts
+// VERSION: 1.2.1
+// WCTX: Level-named shortcut
+// CLOG: Document warning as canonical, warn as its alias
+
+use crate::notifications::classes::NotificationBuilder;
+use crate::notifications::orc_manager::Notifications;
+use crate::notifications::types::{Level, NotificationError};
+use ratatui::text::Text;
+
+/// Shorthand methods for adding leveled notifications with default settings.
+///
+/// Each method builds a notification with the matching `Level` and otherwise
+/// default configuration, adds it to the manager (so overflow limits still
+/// apply), and returns the new ID.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::{Notifications, NotificationsExt};
+///
+/// let mut notifications = Notifications::new();
+/// notifications.info("Saved").unwrap();
+/// notifications.error("Upload failed").unwrap();
+/// ```
+pub trait NotificationsExt {
+    /// Adds a notification with the given level and default settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the message fails builder validation.
+    fn notify(
+        &mut self,
+        level: Level,
+        message: impl Into<Text<'static>>,
+    ) -> Result<u64, NotificationError>;
+
+    /// Adds an `Info` notification.
+    fn info(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Info, message)
+    }
+
+    /// Adds a `Success` notification.
+    fn success(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Success, message)
+    }
+
+    /// Adds a `Warn` notification.
+    ///
+    /// This is the canonical warning shortcut; `warn` forwards here, so an
+    /// implementor overriding one should override `warning`.
+    fn warning(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Warn, message)
+    }
+
+    /// Alias of `warning`, named after `Level::Warn`; always calls `warning`.
+    fn warn(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.warning(message)
+    }
+
+    /// Adds an `Error` notification.
+    fn error(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Error, message)
+    }
+
+    /// Adds a `Debug` notification.
+    fn debug(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Debug, message)
+    }
+
+    /// Adds a `Trace` notification.
+    fn trace(&mut self, message: impl Into<Text<'static>>) -> Result<u64, NotificationError> {
+        self.notify(Level::Trace, message)
+    }
+}
+
+impl NotificationsExt for Notifications {
+    fn notify(
+        &mut self,
+        level: Level,
+        message: impl Into<Text<'static>>,
+    ) -> Result<u64, NotificationError> {
+        let notification = NotificationBuilder::new(message).level(level).build()?;
+        self.add(notification)
+    }
+}
+
+// FILE: src/notifications/traits/notifications_ext.rs - One-liner notification shortcuts
+// END OF VERSION: 1.2.1
@@ -0,0 +1,16 @@
+// FILE: src/notifications/types/action_id.rs - Identifier for notification action buttons
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
+
+/// Identifies an action button on a notification.
+///
+/// Chosen by the app when attaching the action with
+/// `NotificationBuilder::action`, and handed back by
+/// `Notifications::action_at` when the button is under the pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionId(pub u32);
+
+// FILE: src/notifications/types/action_id.rs - Identifier for notification action buttons
+// END OF VERSION: 1.1.0
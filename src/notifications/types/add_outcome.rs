@@ -0,0 +1,17 @@
+// FILE: src/notifications/types/add_outcome.rs - Result of adding a notification
+// VERSION: 1.0.1
+// WCTX: Global notification cap
+// CLOG: Mention max_total
+
+/// What `Notifications::add_with_eviction` did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddOutcome {
+    /// ID of the added notification (or of the one it updated or repeated).
+    pub id: u64,
+
+    /// Notification removed to stay within `max_concurrent` or `max_total`, if any.
+    pub evicted: Option<u64>,
+}
+
+// FILE: src/notifications/types/add_outcome.rs - Result of adding a notification
+// END OF VERSION: 1.0.1
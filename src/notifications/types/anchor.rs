@@ -1,7 +1,7 @@
 // FILE: src/notifications/types/anchor.rs - Screen anchor position enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 /// Screen position from which notifications expand.
 ///
@@ -9,6 +9,7 @@
 /// outward from that anchor point. For example, `BottomRight` means notifications
 /// appear in the bottom-right corner and stack upward/leftward.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Anchor {
     TopLeft,
@@ -25,4 +26,4 @@ pub enum Anchor {
 }
 
 // FILE: src/notifications/types/anchor.rs - Screen anchor position enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
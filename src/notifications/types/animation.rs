@@ -1,10 +1,11 @@
 // FILE: src/notifications/types/animation.rs - Animation type enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 /// Animation style for notification entry and exit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Animation {
     /// Slide animation from a direction (default).
@@ -25,7 +26,13 @@ pub enum Animation {
     /// Notification fades in when appearing and fades out when dismissed.
     /// Subtle and non-intrusive.
     Fade,
+
+    /// Horizontal collapse animation.
+    ///
+    /// Notification widens from its anchor side when entering and shrinks to
+    /// zero width toward it when exiting, keeping its full height.
+    Collapse,
 }
 
 // FILE: src/notifications/types/animation.rs - Animation type enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
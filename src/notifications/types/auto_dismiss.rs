@@ -1,7 +1,7 @@
 // FILE: src/notifications/types/auto_dismiss.rs - Auto-dismiss behavior enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 use std::time::Duration;
 
@@ -10,6 +10,7 @@ use std::time::Duration;
 /// Determines whether a notification will automatically dismiss after
 /// a specified duration or remain visible until manually dismissed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AutoDismiss {
     /// Notification remains visible until manually dismissed.
     Never,
@@ -25,4 +26,4 @@ impl Default for AutoDismiss {
 }
 
 // FILE: src/notifications/types/auto_dismiss.rs - Auto-dismiss behavior enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
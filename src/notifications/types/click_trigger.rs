@@ -0,0 +1,19 @@
+// FILE: src/notifications/types/click_trigger.rs - Mouse event that dismisses a clicked notification
+// VERSION: 1.0.0
+// WCTX: Adding click-to-dismiss
+// CLOG: Initial creation
+
+/// Which mouse event dismisses a clicked notification in `handle_click`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ClickTrigger {
+    /// Dismiss as soon as the button goes down over a notification (default).
+    #[default]
+    Press,
+
+    /// Dismiss when the button is released over the same notification it
+    /// was pressed on. Dragging off before releasing cancels the dismiss.
+    Release,
+}
+
+// FILE: src/notifications/types/click_trigger.rs - Mouse event that dismisses a clicked notification
+// END OF VERSION: 1.0.0
@@ -0,0 +1,27 @@
+// FILE: src/notifications/types/debug_stats.rs - Manager state footprint snapshot
+// VERSION: 1.0.0
+// WCTX: Adding manager diagnostics
+// CLOG: Initial creation
+
+/// Snapshot of how much state a `Notifications` manager is holding.
+///
+/// Intended for diagnostics: a `states` count that only ever grows usually
+/// means notifications with `AutoDismiss::Never` are never being removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DebugStats {
+    /// Notification states held, including waiting and finishing ones.
+    pub states: usize,
+
+    /// States waiting for a slot because their anchor hit `max_visible`.
+    pub queued: usize,
+
+    /// IDs tracked across all anchor groups. Matches `states` unless the
+    /// anchor index has drifted out of sync.
+    pub anchor_entries: usize,
+
+    /// Total IDs handed out by `add` since the manager was created.
+    pub ids_issued: u64,
+}
+
+// FILE: src/notifications/types/debug_stats.rs - Manager state footprint snapshot
+// END OF VERSION: 1.0.0
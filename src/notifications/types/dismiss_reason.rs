@@ -0,0 +1,28 @@
+// FILE: src/notifications/types/dismiss_reason.rs - Why a notification was dismissed
+// VERSION: 1.0.2
+// WCTX: Global notification cap
+// CLOG: Mention max_total
+
+/// Why a notification left the manager, passed to its `on_dismiss` callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DismissReason {
+    /// Its display time ran out and the exit animation finished (default).
+    #[default]
+    Expired,
+
+    /// The app dismissed it: `remove`, a click, `dismiss_when`, or progress
+    /// reaching `1.0`.
+    Manual,
+
+    /// Evicted to keep its anchor within `max_concurrent`, or all anchors within `max_total`.
+    Overflow,
+
+    /// Removed by `clear`, `force_clear` or `clear_anchor`.
+    Cleared,
+
+    /// Replaced by a newer notification of the same kind.
+    Replaced,
+}
+
+// FILE: src/notifications/types/dismiss_reason.rs - Why a notification was dismissed
+// END OF VERSION: 1.0.2
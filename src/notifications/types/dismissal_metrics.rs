@@ -0,0 +1,54 @@
+// FILE: src/notifications/types/dismissal_metrics.rs - Counts of how notifications ended
+// VERSION: 1.0.1
+// WCTX: Per-anchor clearing
+// CLOG: Mention clear_anchor
+
+use super::DismissReason;
+
+/// Running counts of how notifications left a `Notifications` manager.
+///
+/// Returned by `Notifications::dismissal_metrics`; counts accumulate until
+/// `Notifications::reset_dismissal_metrics`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DismissalMetrics {
+    /// Display time ran out.
+    pub expired: u64,
+
+    /// Dismissed by the app: `remove`, a click, `dismiss_when` or completed progress.
+    pub manual: u64,
+
+    /// Evicted by `max_concurrent`.
+    pub overflow: u64,
+
+    /// Removed by `clear`, `force_clear` or `clear_anchor`.
+    pub cleared: u64,
+
+    /// Replaced by a newer notification of the same kind.
+    pub replaced: u64,
+
+    /// Expiries the dismiss guard vetoed. Not a removal: the notification
+    /// stays, and is counted again when it finally goes.
+    pub vetoed: u64,
+}
+
+impl DismissalMetrics {
+    /// Total notifications removed, across every reason (vetoes excluded).
+    pub fn total(&self) -> u64 {
+        self.expired + self.manual + self.overflow + self.cleared + self.replaced
+    }
+
+    /// Counts one removal for `reason`.
+    pub(crate) fn record(&mut self, reason: DismissReason) {
+        let counter = match reason {
+            DismissReason::Expired => &mut self.expired,
+            DismissReason::Manual => &mut self.manual,
+            DismissReason::Overflow => &mut self.overflow,
+            DismissReason::Cleared => &mut self.cleared,
+            DismissReason::Replaced => &mut self.replaced,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
+
+// FILE: src/notifications/types/dismissal_metrics.rs - Counts of how notifications ended
+// END OF VERSION: 1.0.1
@@ -0,0 +1,28 @@
+// FILE: src/notifications/types/easing.rs - Easing curve enum
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
+
+/// Curve mapping linear animation progress to eased progress, for stack
+/// reflows and per-notification entry and exit animations.
+///
+/// See `apply_easing` for the formulas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed (default).
+    #[default]
+    Linear,
+
+    /// Starts slowly and accelerates.
+    EaseInQuad,
+
+    /// Starts quickly and decelerates.
+    EaseOutCubic,
+
+    /// Slow at both ends, fastest in the middle.
+    EaseInOutSine,
+}
+
+// FILE: src/notifications/types/easing.rs - Easing curve enum
+// END OF VERSION: 1.1.0
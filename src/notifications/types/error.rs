@@ -1,7 +1,9 @@
 // FILE: src/notifications/types/error.rs - Notification error type
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.3.0
+// WCTX: Field-specific builder errors
+// CLOG: Add InvalidDuration and InvalidSize, count content in characters
+
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -13,10 +15,36 @@ pub enum NotificationError {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
-    /// Content exceeds size limits.
-    #[error("Content too large: {0} bytes exceeds limit of {1} bytes")]
+    /// Content exceeds size limits (actual and maximum characters).
+    #[error("Content too large: {0} characters exceeds limit of {1} characters")]
     ContentTooLarge(usize, usize),
+
+    /// A builder duration that cannot be honoured, e.g. a zero `duration`.
+    #[error("Invalid {field}: {got:?} must be greater than zero")]
+    InvalidDuration {
+        /// Builder method that set it
+        field: &'static str,
+        /// The rejected duration
+        got: Duration,
+    },
+
+    /// A size limit too small to draw anything, e.g. `max_width(0)`.
+    #[error("Invalid {field}: {got} cells is too small, must be at least 1")]
+    InvalidSize {
+        /// Builder method that set it
+        field: &'static str,
+        /// The rejected number of cells
+        got: u16,
+    },
+
+    /// The anchor is full and every notification there outranks this one.
+    #[error("Rejected: every notification at the anchor has a higher priority")]
+    Outranked,
+
+    /// The anchor's `rate_limit` budget for the current window is spent.
+    #[error("Rate limited: too many notifications at this anchor")]
+    RateLimited,
 }
 
 // FILE: src/notifications/types/error.rs - Notification error type
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
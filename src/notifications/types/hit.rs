@@ -0,0 +1,28 @@
+// FILE: src/notifications/types/hit.rs - Hit-test result for notifications under a point
+// VERSION: 1.1.0
+// WCTX: Notification action buttons
+// CLOG: Add HitRegion::Action
+
+/// Part of a notification that a point falls on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HitRegion {
+    /// Anywhere within the notification's box, border included.
+    Body,
+
+    /// An action button attached with `NotificationBuilder::action`.
+    Action(super::ActionId),
+}
+
+/// A notification found under a screen position by `Notifications::hit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hit {
+    /// ID of the notification under the point.
+    pub id: u64,
+
+    /// Which part of the notification was hit.
+    pub region: HitRegion,
+}
+
+// FILE: src/notifications/types/hit.rs - Hit-test result for notifications under a point
+// END OF VERSION: 1.1.0
@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/initial_render.rs - How notifications added since the last tick render
+// VERSION: 1.0.0
+// WCTX: Adding initial render behaviour
+// CLOG: Initial creation
+
+/// How `render` draws a notification that was added but not yet ticked.
+///
+/// Entry animations start on the first `tick` after `add`, so a render in
+/// between has nothing to animate yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InitialRender {
+    /// Skip it until the next tick starts its entry animation (default).
+    #[default]
+    Hidden,
+
+    /// Show it in its final position at once, skipping the entry animation.
+    AtRest,
+
+    /// Start the entry animation now, drawing its first frame (e.g. off-screen
+    /// for a slide, fully transparent for a fade).
+    Animating,
+}
+
+// FILE: src/notifications/types/initial_render.rs - How notifications added since the last tick render
+// END OF VERSION: 1.0.0
@@ -0,0 +1,21 @@
+// FILE: src/notifications/types/kind_mode.rs - Handling of notifications sharing a kind
+// VERSION: 1.0.0
+// WCTX: Adding unique notification kinds
+// CLOG: Initial creation
+
+/// What `add` does when a notification arrives with the same kind as one
+/// already showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KindMode {
+    /// Remove the existing notification and add the new one, which plays its
+    /// entry animation (default).
+    #[default]
+    Replace,
+
+    /// Copy the new notification's text, title, level and styles into the
+    /// existing one, which keeps its ID, position and animation phase.
+    Update,
+}
+
+// FILE: src/notifications/types/kind_mode.rs - Handling of notifications sharing a kind
+// END OF VERSION: 1.0.0
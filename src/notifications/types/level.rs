@@ -1,18 +1,22 @@
 // FILE: src/notifications/types/level.rs - Notification severity level enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 /// Severity level of a notification.
 ///
 /// Affects the visual styling of the notification (colors, borders).
 /// Higher severity levels typically use more prominent colors to draw attention.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Level {
     /// Informational message (default).
     #[default]
     Info,
 
+    /// Completed operation.
+    Success,
+
     /// Warning message.
     Warn,
 
@@ -27,4 +31,4 @@ pub enum Level {
 }
 
 // FILE: src/notifications/types/level.rs - Notification severity level enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
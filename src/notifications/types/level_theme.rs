@@ -0,0 +1,73 @@
+// FILE: src/notifications/types/level_theme.rs - Per-level default colours and icons
+// VERSION: 1.0.0
+// WCTX: Adding Level::Success and level themes
+// CLOG: Initial creation
+
+use super::Level;
+use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+use crate::notifications::functions::fnc_resolve_styles::resolve_styles;
+use ratatui::style::Style;
+use std::collections::HashMap;
+
+/// How notifications of one level look unless they set their own styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelStyle {
+    /// Border colour; the title takes its foreground too.
+    pub border: Style,
+
+    /// Body foreground and background, e.g. `Style::new().bg(Color::Red)`.
+    pub body: Style,
+
+    /// Glyph drawn before the title, or `None` for no icon.
+    pub icon: Option<&'static str>,
+}
+
+impl LevelStyle {
+    /// The built-in look of a level.
+    pub fn builtin(level: Level) -> Self {
+        let (body, border, _) = resolve_styles(Some(level), None, None, None);
+        Self {
+            border,
+            body,
+            icon: get_level_icon(Some(level)).map(str::trim_start),
+        }
+    }
+}
+
+/// Table of per-level looks used by `Notifications::level_theme`.
+///
+/// Starts from the built-in colours and icons; `set` remaps single levels.
+/// A notification's own `border_style`, `block_style` and `title_style`
+/// still override its level's entry.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::style::{Color, Style};
+/// use ratatui_notifications::notifications::{Level, LevelStyle, LevelTheme};
+///
+/// let theme = LevelTheme::default().set(
+///     Level::Warn,
+///     LevelStyle { border: Style::new().fg(Color::Rgb(255, 200, 0)), ..LevelStyle::builtin(Level::Warn) },
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LevelTheme {
+    overrides: HashMap<Level, LevelStyle>,
+}
+
+impl LevelTheme {
+    /// Replaces the look of `level`.
+    pub fn set(mut self, level: Level, style: LevelStyle) -> Self {
+        self.overrides.insert(level, style);
+        self
+    }
+
+    /// Returns the look of `level`: its override, or the built-in one.
+    pub fn style(&self, level: Level) -> LevelStyle {
+        self.overrides.get(&level).copied().unwrap_or_else(|| LevelStyle::builtin(level))
+    }
+}
+
+// FILE: src/notifications/types/level_theme.rs - Per-level default colours and icons
+// END OF VERSION: 1.0.0
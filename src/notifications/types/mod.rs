@@ -1,29 +1,57 @@
 // FILE: src/notifications/types/mod.rs - Module declarations and re-exports for notification types
-// VERSION: 1.1.0
-// WCTX: OFPF migration
-// CLOG: Made AnimationPhase public for animation function testing
+// VERSION: 1.15.0
+// WCTX: Notification action buttons
+// CLOG: Export ActionId
 
+mod action_id;
+mod add_outcome;
 mod anchor;
 mod animation;
 mod animation_phase;
 mod auto_dismiss;
+mod click_trigger;
+mod debug_stats;
+mod dismiss_reason;
+mod dismissal_metrics;
+mod easing;
 mod error;
+mod hit;
+mod initial_render;
+mod kind_mode;
 mod level;
+mod level_theme;
 mod overflow;
+mod phase_event;
+mod shape;
 mod size_constraint;
 mod slide_direction;
+mod stack_direction;
 mod timing;
 
+pub use action_id::ActionId;
+pub use add_outcome::AddOutcome;
 pub use anchor::Anchor;
 pub use animation::Animation;
 pub use animation_phase::AnimationPhase;
 pub use auto_dismiss::AutoDismiss;
+pub use click_trigger::ClickTrigger;
+pub use debug_stats::DebugStats;
+pub use dismiss_reason::DismissReason;
+pub use dismissal_metrics::DismissalMetrics;
+pub use easing::Easing;
 pub use error::NotificationError;
+pub use hit::{Hit, HitRegion};
+pub use initial_render::InitialRender;
+pub use kind_mode::KindMode;
 pub use level::Level;
+pub use level_theme::{LevelStyle, LevelTheme};
 pub use overflow::Overflow;
+pub use phase_event::PhaseEvent;
+pub use shape::Shape;
 pub use size_constraint::SizeConstraint;
 pub use slide_direction::SlideDirection;
+pub use stack_direction::StackDirection;
 pub use timing::Timing;
 
 // FILE: src/notifications/types/mod.rs - Module declarations and re-exports for notification types
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.15.0
@@ -1,13 +1,15 @@
 // FILE: src/notifications/types/overflow.rs - Notification overflow behavior enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.3.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 /// Behavior when notification limit is reached.
 ///
-/// Determines which notification to discard when the maximum number
-/// of concurrent notifications is exceeded.
+/// Determines which notification to discard, or whether to queue, when the
+/// maximum number of concurrent notifications is exceeded.
+/// The discard rules only choose among the lowest `priority` at the anchor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Overflow {
     /// Discard the oldest notification when limit is reached (default).
     #[default]
@@ -15,7 +17,16 @@ pub enum Overflow {
 
     /// Discard the newest notification when limit is reached.
     DiscardNewest,
+
+    /// Discard the lowest-scoring notification, as rated by the function
+    /// set with `Notifications::overflow_scorer`. Ties go to the oldest.
+    /// Without a scorer this behaves like `DiscardOldest`.
+    Scored,
+
+    /// Discard nothing: extra notifications wait, oldest first, and enter
+    /// (with their timers starting fresh) as showing ones finish.
+    Queue,
 }
 
 // FILE: src/notifications/types/overflow.rs - Notification overflow behavior enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
@@ -0,0 +1,24 @@
+// FILE: src/notifications/types/phase_event.rs - Animation phase transition record
+// VERSION: 1.0.0
+// WCTX: Phase transition events
+// CLOG: Initial creation
+
+use super::AnimationPhase;
+
+/// A notification moving from one animation phase to another during `tick`.
+///
+/// Collected by the manager and drained with `Notifications::take_events`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseEvent {
+    /// The notification that changed phase.
+    pub id: u64,
+
+    /// Phase before the tick.
+    pub from: AnimationPhase,
+
+    /// Phase after the tick.
+    pub to: AnimationPhase,
+}
+
+// FILE: src/notifications/types/phase_event.rs - Animation phase transition record
+// END OF VERSION: 1.0.0
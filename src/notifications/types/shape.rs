@@ -0,0 +1,21 @@
+// FILE: src/notifications/types/shape.rs - Notification outline shape enum
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
+
+/// Outline a notification is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shape {
+    /// Bordered box holding a title and wrapped content (default).
+    #[default]
+    Box,
+
+    /// One row with rounded `(` and `)` ends and the first content line
+    /// centred between them. Borders, title and wrapping are not used, so
+    /// this suits short single-line notifications.
+    Pill,
+}
+
+// FILE: src/notifications/types/shape.rs - Notification outline shape enum
+// END OF VERSION: 1.1.0
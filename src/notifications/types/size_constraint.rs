@@ -1,12 +1,13 @@
 // FILE: src/notifications/types/size_constraint.rs - Size constraint enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 /// Constraint on notification dimensions.
 ///
 /// Allows specifying sizes as absolute values or percentages of available space.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SizeConstraint {
     /// Absolute size in terminal cells/characters.
     Absolute(u16),
@@ -16,4 +17,4 @@ pub enum SizeConstraint {
 }
 
 // FILE: src/notifications/types/size_constraint.rs - Size constraint enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
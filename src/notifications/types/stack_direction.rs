@@ -0,0 +1,48 @@
+// FILE: src/notifications/types/stack_direction.rs - Stack growth direction enum
+// VERSION: 1.0.0
+// WCTX: Stack growth direction override
+// CLOG: Initial creation
+
+use super::Anchor;
+
+/// Direction a stack of notifications grows in from its baseline.
+///
+/// By default bottom anchors stack `Up` and all others `Down`. Set per
+/// anchor with `Notifications::stack_direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StackDirection {
+    /// The newest notification rests on the baseline; older ones rise above it.
+    Up,
+
+    /// The oldest notification hangs from the baseline; newer ones go below it.
+    Down,
+}
+
+impl StackDirection {
+    /// Returns the direction an anchor stacks in unless overridden.
+    pub fn for_anchor(anchor: Anchor) -> Self {
+        match anchor {
+            Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight => Self::Up,
+            _ => Self::Down,
+        }
+    }
+
+    /// Anchor whose vertical position serves as the baseline.
+    ///
+    /// A top anchor has no room to grow up from, nor a bottom anchor down,
+    /// so those stacks rest on the opposite edge of the same column instead.
+    pub(crate) fn baseline_anchor(self, anchor: Anchor) -> Anchor {
+        match (self, anchor) {
+            (Self::Up, Anchor::TopLeft) => Anchor::BottomLeft,
+            (Self::Up, Anchor::TopCenter) => Anchor::BottomCenter,
+            (Self::Up, Anchor::TopRight) => Anchor::BottomRight,
+            (Self::Down, Anchor::BottomLeft) => Anchor::TopLeft,
+            (Self::Down, Anchor::BottomCenter) => Anchor::TopCenter,
+            (Self::Down, Anchor::BottomRight) => Anchor::TopRight,
+            _ => anchor,
+        }
+    }
+}
+
+// FILE: src/notifications/types/stack_direction.rs - Stack growth direction enum
+// END OF VERSION: 1.0.0
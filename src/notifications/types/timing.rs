@@ -1,7 +1,7 @@
 // FILE: src/notifications/types/timing.rs - Animation timing enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Derive serde traits behind the serde feature
 
 use std::time::Duration;
 
@@ -10,6 +10,7 @@ use std::time::Duration;
 /// Controls whether animation durations are explicitly specified or
 /// automatically calculated based on content or system defaults.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Timing {
     /// Fixed duration specified by user.
     Fixed(Duration),
@@ -23,4 +24,4 @@ pub enum Timing {
 }
 
 // FILE: src/notifications/types/timing.rs - Animation timing enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
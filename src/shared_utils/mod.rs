@@ -1,9 +1,11 @@
 // FILE: src/shared_utils/mod.rs - Shared utility modules
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Serde support for notifications
+// CLOG: Add serde_ratatui adapters
 
 pub mod math;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_ratatui;
 
 // FILE: src/shared_utils/mod.rs - Shared utility modules
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
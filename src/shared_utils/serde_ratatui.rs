@@ -0,0 +1,186 @@
+// FILE: src/shared_utils/serde_ratatui.rs - Serde adapters for ratatui types
+// VERSION: 1.0.0
+// WCTX: Serde support for notifications
+// CLOG: Initial creation
+
+//! `serde(with = ...)` adapters for the ratatui types that `Notification`
+//! holds but ratatui's own `serde` feature does not cover: text, padding
+//! and border types. Styles and colours go through ratatui's derives.
+
+use ratatui::layout::Alignment;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{BorderType, Padding};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+enum AlignmentRepr {
+    Left,
+    Center,
+    Right,
+}
+
+impl From<Alignment> for AlignmentRepr {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Left => Self::Left,
+            Alignment::Center => Self::Center,
+            Alignment::Right => Self::Right,
+        }
+    }
+}
+
+impl From<AlignmentRepr> for Alignment {
+    fn from(alignment: AlignmentRepr) -> Self {
+        match alignment {
+            AlignmentRepr::Left => Self::Left,
+            AlignmentRepr::Center => Self::Center,
+            AlignmentRepr::Right => Self::Right,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SpanRepr {
+    content: String,
+    style: Style,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LineRepr {
+    spans: Vec<SpanRepr>,
+    style: Style,
+    alignment: Option<AlignmentRepr>,
+}
+
+impl From<&Line<'_>> for LineRepr {
+    fn from(line: &Line<'_>) -> Self {
+        Self {
+            spans: line
+                .spans
+                .iter()
+                .map(|span| SpanRepr { content: span.content.to_string(), style: span.style })
+                .collect(),
+            style: line.style,
+            alignment: line.alignment.map(AlignmentRepr::from),
+        }
+    }
+}
+
+impl From<LineRepr> for Line<'static> {
+    fn from(line: LineRepr) -> Self {
+        Line {
+            spans: line.spans.into_iter().map(|span| Span::styled(span.content, span.style)).collect(),
+            style: line.style,
+            alignment: line.alignment.map(Alignment::from),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct TextRepr {
+    lines: Vec<LineRepr>,
+    style: Style,
+    alignment: Option<AlignmentRepr>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PaddingRepr {
+    left: u16,
+    right: u16,
+    top: u16,
+    bottom: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+enum BorderTypeRepr {
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+    QuadrantInside,
+    QuadrantOutside,
+}
+
+/// Adapter for `Text<'static>` fields.
+pub(crate) mod text {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(text: &Text<'static>, serializer: S) -> Result<S::Ok, S::Error> {
+        TextRepr {
+            lines: text.lines.iter().map(LineRepr::from).collect(),
+            style: text.style,
+            alignment: text.alignment.map(AlignmentRepr::from),
+        }
+        .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Text<'static>, D::Error> {
+        let text = TextRepr::deserialize(deserializer)?;
+        Ok(Text {
+            lines: text.lines.into_iter().map(Line::from).collect(),
+            style: text.style,
+            alignment: text.alignment.map(Alignment::from),
+        })
+    }
+}
+
+/// Adapter for `Option<Line<'static>>` fields.
+pub(crate) mod option_line {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(line: &Option<Line<'static>>, serializer: S) -> Result<S::Ok, S::Error> {
+        line.as_ref().map(LineRepr::from).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Line<'static>>, D::Error> {
+        Ok(Option::<LineRepr>::deserialize(deserializer)?.map(Line::from))
+    }
+}
+
+/// Adapter for `Padding` fields.
+pub(crate) mod padding {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(padding: &Padding, serializer: S) -> Result<S::Ok, S::Error> {
+        PaddingRepr { left: padding.left, right: padding.right, top: padding.top, bottom: padding.bottom }
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Padding, D::Error> {
+        let padding = PaddingRepr::deserialize(deserializer)?;
+        Ok(Padding::new(padding.left, padding.right, padding.top, padding.bottom))
+    }
+}
+
+/// Adapter for `Option<BorderType>` fields.
+pub(crate) mod option_border_type {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(border: &Option<BorderType>, serializer: S) -> Result<S::Ok, S::Error> {
+        border
+            .map(|border| match border {
+                BorderType::Plain => BorderTypeRepr::Plain,
+                BorderType::Rounded => BorderTypeRepr::Rounded,
+                BorderType::Double => BorderTypeRepr::Double,
+                BorderType::Thick => BorderTypeRepr::Thick,
+                BorderType::QuadrantInside => BorderTypeRepr::QuadrantInside,
+                BorderType::QuadrantOutside => BorderTypeRepr::QuadrantOutside,
+            })
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<BorderType>, D::Error> {
+        Ok(Option::<BorderTypeRepr>::deserialize(deserializer)?.map(|border| match border {
+            BorderTypeRepr::Plain => BorderType::Plain,
+            BorderTypeRepr::Rounded => BorderType::Rounded,
+            BorderTypeRepr::Double => BorderType::Double,
+            BorderTypeRepr::Thick => BorderType::Thick,
+            BorderTypeRepr::QuadrantInside => BorderType::QuadrantInside,
+            BorderTypeRepr::QuadrantOutside => BorderType::QuadrantOutside,
+        }))
+    }
+}
+
+// FILE: src/shared_utils/serde_ratatui.rs - Serde adapters for ratatui types
+// END OF VERSION: 1.0.0
@@ -0,0 +1,55 @@
+// FILE: tests/test_cls_notification_layer.rs - Integration tests for the tracing layer
+// VERSION: 1.0.0
+// WCTX: Bridging tracing events
+// CLOG: Initial creation
+
+#![cfg(feature = "tracing")]
+
+use ratatui_notifications::{Level, Notifications};
+use tracing_subscriber::layer::SubscriberExt;
+
+#[test]
+fn test_events_queue_until_drained() {
+    let mut manager = Notifications::new();
+    let subscriber = tracing_subscriber::registry().with(manager.tracing_layer());
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::error!(path = "src/main.rs", "Build failed");
+        tracing::warn!("Disk almost full");
+        tracing::info!("Saved");
+        tracing::debug!("Ignored");
+    });
+    assert!(manager.is_empty());
+
+    assert_eq!(manager.drain_pending(), 3);
+    assert_eq!(manager.drain_pending(), 0);
+    let shown: Vec<(Option<Level>, String)> =
+        manager.iter().map(|(_, n, _)| (n.level(), n.content().to_string())).collect();
+    assert_eq!(
+        shown,
+        [
+            (Some(Level::Error), "Build failed path=src/main.rs".to_string()),
+            (Some(Level::Warn), "Disk almost full".to_string()),
+            (Some(Level::Info), "Saved".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_events_from_other_threads_arrive_on_drain() {
+    let mut manager = Notifications::new();
+    let layer = manager.tracing_layer();
+
+    std::thread::spawn(move || {
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || tracing::warn!("From a worker"));
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(manager.drain_pending(), 1);
+    assert_eq!(manager.iter().next().unwrap().1.content().to_string(), "From a worker");
+}
+
+// FILE: tests/test_cls_notification_layer.rs - Integration tests for the tracing layer
+// END OF VERSION: 1.0.0
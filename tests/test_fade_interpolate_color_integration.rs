@@ -1,7 +1,7 @@
 // FILE: tests/test_fade_interpolate_color_integration.rs - Integration tests for fade color interpolation
-// VERSION: 1.0.0
-// WCTX: TDD implementation of animation function extraction
-// CLOG: Created standalone integration test to verify fade color interpolation
+// VERSION: 1.0.1
+// WCTX: Clippy cleanup
+// CLOG: Use RangeInclusive::contains
 
 use ratatui::style::Color;
 use ratatui_notifications::notifications::functions::fnc_fade_interpolate_color::{
@@ -84,9 +84,9 @@ fn test_interpolate_color_clamping() {
     // Even with easing that might overshoot, values should stay within [100, 200]
     let result = interpolate_color(from, to, 1.0, true);
     if let Some(Color::Rgb(r, g, b)) = result {
-        assert!(r >= 100 && r <= 200);
-        assert!(g >= 100 && g <= 200);
-        assert!(b >= 100 && b <= 200);
+        assert!((100..=200).contains(&r));
+        assert!((100..=200).contains(&g));
+        assert!((100..=200).contains(&b));
     } else {
         panic!("Expected RGB color");
     }
@@ -186,4 +186,4 @@ fn test_fade_handler_interpolate_content_foreground_other_phases() {
 }
 
 // FILE: tests/test_fade_interpolate_color_integration.rs - Integration tests for fade color interpolation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.0.1
@@ -0,0 +1,44 @@
+// FILE: tests/test_fnc_accessible_text_integration.rs - Integration tests for accessible text export
+// VERSION: 1.0.0
+// WCTX: Adding accessible text export
+// CLOG: Initial creation
+
+use ratatui::prelude::*;
+use ratatui_notifications::notifications::functions::fnc_accessible_text::accessible_text;
+use ratatui_notifications::{Level, Notification};
+
+#[test]
+fn test_level_prefixes_message() {
+    let notification = Notification::new("Low memory").level(Level::Warn).build().unwrap();
+    assert_eq!(accessible_text(&notification), "Warning: Low memory");
+}
+
+#[test]
+fn test_default_level_is_info() {
+    let notification = Notification::new("Hello").build().unwrap();
+    assert_eq!(accessible_text(&notification), "Info: Hello");
+}
+
+#[test]
+fn test_title_precedes_content() {
+    let notification = Notification::new("Retrying in 5s")
+        .title("Connection lost")
+        .level(Level::Error)
+        .build()
+        .unwrap();
+    assert_eq!(accessible_text(&notification), "Error: Connection lost - Retrying in 5s");
+}
+
+#[test]
+fn test_styling_is_stripped_and_lines_joined() {
+    let content = Text::from(vec![
+        Line::from(vec![Span::styled("Build", Style::new().bold()), Span::raw(" failed")]),
+        Line::from(""),
+        Line::from(Span::styled("3 errors", Style::new().red())),
+    ]);
+    let notification = Notification::new(content).level(Level::Error).build().unwrap();
+    assert_eq!(accessible_text(&notification), "Error: Build failed 3 errors");
+}
+
+// FILE: tests/test_fnc_accessible_text_integration.rs - Integration tests for accessible text export
+// END OF VERSION: 1.0.0
@@ -0,0 +1,51 @@
+// FILE: tests/test_fnc_animated_icon_frame_integration.rs - Integration tests for animated icon frame selection
+// VERSION: 1.0.0
+// WCTX: Adding animated icons
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::functions::fnc_animated_icon_frame::animated_icon_frame;
+use std::time::Duration;
+
+fn frames() -> Vec<String> {
+    vec!["◐".to_string(), "◓".to_string(), "◑".to_string(), "◒".to_string()]
+}
+
+#[test]
+fn test_first_frame_at_start() {
+    let frames = frames();
+    let frame = animated_icon_frame(&frames, Duration::from_millis(100), Duration::ZERO);
+    assert_eq!(frame, Some("◐"));
+}
+
+#[test]
+fn test_advances_once_per_period() {
+    let frames = frames();
+    let period = Duration::from_millis(100);
+
+    assert_eq!(animated_icon_frame(&frames, period, Duration::from_millis(99)), Some("◐"));
+    assert_eq!(animated_icon_frame(&frames, period, Duration::from_millis(100)), Some("◓"));
+    assert_eq!(animated_icon_frame(&frames, period, Duration::from_millis(250)), Some("◑"));
+}
+
+#[test]
+fn test_wraps_after_full_cycle() {
+    let frames = frames();
+    let frame = animated_icon_frame(&frames, Duration::from_millis(100), Duration::from_millis(450));
+    assert_eq!(frame, Some("◐"));
+}
+
+#[test]
+fn test_empty_frames_returns_none() {
+    let frame = animated_icon_frame(&[], Duration::from_millis(100), Duration::from_secs(1));
+    assert_eq!(frame, None);
+}
+
+#[test]
+fn test_zero_period_holds_first_frame() {
+    let frames = frames();
+    let frame = animated_icon_frame(&frames, Duration::ZERO, Duration::from_secs(5));
+    assert_eq!(frame, Some("◐"));
+}
+
+// FILE: tests/test_fnc_animated_icon_frame_integration.rs - Integration tests for animated icon frame selection
+// END OF VERSION: 1.0.0
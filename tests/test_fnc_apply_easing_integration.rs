@@ -0,0 +1,50 @@
+// FILE: tests/test_fnc_apply_easing_integration.rs - Integration tests for easing curves
+// VERSION: 1.0.0
+// WCTX: Adding reflow animation
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::functions::fnc_apply_easing::apply_easing;
+use ratatui_notifications::notifications::types::Easing;
+
+const ALL: [Easing; 4] = [Easing::Linear, Easing::EaseInQuad, Easing::EaseOutCubic, Easing::EaseInOutSine];
+
+#[test]
+fn test_curves_fix_endpoints() {
+    for easing in ALL {
+        assert!(apply_easing(easing, 0.0).abs() < 1e-6, "{easing:?} at 0");
+        assert!((apply_easing(easing, 1.0) - 1.0).abs() < 1e-6, "{easing:?} at 1");
+    }
+}
+
+#[test]
+fn test_curves_are_monotonic() {
+    for easing in ALL {
+        let samples: Vec<f32> = (0..=20).map(|i| apply_easing(easing, i as f32 / 20.0)).collect();
+        assert!(samples.windows(2).all(|pair| pair[1] >= pair[0]), "{easing:?}");
+    }
+}
+
+#[test]
+fn test_curve_shapes_at_midpoint() {
+    assert_eq!(apply_easing(Easing::Linear, 0.5), 0.5);
+    assert!(apply_easing(Easing::EaseInQuad, 0.5) < 0.5);
+    assert!(apply_easing(Easing::EaseOutCubic, 0.5) > 0.5);
+    assert!((apply_easing(Easing::EaseInOutSine, 0.5) - 0.5).abs() < 1e-6);
+    assert!(apply_easing(Easing::EaseInOutSine, 0.25) < 0.25);
+}
+
+#[test]
+fn test_progress_is_clamped() {
+    for easing in ALL {
+        assert_eq!(apply_easing(easing, -1.0), apply_easing(easing, 0.0));
+        assert_eq!(apply_easing(easing, 2.0), apply_easing(easing, 1.0));
+    }
+}
+
+#[test]
+fn test_default_is_linear() {
+    assert_eq!(Easing::default(), Easing::Linear);
+}
+
+// FILE: tests/test_fnc_apply_easing_integration.rs - Integration tests for easing curves
+// END OF VERSION: 1.0.0
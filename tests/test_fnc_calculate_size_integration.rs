@@ -1,13 +1,13 @@
 // FILE: tests/test_fnc_calculate_size_integration.rs - Integration tests for fnc_calculate_size
-// VERSION: 1.1.0
-// WCTX: TDD implementation of deferred functions
-// CLOG: Rewrote tests to use NotificationBuilder pattern instead of direct struct construction
+// VERSION: 1.6.0
+// WCTX: Height-capped notifications
+// CLOG: Test the more-lines width
 
 use ratatui::prelude::*;
 use ratatui::widgets::{BorderType, Padding};
 use ratatui_notifications::notifications::NotificationBuilder;
 use ratatui_notifications::notifications::functions::fnc_calculate_size::calculate_size;
-use ratatui_notifications::notifications::types::SizeConstraint;
+use ratatui_notifications::notifications::types::{ActionId, Shape, SizeConstraint};
 
 #[test]
 fn test_empty_content_returns_minimum_size() {
@@ -190,5 +190,98 @@ fn test_wrapping_increases_height() {
     assert!(height > 5); // Should be significantly taller due to wrapping
 }
 
+#[test]
+fn test_pill_is_one_row_with_cap_cells() {
+    let notification = NotificationBuilder::new("Saved").shape(Shape::Pill).build().unwrap();
+
+    // Text, default horizontal padding (1 + 1) and one cap cell at each end
+    let (width, height) = calculate_size(&notification, Rect::new(0, 0, 100, 100));
+    assert_eq!((width, height), (5 + 2 + 2, 1));
+}
+
+#[test]
+fn test_pill_measures_first_line_only() {
+    let notification = NotificationBuilder::new("Short\nA much longer second line")
+        .shape(Shape::Pill)
+        .build()
+        .unwrap();
+
+    let (width, height) = calculate_size(&notification, Rect::new(0, 0, 100, 100));
+    assert_eq!((width, height), (5 + 2 + 2, 1));
+}
+
+#[test]
+fn test_pill_respects_max_width() {
+    let notification = NotificationBuilder::new("A pill with far more text than fits")
+        .shape(Shape::Pill)
+        .max_size(SizeConstraint::Absolute(12), SizeConstraint::Absolute(5))
+        .padding(Padding::ZERO)
+        .build()
+        .unwrap();
+
+    let (width, height) = calculate_size(&notification, Rect::new(0, 0, 100, 100));
+    assert_eq!((width, height), (12, 1));
+}
+
+#[test]
+fn test_icon_only_notification_fits_its_icon() {
+    use ratatui_notifications::notifications::types::Level;
+
+    // The debug icon is two cells wide: icon, padding (1 + 1) and borders (1 + 1)
+    let notification = NotificationBuilder::new("").level(Level::Debug).build().unwrap();
+    let (width, _) = calculate_size(&notification, Rect::new(0, 0, 100, 100));
+    assert_eq!(width, 2 + 2 + 2);
+
+    // With a title the icon sits in the title, so the body stays minimal
+    let titled = NotificationBuilder::new("").level(Level::Debug).title("T").build().unwrap();
+    assert_eq!(calculate_size(&titled, Rect::new(0, 0, 100, 100)).0, 5);
+}
+
+#[test]
+fn test_wrapped_height_fits_the_lines_exactly() {
+    // 12 wide leaves 8 cells inside the border and padding: four rows of text
+    let notification = NotificationBuilder::new("wrap this long message")
+        .max_width(12)
+        .build()
+        .unwrap();
+    assert_eq!(calculate_size(&notification, Rect::new(0, 0, 100, 100)), (12, 4 + 2));
+
+    // One line needs no spare rows
+    let short = NotificationBuilder::new("short").build().unwrap();
+    assert_eq!(calculate_size(&short, Rect::new(0, 0, 100, 100)).1, 3);
+}
+
+#[test]
+fn test_progress_gauge_gets_its_own_row() {
+    let notification = NotificationBuilder::new("Uploading").progress(0.5).build().unwrap();
+    assert_eq!(calculate_size(&notification, Rect::new(0, 0, 100, 100)).1, 4);
+}
+
+#[test]
+fn test_actions_get_their_own_row_and_fit_the_width() {
+    let notification = NotificationBuilder::new("Hi")
+        .action("Retry", ActionId(1))
+        .action("Dismiss", ActionId(2))
+        .build()
+        .unwrap();
+
+    // "[Retry] [Dismiss]" is 17 cells, plus border and padding
+    assert_eq!(calculate_size(&notification, Rect::new(0, 0, 100, 100)), (21, 4));
+
+    let both = NotificationBuilder::new("Uploading").progress(0.5).action("Cancel", ActionId(1)).build().unwrap();
+    assert_eq!(calculate_size(&both, Rect::new(0, 0, 100, 100)).1, 5);
+}
+
+#[test]
+fn test_height_cap_widens_for_more_lines_row() {
+    // Ten 2-cell lines in a 6-row box: 3 lines, then "▼ 7 more lines" (14 cells)
+    let notification = NotificationBuilder::new("a1\na2\na3\na4\na5\na6\na7\na8\na9\na0")
+        .max_height(6)
+        .build()
+        .unwrap();
+
+    assert_eq!(calculate_size(&notification, Rect::new(0, 0, 100, 100)), (14 + 4, 6));
+}
+
 // FILE: tests/test_fnc_calculate_size_integration.rs - Integration tests for fnc_calculate_size
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.6.0
@@ -0,0 +1,37 @@
+// FILE: tests/test_fnc_clip_rows_integration.rs - Integration tests for row clipping
+// VERSION: 1.0.0
+// WCTX: Height-capped notifications
+// CLOG: Initial creation
+
+use ratatui::prelude::*;
+use ratatui_notifications::notifications::functions::fnc_clip_rows::clip_rows;
+
+fn numbered(count: usize) -> Text<'static> {
+    Text::from((1..=count).map(|n| Line::from(format!("line {n}"))).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_text_that_fits_is_unchanged() {
+    assert_eq!(clip_rows(numbered(4), 4, Style::default()), numbered(4));
+    assert_eq!(clip_rows(numbered(2), 5, Style::default()), numbered(2));
+}
+
+#[test]
+fn test_overflow_keeps_whole_lines_and_counts_the_rest() {
+    let style = Style::new().fg(Color::Yellow);
+
+    let clipped = clip_rows(numbered(10), 4, style);
+
+    let rows: Vec<String> = clipped.lines.iter().map(ToString::to_string).collect();
+    assert_eq!(rows, vec!["line 1", "line 2", "line 3", "▼ 7 more lines"]);
+    assert_eq!(clipped.lines[3].style, style);
+}
+
+#[test]
+fn test_single_row_leaves_clipping_to_the_caller() {
+    assert_eq!(clip_rows(numbered(3), 1, Style::default()), numbered(3));
+    assert_eq!(clip_rows(numbered(3), 0, Style::default()), numbered(3));
+}
+
+// FILE: tests/test_fnc_clip_rows_integration.rs - Integration tests for row clipping
+// END OF VERSION: 1.0.0
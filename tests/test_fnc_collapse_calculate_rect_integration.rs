@@ -0,0 +1,54 @@
+// FILE: tests/test_fnc_collapse_calculate_rect_integration.rs - Integration tests for collapse rect calculation
+// VERSION: 1.0.0
+// WCTX: Adding horizontal collapse animation
+// CLOG: Initial creation
+
+use ratatui::prelude::*;
+use ratatui_notifications::notifications::functions::fnc_collapse_calculate_rect::calculate_rect;
+use ratatui_notifications::notifications::types::{Anchor, AnimationPhase};
+
+#[test]
+fn test_collapse_width_shrinks_monotonically_to_zero() {
+    let full_rect = Rect::new(60, 20, 20, 4);
+
+    let widths: Vec<u16> = (0..=10)
+        .map(|step| {
+            calculate_rect(full_rect, Anchor::BottomRight, AnimationPhase::Collapsing, step as f32 / 10.0).width
+        })
+        .collect();
+
+    assert_eq!(widths.first(), Some(&20));
+    assert_eq!(widths.last(), Some(&0));
+    assert!(widths.windows(2).all(|pair| pair[1] <= pair[0]));
+}
+
+#[test]
+fn test_collapse_keeps_height_and_anchored_edge() {
+    let full_rect = Rect::new(60, 20, 20, 4);
+
+    let right = calculate_rect(full_rect, Anchor::BottomRight, AnimationPhase::Collapsing, 0.25);
+    let left = calculate_rect(full_rect, Anchor::TopLeft, AnimationPhase::Collapsing, 0.25);
+    let center = calculate_rect(full_rect, Anchor::TopCenter, AnimationPhase::Collapsing, 0.5);
+
+    assert_eq!(right, Rect::new(65, 20, 15, 4));
+    assert_eq!(left, Rect::new(60, 20, 15, 4));
+    assert_eq!(center, Rect::new(65, 20, 10, 4));
+}
+
+#[test]
+fn test_collapse_expanding_grows_from_zero() {
+    let full_rect = Rect::new(0, 0, 20, 4);
+
+    assert_eq!(calculate_rect(full_rect, Anchor::TopLeft, AnimationPhase::Expanding, 0.0), Rect::default());
+    assert_eq!(calculate_rect(full_rect, Anchor::TopLeft, AnimationPhase::Expanding, 1.0), full_rect);
+}
+
+#[test]
+fn test_collapse_other_phases_return_full_rect() {
+    let full_rect = Rect::new(5, 5, 20, 4);
+
+    assert_eq!(calculate_rect(full_rect, Anchor::TopLeft, AnimationPhase::Dwelling, 0.5), full_rect);
+}
+
+// FILE: tests/test_fnc_collapse_calculate_rect_integration.rs - Integration tests for collapse rect calculation
+// END OF VERSION: 1.0.0
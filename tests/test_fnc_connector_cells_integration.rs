@@ -0,0 +1,91 @@
+// FILE: tests/test_fnc_connector_cells_integration.rs - Integration tests for connector layout
+// VERSION: 1.0.0
+// WCTX: Adding notification pointers
+// CLOG: Initial creation
+
+use ratatui::layout::{Position, Rect};
+use ratatui_notifications::notifications::functions::fnc_connector_cells::connector_cells;
+
+fn rect() -> Rect {
+    Rect::new(10, 5, 6, 3)
+}
+
+#[test]
+fn test_target_inside_box_has_no_connector() {
+    assert!(connector_cells(rect(), Position::new(12, 6)).is_empty());
+}
+
+#[test]
+fn test_empty_rect_has_no_connector() {
+    assert!(connector_cells(Rect::new(10, 5, 0, 0), Position::new(0, 0)).is_empty());
+}
+
+#[test]
+fn test_adjacent_target_has_no_connector() {
+    assert!(connector_cells(rect(), Position::new(16, 6)).is_empty());
+    assert!(connector_cells(rect(), Position::new(12, 4)).is_empty());
+}
+
+#[test]
+fn test_target_to_the_left_runs_horizontally() {
+    let cells = connector_cells(rect(), Position::new(6, 6));
+    assert_eq!(
+        cells,
+        vec![(Position::new(9, 6), "─"), (Position::new(8, 6), "─"), (Position::new(7, 6), "─")]
+    );
+}
+
+#[test]
+fn test_target_below_runs_vertically() {
+    let cells = connector_cells(rect(), Position::new(12, 10));
+    assert_eq!(cells, vec![(Position::new(12, 8), "│"), (Position::new(12, 9), "│")]);
+}
+
+#[test]
+fn test_target_above_runs_vertically() {
+    let cells = connector_cells(rect(), Position::new(11, 1));
+    assert_eq!(
+        cells,
+        vec![(Position::new(11, 4), "│"), (Position::new(11, 3), "│"), (Position::new(11, 2), "│")]
+    );
+}
+
+#[test]
+fn test_diagonal_target_bends_toward_it() {
+    // Below-right: down from the right column, bend, then across
+    let cells = connector_cells(rect(), Position::new(19, 9));
+    assert_eq!(
+        cells,
+        vec![
+            (Position::new(15, 8), "│"),
+            (Position::new(15, 9), "╰"),
+            (Position::new(16, 9), "─"),
+            (Position::new(17, 9), "─"),
+            (Position::new(18, 9), "─"),
+        ]
+    );
+}
+
+#[test]
+fn test_diagonal_bend_glyphs() {
+    let bend = |target: Position| {
+        connector_cells(rect(), target)
+            .into_iter()
+            .find(|&(position, _)| position.y == target.y && position.x != target.x)
+            .map(|(_, glyph)| glyph)
+    };
+
+    assert_eq!(bend(Position::new(3, 9)), Some("╯"));
+    assert_eq!(bend(Position::new(20, 1)), Some("╭"));
+    assert_eq!(bend(Position::new(3, 1)), Some("╮"));
+}
+
+#[test]
+fn test_connector_never_covers_target() {
+    for target in [Position::new(2, 2), Position::new(30, 6), Position::new(12, 20), Position::new(25, 0)] {
+        assert!(connector_cells(rect(), target).iter().all(|&(position, _)| position != target));
+    }
+}
+
+// FILE: tests/test_fnc_connector_cells_integration.rs - Integration tests for connector layout
+// END OF VERSION: 1.0.0
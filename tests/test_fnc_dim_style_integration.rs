@@ -0,0 +1,43 @@
+// FILE: tests/test_fnc_dim_style_integration.rs - Integration tests for style dimming
+// VERSION: 1.0.0
+// WCTX: Adding depth fade
+// CLOG: Initial creation
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui_notifications::notifications::functions::fnc_dim_style::dim_style;
+
+#[test]
+fn test_zero_amount_leaves_style_unchanged() {
+    let style = Style::default().fg(Color::Red);
+    assert_eq!(dim_style(style, 0.0), style);
+}
+
+#[test]
+fn test_full_amount_reaches_background() {
+    let style = Style::default().fg(Color::White).bg(Color::Rgb(10, 20, 30));
+    assert_eq!(dim_style(style, 1.0).fg, Some(Color::Rgb(10, 20, 30)));
+}
+
+#[test]
+fn test_amount_is_clamped_above_one() {
+    let style = Style::default().fg(Color::White);
+    assert_eq!(dim_style(style, 3.0).fg, Some(Color::Rgb(0, 0, 0)));
+}
+
+#[test]
+fn test_missing_foreground_treated_as_white() {
+    assert_eq!(dim_style(Style::default(), 0.5).fg, Some(Color::Rgb(128, 128, 128)));
+}
+
+#[test]
+fn test_other_attributes_preserved() {
+    let style = Style::default().fg(Color::Blue).bg(Color::Black).add_modifier(Modifier::BOLD);
+    let dimmed = dim_style(style, 0.25);
+
+    assert_eq!(dimmed.bg, Some(Color::Black));
+    assert!(dimmed.add_modifier.contains(Modifier::BOLD));
+    assert_eq!(dimmed.fg, Some(Color::Rgb(0, 0, 191)));
+}
+
+// FILE: tests/test_fnc_dim_style_integration.rs - Integration tests for style dimming
+// END OF VERSION: 1.0.0
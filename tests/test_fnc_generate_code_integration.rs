@@ -1,15 +1,16 @@
 // FILE: tests/test_fnc_generate_code_integration.rs - Integration tests for code generation function
-// VERSION: 1.0.0
-// WCTX: Adding "show code" feature to demo
-// CLOG: Initial creation
+// VERSION: 1.14.0
+// WCTX: Keyboard shortcuts for actions
+// CLOG: Test action_keys output
 
 use std::time::Duration;
 
+use crossterm::event::KeyCode;
 use ratatui::prelude::*;
 use ratatui::widgets::{BorderType, Padding};
 
 use ratatui_notifications::{
-    generate_code, Anchor, Animation, AutoDismiss, Level, Notification, SlideDirection,
+    generate_code, ActionId, Anchor, Animation, AutoDismiss, Level, Notification, SlideDirection,
     SizeConstraint, Timing,
 };
 
@@ -79,6 +80,35 @@ fn test_title_appears_in_code() {
     assert!(code.contains("My Title"));
 }
 
+#[test]
+fn test_headline_appears_in_code() {
+    let notification = Notification::new("3 errors").headline("Build \"main\" failed").build().unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(r#".headline("Build \"main\" failed")"#));
+    assert!(!generate_code(&Notification::new("3 errors").build().unwrap()).contains(".headline("));
+}
+
+#[test]
+fn test_borderless_appears_in_code() {
+    let notification = Notification::new("Bare").borderless().build().unwrap();
+    assert!(generate_code(&notification).contains(".borderless()"));
+    assert!(!generate_code(&Notification::new("Boxed").build().unwrap()).contains("border"));
+}
+
+#[test]
+fn test_priority_appears_in_code() {
+    let notification = Notification::new("Disk failing").priority(7).build().unwrap();
+    assert!(generate_code(&notification).contains(".priority(7)"));
+    assert!(!generate_code(&Notification::new("Saved").build().unwrap()).contains(".priority("));
+}
+
+#[test]
+fn test_duration_appears_in_code() {
+    let notification = Notification::new("Critical").duration(Duration::from_secs(10)).build().unwrap();
+    assert!(generate_code(&notification).contains(".duration(Duration::from_secs(10))"));
+}
+
 #[test]
 fn test_auto_dismiss_never_appears_in_code() {
     let notification = Notification::new("Test")
@@ -302,5 +332,101 @@ fn test_code_is_syntactically_structured() {
     assert!(code.contains("\n    ."));
 }
 
+#[test]
+fn test_protected_appears_in_code() {
+    let notification = Notification::new("Test").protected(true).build().unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".protected(true)"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".protected("));
+}
+
+#[test]
+fn test_explicit_default_anchor_appears_in_code() {
+    // An explicit BottomRight must survive so it still overrides a manager default_anchor
+    let notification = Notification::new("Test").anchor(Anchor::BottomRight).build().unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".anchor(Anchor::BottomRight)"));
+}
+
+#[test]
+fn test_new_badge_appears_in_code() {
+    let notification = Notification::new("Test").new_badge(true).build().unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".new_badge(true)"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".new_badge("));
+}
+
+#[test]
+fn test_kind_appears_in_code() {
+    let notification = Notification::new("Test").kind("sav\"ing").build().unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".kind(\"sav\\\"ing\")"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".kind("));
+}
+
+#[test]
+fn test_easing_appears_in_code() {
+    use ratatui_notifications::Easing;
+
+    let notification = Notification::new("Test").easing(Easing::EaseInOutSine).build().unwrap();
+
+    assert!(generate_code(&notification).contains(".easing(Easing::EaseInOutSine)"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".easing("));
+}
+
+#[test]
+fn test_shape_appears_in_code() {
+    use ratatui_notifications::notifications::Shape;
+
+    let notification = Notification::new("Test").shape(Shape::Pill).build().unwrap();
+
+    assert!(generate_code(&notification).contains(".shape(Shape::Pill)"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".shape("));
+}
+
+#[test]
+fn test_progress_appears_in_code() {
+    let notification = Notification::new("Test").progress(0.5).build().unwrap();
+
+    assert!(generate_code(&notification).contains(".progress(0.5)"));
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".progress("));
+}
+
+#[test]
+fn test_sticky_and_progress_linger_appear_in_code() {
+    let notification = Notification::new("Test")
+        .sticky(true)
+        .progress_linger(Duration::from_millis(800))
+        .build()
+        .unwrap();
+
+    let code = generate_code(&notification);
+    assert!(code.contains(".sticky(true)"));
+    assert!(code.contains(".progress_linger(Duration::from_millis(800))"));
+    let plain = generate_code(&Notification::new("Test").build().unwrap());
+    assert!(!plain.contains(".sticky(") && !plain.contains(".progress_linger("));
+}
+
+#[test]
+fn test_actions_appear_in_code_in_order() {
+    let notification = Notification::new("Test")
+        .action("Retry \"now\"", ActionId(1))
+        .action("Dismiss", ActionId(2))
+        .action_keys(vec![("Dismiss".to_string(), KeyCode::Esc)])
+        .build()
+        .unwrap();
+
+    let code = generate_code(&notification);
+    assert!(code.contains(".action_keys(vec![(\"Dismiss\".to_string(), KeyCode::Esc)])"));
+    let retry = code.find(".action(\"Retry \\\"now\\\"\", ActionId(1))").unwrap();
+    let dismiss = code.find(".action(\"Dismiss\", ActionId(2))").unwrap();
+    assert!(retry < dismiss);
+    assert!(!generate_code(&Notification::new("Test").build().unwrap()).contains(".action("));
+}
+
 // FILE: tests/test_fnc_generate_code_integration.rs - Integration tests for code generation function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.14.0
@@ -1,7 +1,7 @@
 // FILE: tests/test_fnc_get_level_icon_integration.rs - Integration tests for level icon lookup function
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Success level and level themes
+// CLOG: Add Success icon test
 
 use ratatui_notifications::notifications::functions::fnc_get_level_icon::get_level_icon;
 use ratatui_notifications::notifications::types::Level;
@@ -36,6 +36,12 @@ fn test_level_trace_returns_trace_icon() {
     assert_eq!(icon, Some(" ⊙"));
 }
 
+#[test]
+fn test_level_success_returns_check_icon() {
+    let icon = get_level_icon(Some(Level::Success));
+    assert_eq!(icon, Some(" ✔"));
+}
+
 #[test]
 fn test_none_returns_none() {
     let icon = get_level_icon(None);
@@ -43,4 +49,4 @@ fn test_none_returns_none() {
 }
 
 // FILE: tests/test_fnc_get_level_icon_integration.rs - Integration tests for level icon lookup function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
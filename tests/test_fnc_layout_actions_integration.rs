@@ -0,0 +1,40 @@
+// FILE: tests/test_fnc_layout_actions_integration.rs - Integration tests for action button layout
+// VERSION: 1.0.0
+// WCTX: Notification action buttons
+// CLOG: Initial creation
+
+use ratatui::layout::Rect;
+use ratatui_notifications::notifications::functions::fnc_layout_actions::layout_actions;
+use ratatui_notifications::notifications::ActionId;
+
+#[test]
+fn test_buttons_follow_row_origin_one_cell_apart() {
+    let actions = [("OK".to_string(), ActionId(7)), ("No".to_string(), ActionId(8))];
+
+    let buttons = layout_actions(&actions, Rect::new(5, 3, 30, 1));
+
+    assert_eq!(buttons, vec![(Rect::new(5, 3, 4, 1), ActionId(7)), (Rect::new(10, 3, 4, 1), ActionId(8))]);
+}
+
+#[test]
+fn test_buttons_that_do_not_fit_are_dropped() {
+    let actions = [
+        ("Retry".to_string(), ActionId(1)),
+        ("Dismiss".to_string(), ActionId(2)),
+        ("X".to_string(), ActionId(3)),
+    ];
+
+    // "[Retry]" fits in 10 cells, "[Dismiss]" does not, and nothing after it is placed
+    assert_eq!(layout_actions(&actions, Rect::new(0, 0, 10, 1)), vec![(Rect::new(0, 0, 7, 1), ActionId(1))]);
+    assert!(layout_actions(&actions, Rect::new(0, 0, 6, 1)).is_empty());
+}
+
+#[test]
+fn test_labels_measured_in_display_cells() {
+    let actions = [("再試行".to_string(), ActionId(1))];
+
+    assert_eq!(layout_actions(&actions, Rect::new(0, 0, 8, 1)), vec![(Rect::new(0, 0, 8, 1), ActionId(1))]);
+}
+
+// FILE: tests/test_fnc_layout_actions_integration.rs - Integration tests for action button layout
+// END OF VERSION: 1.0.0
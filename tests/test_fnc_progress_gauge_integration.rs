@@ -0,0 +1,39 @@
+// FILE: tests/test_fnc_progress_gauge_integration.rs - Integration tests for progress gauge text
+// VERSION: 1.0.0
+// WCTX: Adding progress gauges
+// CLOG: Initial creation
+
+use ratatui::text::Span;
+use ratatui_notifications::notifications::functions::fnc_progress_gauge::progress_gauge;
+
+#[test]
+fn test_gauge_fills_width_exactly() {
+    for width in 0..30 {
+        for step in 0..=10 {
+            let gauge = progress_gauge(step as f32 / 10.0, width);
+            assert_eq!(Span::raw(gauge).width(), usize::from(width));
+        }
+    }
+}
+
+#[test]
+fn test_gauge_fill_tracks_progress() {
+    assert_eq!(progress_gauge(0.0, 15), "░░░░░░░░░░░░ 0%");
+    assert_eq!(progress_gauge(0.2, 14), "██░░░░░░░░ 20%");
+    assert_eq!(progress_gauge(1.0, 15), "██████████ 100%");
+}
+
+#[test]
+fn test_gauge_clamps_out_of_range_progress() {
+    assert_eq!(progress_gauge(-1.0, 10), progress_gauge(0.0, 10));
+    assert_eq!(progress_gauge(3.0, 10), progress_gauge(1.0, 10));
+}
+
+#[test]
+fn test_gauge_drops_label_when_narrow() {
+    assert_eq!(progress_gauge(0.4, 5), "██░░░");
+    assert_eq!(progress_gauge(1.0, 1), "█");
+}
+
+// FILE: tests/test_fnc_progress_gauge_integration.rs - Integration tests for progress gauge text
+// END OF VERSION: 1.0.0
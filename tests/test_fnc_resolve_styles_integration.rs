@@ -1,7 +1,7 @@
 // FILE: tests/test_fnc_resolve_styles_integration.rs - Integration tests for style resolution function
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Success level and level themes
+// CLOG: Add Success colour test
 
 use ratatui::style::{Color, Style};
 use ratatui_notifications::notifications::functions::fnc_resolve_styles::resolve_styles;
@@ -77,6 +77,16 @@ fn test_level_trace_returns_magenta_border() {
     assert_eq!(title_style, Style::new().fg(Color::Magenta));
 }
 
+#[test]
+fn test_level_success_returns_light_green_border() {
+    let (block_style, border_style, title_style) =
+        resolve_styles(Some(Level::Success), None, None, None);
+
+    assert_eq!(block_style, Style::new());
+    assert_eq!(border_style, Style::new().fg(Color::LightGreen));
+    assert_eq!(title_style, Style::new().fg(Color::LightGreen));
+}
+
 #[test]
 fn test_custom_block_style_overrides_default() {
     let custom_block = Style::new().bg(Color::Cyan);
@@ -145,4 +155,4 @@ fn test_all_custom_styles_provided() {
 }
 
 // FILE: tests/test_fnc_resolve_styles_integration.rs - Integration tests for style resolution function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
@@ -0,0 +1,51 @@
+// FILE: tests/test_fnc_slide_calculate_rect_integration.rs - Integration tests for slide rect calculation
+// VERSION: 1.0.0
+// WCTX: Rendering within a region
+// CLOG: Initial creation
+
+use ratatui::prelude::*;
+use ratatui_notifications::notifications::functions::fnc_slide_calculate_rect::slide_calculate_rect;
+use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, SlideDirection};
+
+#[test]
+fn test_offset_area_keeps_resting_rect_whole() {
+    // A pane on the right half of the screen, notification resting inside it
+    let full_rect = Rect::new(60, 20, 10, 4);
+    let area = Rect::new(40, 10, 40, 14);
+
+    let rect = slide_calculate_rect(
+        full_rect,
+        area,
+        1.0,
+        AnimationPhase::SlidingIn,
+        Anchor::BottomRight,
+        SlideDirection::FromRight,
+        None,
+        None,
+    );
+
+    assert_eq!(rect, full_rect);
+}
+
+#[test]
+fn test_offset_area_clips_to_its_right_edge() {
+    let full_rect = Rect::new(60, 20, 10, 4);
+    let area = Rect::new(40, 10, 40, 14);
+
+    let rect = slide_calculate_rect(
+        full_rect,
+        area,
+        0.5,
+        AnimationPhase::SlidingIn,
+        Anchor::BottomRight,
+        SlideDirection::FromRight,
+        None,
+        None,
+    );
+
+    assert!(rect.width > 0 && rect.width < full_rect.width);
+    assert_eq!(rect.right(), area.right());
+}
+
+// FILE: tests/test_fnc_slide_calculate_rect_integration.rs - Integration tests for slide rect calculation
+// END OF VERSION: 1.0.0
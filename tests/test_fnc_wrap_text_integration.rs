@@ -0,0 +1,73 @@
+// FILE: tests/test_fnc_wrap_text_integration.rs - Integration tests for grapheme-aware word wrapping
+// VERSION: 1.1.0
+// WCTX: Display-width text layout
+// CLOG: Add flag emoji test
+
+use ratatui::prelude::*;
+use ratatui_notifications::notifications::functions::fnc_wrap_text::wrap_text;
+
+fn rows(text: &Text<'static>) -> Vec<String> {
+    text.lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_short_text_is_unchanged() {
+    let wrapped = wrap_text(&Text::from("Saved"), 20);
+    assert_eq!(rows(&wrapped), ["Saved"]);
+}
+
+#[test]
+fn test_wraps_on_whitespace_and_drops_it() {
+    let wrapped = wrap_text(&Text::from("the quick brown fox"), 10);
+    assert_eq!(rows(&wrapped), ["the quick", "brown fox"]);
+}
+
+#[test]
+fn test_breaks_words_longer_than_the_width() {
+    let wrapped = wrap_text(&Text::from("abcdefghij kl"), 4);
+    assert_eq!(rows(&wrapped), ["abcd", "efgh", "ij", "kl"]);
+}
+
+#[test]
+fn test_never_splits_graphemes() {
+    // Combining accents stay on their base letter; wide characters count as two cells
+    let wrapped = wrap_text(&Text::from("cafe\u{301} 日本語テキスト"), 6);
+    assert_eq!(rows(&wrapped), ["cafe\u{301}", "日本語", "テキス", "ト"]);
+    for line in &wrapped.lines {
+        assert!(line.width() <= 6);
+    }
+}
+
+#[test]
+fn test_flag_emoji_is_one_two_cell_grapheme() {
+    // The flag is two code points but one cluster: it moves whole to the next row
+    let wrapped = wrap_text(&Text::from("ok 日本語🇯🇵"), 7);
+    assert_eq!(rows(&wrapped), ["ok", "日本語", "🇯🇵"]);
+
+    let wrapped = wrap_text(&Text::from("日本語🇯🇵"), 8);
+    assert_eq!(rows(&wrapped), ["日本語🇯🇵"]);
+    assert_eq!(wrapped.lines[0].width(), 8);
+}
+
+#[test]
+fn test_keeps_span_styles_and_blank_lines() {
+    let text = Text::from(vec![
+        Line::from(vec![Span::raw("plain "), Span::styled("bold words", Style::new().bold())]),
+        Line::default(),
+        Line::from("end").alignment(Alignment::Right),
+    ]);
+    let wrapped = wrap_text(&text, 8);
+
+    assert_eq!(rows(&wrapped), ["plain", "bold", "words", "", "end"]);
+    assert_eq!(wrapped.lines[1].spans[0].style, Style::new().bold());
+    assert_eq!(wrapped.lines[4].alignment, Some(Alignment::Right));
+}
+
+#[test]
+fn test_zero_width_returns_text_as_is() {
+    let text = Text::from("anything at all");
+    assert_eq!(wrap_text(&text, 0), text);
+}
+
+// FILE: tests/test_fnc_wrap_text_integration.rs - Integration tests for grapheme-aware word wrapping
+// END OF VERSION: 1.1.0
@@ -0,0 +1,105 @@
+// FILE: tests/test_notifications_ext.rs - Integration tests for NotificationsExt shortcuts
+// VERSION: 1.2.0
+// WCTX: Level-named shortcut
+// CLOG: Test stored level and anchor per shortcut
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::{Anchor, Level, Notification, Notifications, NotificationsExt, Overflow};
+use std::time::Duration;
+
+/// Ticks past the entry animation and renders into an 80x24 buffer.
+fn settle_and_render(manager: &mut Notifications) -> Buffer {
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn test_warning_matches_builder_path() {
+    let mut shortcut = Notifications::new();
+    shortcut.warning("x").unwrap();
+
+    let mut built = Notifications::new();
+    built
+        .add(Notification::new("x").level(Level::Warn).build().unwrap())
+        .unwrap();
+
+    assert_eq!(settle_and_render(&mut shortcut), settle_and_render(&mut built));
+}
+
+#[test]
+fn test_success_matches_builder_path() {
+    let mut shortcut = Notifications::new();
+    shortcut.success("x").unwrap();
+
+    let mut built = Notifications::new();
+    built
+        .add(Notification::new("x").level(Level::Success).build().unwrap())
+        .unwrap();
+
+    assert_eq!(settle_and_render(&mut shortcut), settle_and_render(&mut built));
+}
+
+#[test]
+fn test_shortcuts_return_sequential_ids() {
+    let mut manager = Notifications::new();
+
+    assert_eq!(manager.info("a").unwrap(), 0);
+    assert_eq!(manager.error("b").unwrap(), 1);
+    assert_eq!(manager.debug("c").unwrap(), 2);
+    assert_eq!(manager.trace("d").unwrap(), 3);
+}
+
+#[test]
+fn test_shortcuts_respect_overflow_limits() {
+    let mut manager = Notifications::new()
+        .max_concurrent(Some(1))
+        .overflow(Overflow::DiscardOldest);
+
+    let first = manager.info("first").unwrap();
+    let second = manager.info("second").unwrap();
+
+    assert!(!manager.remove(first));
+    assert!(manager.remove(second));
+}
+
+#[test]
+fn test_shortcut_rejects_oversized_message() {
+    let mut manager = Notifications::new();
+
+    assert!(manager.error("a".repeat(1001)).is_err());
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_shortcuts_store_matching_level_at_default_anchor() {
+    let mut manager = Notifications::new().default_anchor(Anchor::TopCenter);
+
+    manager.info("i").unwrap();
+    manager.warn("w").unwrap();
+    manager.error("e").unwrap();
+    manager.success("s").unwrap();
+
+    let stored: Vec<(Option<Level>, Anchor)> = manager
+        .iter()
+        .map(|(_, notification, _)| (notification.level(), notification.anchor()))
+        .collect();
+    assert_eq!(
+        stored,
+        vec![
+            (Some(Level::Info), Anchor::TopCenter),
+            (Some(Level::Warn), Anchor::TopCenter),
+            (Some(Level::Error), Anchor::TopCenter),
+            (Some(Level::Success), Anchor::TopCenter),
+        ]
+    );
+}
+
+// FILE: tests/test_notifications_ext.rs - Integration tests for NotificationsExt shortcuts
+// END OF VERSION: 1.2.0
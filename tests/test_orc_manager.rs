@@ -1,7 +1,7 @@
 // FILE: tests/notifications/test_orc_manager.rs - Tests for Notifications manager orchestrator
-// VERSION: 1.0.0
-// WCTX: Implementing Notifications manager orchestrator using TDD
-// CLOG: Initial creation with comprehensive test coverage
+// VERSION: 1.85.0
+// WCTX: Dismiss callbacks run once
+// CLOG: Test update, snapshot and coalesce callbacks
 
 #[cfg(test)]
 mod tests {
@@ -254,6 +254,61 @@ mod tests {
         assert!(manager.remove(id_br2));
     }
 
+    #[test]
+    fn test_add_with_eviction_reports_removed_id() {
+        use ratatui_notifications::notifications::{AddOutcome, Notifications};
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).overflow(Overflow::DiscardOldest);
+        let first = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let second = manager.add_with_eviction(create_test_notification(Anchor::TopLeft)).unwrap();
+        assert_eq!(second.evicted, None);
+
+        // Full: the oldest makes room; other anchors are still under the limit
+        let third = manager.add_with_eviction(create_test_notification(Anchor::TopLeft)).unwrap();
+        assert_eq!(third, AddOutcome { id: second.id + 1, evicted: Some(first) });
+        let elsewhere = manager.add_with_eviction(create_test_notification(Anchor::BottomLeft)).unwrap();
+        assert_eq!(elsewhere.evicted, None);
+        assert_eq!(manager.len(), 3);
+    }
+
+    fn prioritized(priority: u8) -> Notification {
+        NotificationBuilder::new(format!("Priority {priority}")).anchor(Anchor::TopLeft).priority(priority).build().unwrap()
+    }
+
+    #[test]
+    fn test_overflow_evicts_lowest_priority_first() {
+        use ratatui_notifications::notifications::Notifications;
+
+        for (overflow, evicted) in [(Overflow::DiscardOldest, 1), (Overflow::DiscardNewest, 2)] {
+            let mut manager = Notifications::new().max_concurrent(Some(3)).overflow(overflow);
+            let ids = [5, 0, 0].map(|priority| manager.add(prioritized(priority)).unwrap());
+            let outcome = manager.add_with_eviction(prioritized(0)).unwrap();
+
+            // The older error survives even under DiscardOldest
+            assert_eq!(outcome.evicted, Some(ids[evicted]));
+            assert!(manager.iter().any(|(id, ..)| id == ids[0]));
+        }
+    }
+
+    #[test]
+    fn test_add_rejects_notification_outranked_by_a_full_anchor() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let mut manager = Notifications::new().max_concurrent(Some(2));
+        manager.add(prioritized(5)).unwrap();
+        manager.add(prioritized(3)).unwrap();
+
+        assert_eq!(manager.add(prioritized(1)), Err(NotificationError::Outranked));
+        assert_eq!(manager.len(), 2);
+
+        // Equal priority falls back to the overflow rule; a queue always waits
+        let outcome = manager.add_with_eviction(prioritized(3)).unwrap();
+        assert!(outcome.evicted.is_some());
+        let mut queued = Notifications::new().max_concurrent(Some(1)).overflow(Overflow::Queue);
+        queued.add(prioritized(5)).unwrap();
+        assert!(queued.add(prioritized(0)).is_ok());
+    }
+
     #[test]
     fn test_builder_pattern_is_fluent() {
         use ratatui_notifications::notifications::Notifications;
@@ -306,7 +361,4113 @@ mod tests {
             manager.render(frame, frame.area());
         }).unwrap();
     }
+
+    // Helper to render the manager into a test buffer and return its text rows
+    fn render_to_lines(manager: &mut ratatui_notifications::notifications::Notifications) -> Vec<String> {
+        render_lines_at(manager, 80, 24)
+    }
+
+    fn render_lines_at(
+        manager: &mut ratatui_notifications::notifications::Notifications,
+        width: u16,
+        height: u16,
+    ) -> Vec<String> {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let backend = TestBackend::new(width, height);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|frame| {
+            manager.render(frame, frame.area());
+        }).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_max_visible_draws_only_limit_and_keeps_rest_waiting() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_visible(Anchor::BottomRight, 2);
+
+        for i in 0..5 {
+            let notif = NotificationBuilder::new(format!("Queued {}", i))
+                .anchor(Anchor::BottomRight)
+                .build()
+                .unwrap();
+            manager.add(notif).unwrap();
+        }
+
+        // Advance past the entry animation
+        manager.tick(Duration::from_millis(600));
+
+        let lines = render_to_lines(&mut manager);
+        let drawn: Vec<usize> = (0..5)
+            .filter(|i| lines.iter().any(|l| l.contains(&format!("Queued {}", i))))
+            .collect();
+
+        // Only the two oldest are drawn; the rest wait without being discarded
+        assert_eq!(drawn, vec![0, 1]);
+        for id in 0..5 {
+            assert!(manager.remove(id));
+        }
+    }
+
+    #[test]
+    fn test_max_visible_promotes_waiting_notifications_as_slots_free() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_visible(Anchor::BottomRight, 2);
+
+        for i in 0..3 {
+            let notif = NotificationBuilder::new(format!("Queued {}", i))
+                .anchor(Anchor::BottomRight)
+                .build()
+                .unwrap();
+            manager.add(notif).unwrap();
+        }
+        manager.tick(Duration::from_millis(600));
+
+        // Free one slot; the waiting notification starts on the next tick
+        assert!(manager.remove(0));
+        manager.tick(Duration::from_millis(600));
+
+        let lines = render_to_lines(&mut manager);
+        assert!(!lines.iter().any(|l| l.contains("Queued 0")));
+        assert!(lines.iter().any(|l| l.contains("Queued 1")));
+        assert!(lines.iter().any(|l| l.contains("Queued 2")));
+    }
+
+    #[test]
+    fn test_promotion_animation_used_for_promoted_entry() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        // Early and settled positions of "Waiting" once promoted mid-slide
+        let promote = |manager: Notifications| {
+            let mut manager = manager.max_visible(Anchor::BottomRight, 1);
+            let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            manager
+                .add(NotificationBuilder::new("Waiting").anchor(Anchor::BottomRight).build().unwrap())
+                .unwrap();
+            manager.tick(Duration::from_millis(600));
+            manager.remove(first);
+            manager.tick(Duration::from_millis(100));
+            let early = render_to_lines(&mut manager);
+            for _ in 0..10 {
+                manager.tick(Duration::from_millis(100));
+            }
+            (early, locate(&render_to_lines(&mut manager), "Waiting"))
+        };
+
+        let (early, settled) = promote(Notifications::new().promotion_animation(Animation::Fade));
+        assert_eq!(locate(&early, "Waiting"), settled);
+
+        // Without it the promoted notification slides in from the edge
+        let (early, settled) = promote(Notifications::new());
+        assert!(early.iter().all(|line| !line.contains("Waiting")) || locate(&early, "Waiting") != settled);
+    }
+
+    #[test]
+    fn test_line_transform_rewrites_rendered_lines() {
+        use ratatui::text::{Line, Span};
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.set_line_transform(Box::new(|_, lines| {
+            lines
+                .into_iter()
+                .map(|line| Line::from(Span::styled(line.to_string().to_uppercase(), line.style)))
+                .collect()
+        }));
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let text = render_to_lines(&mut manager).join("\n");
+
+        assert!(text.contains("TEST NOTIFICATION"));
+        assert!(!text.contains("Test notification"));
+    }
+
+    #[test]
+    fn test_line_transform_receives_notification() {
+        use ratatui::text::Line;
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        manager.set_line_transform(Box::new(|notification, lines| {
+            if notification.level() == Some(Level::Error) {
+                vec![Line::from("[hidden]")]
+            } else {
+                lines
+            }
+        }));
+        manager
+            .add(NotificationBuilder::new("token=abc").level(Level::Error).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        manager.add(NotificationBuilder::new("Saved").anchor(Anchor::TopRight).build().unwrap()).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let text = render_to_lines(&mut manager).join("\n");
+
+        assert!(text.contains("[hidden]"));
+        assert!(!text.contains("token=abc"));
+        assert!(text.contains("Saved"));
+    }
+
+    #[test]
+    fn test_update_swaps_content_without_reentering() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager
+            .add(NotificationBuilder::new("Uploading 40%").anchor(Anchor::BottomRight).build().unwrap())
+            .unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let before = render_to_lines(&mut manager);
+
+        assert!(manager.update(id, NotificationBuilder::new("Uploading 80%").build().unwrap()));
+        let after = render_to_lines(&mut manager);
+
+        // Drawn in place straight away: no entry animation from the edge
+        assert_eq!(locate(&after, "Uploading 80%"), locate(&before, "Uploading 40%"));
+        assert!(after.iter().all(|line| !line.contains("40%")));
+        assert_eq!(manager.find_by(|_| true), vec![id]);
+    }
+
+    #[test]
+    fn test_update_moves_between_anchors() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        manager.update(id, NotificationBuilder::new("Moved").anchor(Anchor::TopLeft).build().unwrap());
+        manager.tick(Duration::from_millis(100));
+        let lines = render_to_lines(&mut manager);
+
+        let (col, row) = locate(&lines, "Moved");
+        assert!(col < 40 && row < 12);
+        assert_eq!(manager.debug_stats().anchor_entries, 1);
+    }
+
+    #[test]
+    fn test_update_restarts_dwell_with_new_duration() {
+        use ratatui_notifications::notifications::{AnimationPhase, AutoDismiss, Notifications};
+
+        let phase = |manager: &Notifications, id: u64| {
+            manager.iter().find(|&(found, ..)| found == id).map(|(.., state)| state.phase())
+        };
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let longer = NotificationBuilder::new("Still working")
+            .auto_dismiss(AutoDismiss::After(Duration::from_secs(10)))
+            .build()
+            .unwrap();
+        assert!(manager.update(id, longer));
+
+        // Past the old 4s display time, inside the new 10s one
+        manager.tick(Duration::from_secs(6));
+        assert_eq!(phase(&manager, id), Some(AnimationPhase::Dwelling));
+        manager.tick(Duration::from_secs(5));
+        assert_eq!(phase(&manager, id), Some(AnimationPhase::SlidingOut));
+    }
+
+    #[test]
+    fn test_update_unknown_id() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert!(!manager.update(3, create_test_notification(Anchor::TopLeft)));
+    }
+
+    #[test]
+    fn test_collapse_animation_shrinks_width_on_exit() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::{Animation, AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager
+            .add(
+                NotificationBuilder::new("Collapsing away")
+                    .anchor(Anchor::BottomRight)
+                    .animation(Animation::Collapse)
+                    .auto_dismiss(AutoDismiss::Never)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+        render_to_lines(&mut manager);
+        let full = manager.damage_rect().unwrap();
+
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), (full.x + 1, full.y + 1))), Some(id));
+        let mut widths = Vec::new();
+        for _ in 0..8 {
+            manager.tick(Duration::from_millis(100));
+            render_to_lines(&mut manager);
+            widths.push(manager.damage_rect().map_or(0, |rect| rect.width));
+        }
+
+        assert!(widths.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(widths[0] < full.width);
+        assert_eq!(widths.last(), Some(&0));
+        assert!(manager.find_by(|_| true).iter().all(|&other| other != id));
+    }
+
+    #[test]
+    fn test_text_renders_styles_and_wraps_each_line() {
+        use ratatui::style::{Color, Stylize};
+        use ratatui::text::{Line, Text};
+        use ratatui_notifications::notifications::{Notifications, SizeConstraint};
+
+        let text = Text::from(vec![
+            Line::from(vec!["Build ".into(), "failed".red().bold()]),
+            Line::from("alpha beta gamma delta"),
+        ]);
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("")
+                    .text(text)
+                    .anchor(Anchor::TopLeft)
+                    .max_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(8))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let lines = render_to_lines(&mut manager);
+        let (_, first_row) = locate(&lines, "Build");
+        let (_, wrapped_row) = locate(&lines, "gamma");
+        let (_, rest_row) = locate(&lines, "delta");
+
+        assert_eq!(fg_of(&mut manager, "failed"), Color::Red);
+        assert!(first_row < wrapped_row);
+        assert_eq!(rest_row, wrapped_row + 1);
+    }
+
+    #[test]
+    fn test_pause_freezes_timers_until_resumed() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let mut unpaused = Notifications::new();
+        for m in [&mut manager, &mut unpaused] {
+            m.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            for _ in 0..30 {
+                m.tick(Duration::from_millis(100));
+            }
+        }
+        let before = render_to_lines(&mut manager);
+
+        manager.pause();
+        assert!(manager.is_paused());
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(render_to_lines(&mut manager), before);
+
+        // Resumes from the frozen point, in step with a manager never paused
+        manager.resume();
+        assert!(!manager.is_paused());
+        for m in [&mut manager, &mut unpaused] {
+            for _ in 0..12 {
+                m.tick(Duration::from_millis(100));
+            }
+        }
+        assert!(manager.has_notification());
+        assert_eq!(render_to_lines(&mut manager), render_to_lines(&mut unpaused));
+    }
+
+    #[test]
+    fn test_unfocused_ticks_advance_nothing() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.tick(Duration::from_millis(200));
+        let before = render_to_lines(&mut manager);
+        let phase = |manager: &Notifications| manager.iter().next().map(|(_, _, state)| state.phase());
+        let entering = phase(&manager);
+
+        manager.set_focused(false);
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(phase(&manager), entering);
+        assert_eq!(render_to_lines(&mut manager), before);
+
+        // Regaining focus (twice) picks up from the same frame
+        manager.set_focused(true);
+        manager.set_focused(true);
+        assert!(manager.is_focused());
+        manager.tick(Duration::from_millis(100));
+        assert_ne!(render_to_lines(&mut manager), before);
+        assert_eq!(phase(&manager), entering);
+    }
+
+    #[test]
+    fn test_focus_does_not_resume_a_manual_pause() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.pause();
+        manager.set_focused(false);
+        manager.set_focused(true);
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(manager.is_paused());
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn test_degrade_above_renders_compactly_then_restores() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().degrade_above(2);
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.tick(Duration::from_millis(100));
+
+        // Over the threshold: one-row pills, already in place mid-entry
+        let degraded = render_to_lines(&mut manager);
+        let pills: Vec<&String> = degraded.iter().filter(|line| line.contains("Test notification")).collect();
+        assert_eq!(pills.len(), 3);
+        assert!(pills.iter().all(|line| line.trim_end().ends_with(')')));
+        assert!(degraded.iter().all(|line| !line.contains('╭')));
+
+        // Back at the threshold: bordered boxes again
+        manager.remove(first);
+        manager.tick(Duration::from_millis(500));
+        let restored = render_to_lines(&mut manager);
+        assert!(restored.iter().any(|line| line.contains('╭')));
+        assert!(restored.iter().all(|line| !line.trim_end().ends_with(')')));
+    }
+
+    #[test]
+    fn test_notification_at_and_freeze_keep_hovered_alive() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let hovered = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let other = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.tick(Duration::from_secs(1));
+        let lines = render_to_lines(&mut manager);
+        let (col, row) = locate(&lines, "Test notification");
+
+        let under = manager.notification_at(col, row);
+        assert_eq!(under, Some(hovered));
+        assert!(manager.freeze(hovered));
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert_eq!(manager.find_by(|_| true), vec![hovered]);
+        assert!(!manager.freeze(other));
+
+        // Unfrozen, the remaining countdown runs out as usual
+        assert!(manager.unfreeze(hovered));
+        for _ in 0..40 {
+            manager.tick(Duration::from_millis(100));
+        }
+        render_to_lines(&mut manager);
+        assert!(!manager.has_notification());
+        assert_eq!(manager.notification_at(col, row), None);
+    }
+
+    #[test]
+    fn test_overflow_queue_holds_extras_and_promotes_fifo() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).overflow(Overflow::Queue);
+        let ids: Vec<u64> = (0..4)
+            .map(|i| {
+                let notif = NotificationBuilder::new(format!("Job {}", i)).anchor(Anchor::BottomRight).build().unwrap();
+                manager.add(notif).unwrap()
+            })
+            .collect();
+        manager.tick(Duration::from_millis(600));
+
+        assert_eq!(manager.queued_len(Anchor::BottomRight), 2);
+        assert_eq!(manager.queued_len(Anchor::TopLeft), 0);
+        assert_eq!(manager.find_by(|_| true), ids);
+        let text = render_to_lines(&mut manager).join("\n");
+        assert!(text.contains("Job 0") && text.contains("Job 1"));
+        assert!(!text.contains("Job 2") && !text.contains("Job 3"));
+
+        // Freeing a slot promotes the oldest waiting one, entering from the start
+        manager.remove(ids[0]);
+        manager.tick(Duration::from_millis(600));
+        assert_eq!(manager.queued_len(Anchor::BottomRight), 1);
+        let text = render_to_lines(&mut manager).join("\n");
+        assert!(text.contains("Job 2"));
+        assert!(!text.contains("Job 3"));
+    }
+
+    #[test]
+    fn test_overflow_queue_promotes_when_notification_expires() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(1)).overflow(Overflow::Queue);
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let waiting = manager.add(NotificationBuilder::new("Next").anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+
+        // First one lives about 5.25s; the queued one's timer has not started meanwhile
+        for _ in 0..60 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert_eq!(manager.find_by(|_| true), vec![waiting]);
+        assert_eq!(manager.queued_len(Anchor::TopLeft), 0);
+        assert!(render_to_lines(&mut manager).join("\n").contains("Next"));
+    }
+
+    #[test]
+    fn test_remaining_capacity_decreases_as_added() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(3));
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(3));
+
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(2));
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(1));
+        assert_eq!(manager.remaining_capacity(Anchor::TopLeft), Some(2));
+
+        let last = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(0));
+
+        // Overflow keeps the anchor full; a removal frees a slot
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(0));
+        manager.remove(last);
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), Some(1));
+    }
+
+    #[test]
+    fn test_remaining_capacity_unlimited_is_none() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_eq!(manager.remaining_capacity(Anchor::BottomRight), None);
+    }
+
+    #[test]
+    fn test_coalesce_counts_repeats_with_badge() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let lost = || NotificationBuilder::new("Connection lost").level(Level::Error).anchor(Anchor::BottomRight).build().unwrap();
+        let mut manager = Notifications::new().coalesce(true).max_concurrent(Some(2));
+        let kept = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let first = manager.add(lost()).unwrap();
+        for _ in 0..4 {
+            assert_eq!(manager.add(lost()).unwrap(), first);
+        }
+        // Same text at another level, or at another anchor, stays separate
+        let warn = NotificationBuilder::new("Connection lost").level(Level::Warn).anchor(Anchor::TopLeft).build().unwrap();
+        assert_ne!(manager.add(warn).unwrap(), first);
+        manager.tick(Duration::from_secs(1));
+
+        // Repeats never evicted the other notification at the full anchor
+        assert_eq!(manager.find_by(|_| true).len(), 3);
+        assert!(manager.find_by(|_| true).contains(&kept));
+        let text = render_to_lines(&mut manager).join("\n");
+        assert!(text.contains("(x5)"));
+
+        // Removing it takes every repeat with it
+        manager.remove(first);
+        let text = render_to_lines(&mut manager).join("\n");
+        assert!(!text.contains("(x"));
+        assert!(!manager.find_by(|n| n.level() == Some(Level::Error)).contains(&first));
+    }
+
+    #[test]
+    fn test_coalesce_restarts_dismiss_timer() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().coalesce(true);
+        let id = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        for _ in 0..40 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        // Without the restart it would have expired about 1.25s later
+        for _ in 0..30 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.find_by(|_| true), vec![id]);
+    }
+
+    #[test]
+    fn test_coalesce_off_adds_duplicates() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_ne!(manager.add(create_test_notification(Anchor::BottomRight)).unwrap(), first);
+    }
+
+    type DismissLog = std::sync::Arc<std::sync::Mutex<Vec<(u64, ratatui_notifications::DismissReason)>>>;
+
+    // Helper to create a notification that logs its dismissals
+    fn logged_notification(log: &DismissLog) -> Notification {
+        let log = std::sync::Arc::clone(log);
+        NotificationBuilder::new("Logged")
+            .anchor(Anchor::BottomRight)
+            .on_dismiss(Box::new(move |id, reason| log.lock().unwrap().push((id, reason))))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_on_dismiss_reports_expired_once() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let log = DismissLog::default();
+        let mut manager = Notifications::new();
+        let id = manager.add(logged_notification(&log)).unwrap();
+        for _ in 0..80 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager.remove(id);
+        manager.clear();
+
+        assert_eq!(*log.lock().unwrap(), vec![(id, DismissReason::Expired)]);
+    }
+
+    #[test]
+    fn test_on_dismiss_reports_manual_removal() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let log = DismissLog::default();
+        let mut manager = Notifications::new();
+        let removed = manager.add(logged_notification(&log)).unwrap();
+        let clicked = manager.add(logged_notification(&log)).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert!(manager.remove(removed));
+        assert!(!manager.remove(removed));
+        assert_eq!(*log.lock().unwrap(), vec![(removed, DismissReason::Manual)]);
+
+        // Dismissed early, so reported as manual once the exit finishes
+        render_to_lines(&mut manager);
+        let rect = manager.damage_rect().unwrap();
+        let down = crossterm::event::MouseEventKind::Down(crossterm::event::MouseButton::Left);
+        assert_eq!(manager.handle_click(&mouse(down, (rect.x + 1, rect.y + 1))), Some(clicked));
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(log.lock().unwrap().last(), Some(&(clicked, DismissReason::Manual)));
+        assert_eq!(log.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_on_dismiss_reports_overflow_and_cleared() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let log = DismissLog::default();
+        let mut manager = Notifications::new().max_concurrent(Some(2)).overflow(Overflow::DiscardOldest);
+        let oldest = manager.add(logged_notification(&log)).unwrap();
+        let second = manager.add(logged_notification(&log)).unwrap();
+        let third = manager.add(logged_notification(&log)).unwrap();
+        assert_eq!(*log.lock().unwrap(), vec![(oldest, DismissReason::Overflow)]);
+
+        manager.clear();
+        let mut cleared = log.lock().unwrap().split_off(1);
+        cleared.sort_by_key(|&(id, _)| id);
+        assert_eq!(cleared, vec![(second, DismissReason::Cleared), (third, DismissReason::Cleared)]);
+    }
+
+    #[test]
+    fn test_on_dismiss_survives_kind_update() {
+        use ratatui_notifications::notifications::{DismissReason, KindMode, Notifications};
+
+        let log = DismissLog::default();
+        let mut manager = Notifications::new().kind_mode(KindMode::Update);
+        let id = manager.add(NotificationBuilder::new("Saving").kind("save").on_dismiss({
+            let log = std::sync::Arc::clone(&log);
+            Box::new(move |id, reason| log.lock().unwrap().push((id, reason)))
+        }).build().unwrap()).unwrap();
+        manager.add(NotificationBuilder::new("Saved").kind("save").build().unwrap()).unwrap();
+        manager.force_clear();
+
+        assert_eq!(*log.lock().unwrap(), vec![(id, DismissReason::Cleared)]);
+    }
+
+    #[test]
+    fn test_update_with_new_callback_runs_old_one_as_replaced() {
+        use ratatui_notifications::notifications::{DismissReason, KindMode, Notifications};
+
+        let old_log = DismissLog::default();
+        let new_log = DismissLog::default();
+        let mut manager = Notifications::new();
+        let id = manager.add(logged_notification(&old_log)).unwrap();
+        assert!(manager.update(id, logged_notification(&new_log)));
+        assert_eq!(*old_log.lock().unwrap(), vec![(id, DismissReason::Replaced)]);
+        assert!(new_log.lock().unwrap().is_empty());
+
+        manager.remove(id);
+        assert_eq!(*old_log.lock().unwrap(), vec![(id, DismissReason::Replaced)]);
+        assert_eq!(*new_log.lock().unwrap(), vec![(id, DismissReason::Manual)]);
+
+        // The same through a kind update
+        let old_log = DismissLog::default();
+        let new_log = DismissLog::default();
+        let mut manager = Notifications::new().kind_mode(KindMode::Update);
+        let kinded = |log: &DismissLog| {
+            let log = std::sync::Arc::clone(log);
+            NotificationBuilder::new("Saving")
+                .kind("save")
+                .on_dismiss(Box::new(move |id, reason| log.lock().unwrap().push((id, reason))))
+                .build()
+                .unwrap()
+        };
+        let id = manager.add(kinded(&old_log)).unwrap();
+        assert_eq!(manager.add(kinded(&new_log)).unwrap(), id);
+        manager.force_clear();
+        assert_eq!(*old_log.lock().unwrap(), vec![(id, DismissReason::Replaced)]);
+        assert_eq!(*new_log.lock().unwrap(), vec![(id, DismissReason::Cleared)]);
+    }
+
+    #[test]
+    fn test_on_dismiss_runs_once_across_snapshot_and_restore() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let log = DismissLog::default();
+        let mut manager = Notifications::new();
+        manager.start_recording();
+        let id = manager.add(logged_notification(&log)).unwrap();
+        let snapshot = manager.snapshot();
+        manager.clear();
+        assert_eq!(*log.lock().unwrap(), vec![(id, DismissReason::Cleared)]);
+
+        let restored = manager.restore(snapshot).unwrap();
+        manager.remove(restored[0]);
+        let mut replayed = Notifications::new();
+        replayed.replay(&manager.stop_recording()).unwrap();
+        replayed.clear();
+        manager.clear();
+        assert_eq!(log.lock().unwrap().len(), 1, "restored and replayed copies share the spent callback");
+
+        // Restored into another manager without clearing: only the first to leave runs it
+        let log = DismissLog::default();
+        let mut old = Notifications::new();
+        let first = old.add(logged_notification(&log)).unwrap();
+        let mut reloaded = Notifications::new();
+        let second = reloaded.restore(old.snapshot()).unwrap()[0];
+        reloaded.remove(second);
+        old.remove(first);
+        assert_eq!(*log.lock().unwrap(), vec![(second, DismissReason::Manual)]);
+    }
+
+    #[test]
+    fn test_coalesced_repeat_runs_its_callback_with_the_original() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let first_log = DismissLog::default();
+        let repeat_log = DismissLog::default();
+        let mut manager = Notifications::new().coalesce(true);
+        let id = manager.add(logged_notification(&first_log)).unwrap();
+        assert_eq!(manager.add(logged_notification(&repeat_log)).unwrap(), id);
+        assert!(repeat_log.lock().unwrap().is_empty());
+
+        manager.remove(id);
+        assert_eq!(*first_log.lock().unwrap(), vec![(id, DismissReason::Manual)]);
+        assert_eq!(*repeat_log.lock().unwrap(), vec![(id, DismissReason::Manual)]);
+    }
+
+    #[test]
+    fn test_kind_update_keeps_manager_default_border() {
+        use ratatui::widgets::BorderType;
+        use ratatui_notifications::notifications::{KindMode, Notifications};
+
+        let mut manager = Notifications::new()
+            .kind_mode(KindMode::Update)
+            .default_border(Some(BorderType::Double));
+        let id = manager.add(NotificationBuilder::new("Saving").kind("save").build().unwrap()).unwrap();
+        manager.add(NotificationBuilder::new("Saved").kind("save").build().unwrap()).unwrap();
+
+        let border = manager.iter().find(|&(found, ..)| found == id).map(|(_, notification, _)| notification.border_type());
+        assert_eq!(border, Some(Some(BorderType::Double)));
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn test_level_lines_render_in_level_colours() {
+        use ratatui::style::Color;
+        use ratatui_notifications::notifications::{Level, Notifications, SizeConstraint};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("")
+                    .level_lines(vec![
+                        (Level::Info, "synced".to_string()),
+                        (Level::Warn, "skipped".to_string()),
+                        (Level::Error, "failed".to_string()),
+                    ])
+                    .anchor(Anchor::TopLeft)
+                    .max_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(8))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert_eq!(fg_of(&mut manager, "synced"), Color::Green);
+        assert_eq!(fg_of(&mut manager, "skipped"), Color::Yellow);
+        assert_eq!(fg_of(&mut manager, "failed"), Color::Red);
+    }
+
+    #[test]
+    fn test_initial_render_hidden_by_default() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert!(!render_to_lines(&mut manager).join("\n").contains("Test notification"));
+        assert_eq!(manager.damage_rect(), None);
+    }
+
+    #[test]
+    fn test_initial_render_at_rest_shows_final_position() {
+        use ratatui_notifications::notifications::{InitialRender, Notifications};
+
+        let mut settled = Notifications::new();
+        settled.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        settled.tick(Duration::from_secs(1));
+        render_to_lines(&mut settled);
+
+        let mut manager = Notifications::new().initial_render(InitialRender::AtRest);
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let lines = render_to_lines(&mut manager);
+
+        assert!(lines.join("\n").contains("Test notification"));
+        assert_eq!(manager.damage_rect(), settled.damage_rect());
+    }
+
+    #[test]
+    fn test_initial_render_animating_draws_first_entry_frame() {
+        use ratatui_notifications::notifications::{Animation, InitialRender, Notifications};
+
+        let fading = || NotificationBuilder::new("Test notification").anchor(Anchor::BottomRight).animation(Animation::Fade).build().unwrap();
+        let mut settled = Notifications::new();
+        settled.add(fading()).unwrap();
+        settled.tick(Duration::from_secs(1));
+        render_to_lines(&mut settled);
+
+        // A fade starts in its final position, fully faded
+        let mut manager = Notifications::new().initial_render(InitialRender::Animating);
+        manager.add(fading()).unwrap();
+        render_to_lines(&mut manager);
+        assert_eq!(manager.damage_rect(), settled.damage_rect());
+        assert_ne!(fg_of(&mut manager, "Test"), fg_of(&mut settled, "Test"));
+
+        // A slide starts off-screen
+        let mut manager = Notifications::new().initial_render(InitialRender::Animating);
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        render_to_lines(&mut manager);
+        assert_eq!(manager.damage_rect(), None);
+        manager.tick(Duration::from_millis(250));
+        render_to_lines(&mut manager);
+        assert!(manager.damage_rect().is_some());
+    }
+
+    #[test]
+    fn test_iter_yields_live_notifications_oldest_first() {
+        use ratatui_notifications::notifications::{AnimationPhase, AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        assert!(manager.is_empty());
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let second = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let sticky = manager
+            .add(NotificationBuilder::new("Sticky").auto_dismiss(AutoDismiss::Never).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let items: Vec<_> = manager.iter().collect();
+        assert_eq!(items.iter().map(|(id, ..)| *id).collect::<Vec<_>>(), vec![first, second, sticky]);
+        assert_eq!(items[2].1.content().to_string(), "Sticky");
+        assert!(items.iter().all(|(.., state)| state.phase() == AnimationPhase::Dwelling));
+        assert_eq!(items[0].2.remaining_display_time(), Some(Duration::from_secs(3)));
+        assert_eq!(items[2].2.remaining_display_time(), None);
+        assert_eq!(manager.len(), 3);
+
+        // Expired notifications drop out once finished
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(500));
+        }
+        assert_eq!(manager.iter().map(|(id, ..)| id).collect::<Vec<_>>(), vec![sticky]);
+        assert_eq!(manager.len(), 1);
+        assert!(!manager.is_empty());
+    }
+
+    #[test]
+    fn test_dismissal_metrics_count_each_reason() {
+        use ratatui_notifications::notifications::{DismissalMetrics, KindMode, Notifications};
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).kind_mode(KindMode::Replace);
+        assert_eq!(manager.dismissal_metrics(), DismissalMetrics::default());
+
+        // Overflow: a third at a full anchor evicts the oldest
+        let manual = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.dismissal_metrics().overflow, 1);
+
+        // Manual removal of an already evicted ID counts nothing
+        assert!(!manager.remove(manual));
+        manager.clear();
+        assert_eq!(manager.dismissal_metrics().cleared, 2);
+
+        // Replaced by the same kind, then removed by hand
+        manager.add(NotificationBuilder::new("Saving").kind("save").build().unwrap()).unwrap();
+        let saved = manager.add(NotificationBuilder::new("Saved").kind("save").build().unwrap()).unwrap();
+        assert!(manager.remove(saved));
+
+        // Expired after its display time
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..60 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let metrics = manager.dismissal_metrics();
+        assert_eq!(
+            metrics,
+            DismissalMetrics { expired: 1, manual: 1, overflow: 1, cleared: 2, replaced: 1, vetoed: 0 }
+        );
+        assert_eq!(metrics.total(), 6);
+
+        manager.reset_dismissal_metrics();
+        assert_eq!(manager.dismissal_metrics(), DismissalMetrics::default());
+    }
+
+    #[test]
+    fn test_dismissal_metrics_count_vetoes() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.set_dismiss_guard(Box::new(|_| false));
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let metrics = manager.dismissal_metrics();
+        assert_eq!(metrics.vetoed, 2);
+        assert_eq!(metrics.total(), 0);
+    }
+
+    #[test]
+    fn test_easing_shapes_entry_motion() {
+        use ratatui_notifications::notifications::{Easing, Notifications, SlideDirection};
+
+        // Left edge of the sliding box 300ms into its 500ms entry
+        let left_edge = |easing: Easing| {
+            let mut manager = Notifications::new();
+            let notification = NotificationBuilder::new("Sliding")
+                .anchor(Anchor::BottomRight)
+                .slide_direction(SlideDirection::FromRight)
+                .easing(easing)
+                .build()
+                .unwrap();
+            manager.add(notification).unwrap();
+            manager.tick(Duration::from_millis(300));
+            render_to_lines(&mut manager);
+            let eased = manager.iter().next().unwrap().2.eased_progress();
+            (manager.damage_rect().unwrap().x, eased)
+        };
+
+        let (linear_x, linear) = left_edge(Easing::Linear);
+        let (out_x, ease_out) = left_edge(Easing::EaseOutCubic);
+        let (in_x, ease_in) = left_edge(Easing::EaseInQuad);
+
+        assert!((linear - 0.6).abs() < 1e-4);
+        assert!(ease_out > linear && ease_in < linear);
+        // Sliding in from the right, further along means further left
+        assert!(out_x < linear_x && in_x > linear_x);
+    }
+
+    #[test]
+    fn test_success_level_renders_with_icon_and_colour() {
+        use ratatui::style::Color;
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(NotificationBuilder::new("Deployed").title("Done").level(Level::Success).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert!(render_to_lines(&mut manager).join("\n").contains("✔Done"));
+        assert_eq!(fg_of(&mut manager, "Done"), Color::LightGreen);
+    }
+
+    #[test]
+    fn test_level_theme_remaps_one_level() {
+        use ratatui::style::{Color, Style};
+        use ratatui_notifications::notifications::{Level, LevelStyle, LevelTheme, Notifications};
+
+        let warn = LevelStyle { border: Style::new().fg(Color::Rgb(255, 200, 0)), body: Style::new(), icon: Some("!") };
+        let mut manager = Notifications::new().level_theme(LevelTheme::default().set(Level::Warn, warn));
+        manager
+            .add(NotificationBuilder::new("Low disk").title("Careful").level(Level::Warn).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        manager
+            .add(NotificationBuilder::new("Fine").title("Errors").level(Level::Error).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert!(render_to_lines(&mut manager).join("\n").contains("!Careful"));
+        assert_eq!(fg_of(&mut manager, "Careful"), Color::Rgb(255, 200, 0));
+        // Unmapped levels keep the built-in look
+        assert_eq!(fg_of(&mut manager, "Errors"), Color::Red);
+    }
+
+    #[test]
+    fn test_notification_styles_override_level_theme() {
+        use ratatui::style::{Color, Style};
+        use ratatui_notifications::notifications::{Level, LevelStyle, LevelTheme, Notifications};
+
+        let body = LevelStyle { body: Style::new().fg(Color::White).bg(Color::Red), ..LevelStyle::builtin(Level::Error) };
+        let mut manager = Notifications::new().level_theme(LevelTheme::default().set(Level::Error, body));
+        manager
+            .add(NotificationBuilder::new("Themed").level(Level::Error).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        manager
+            .add(
+                NotificationBuilder::new("Custom")
+                    .level(Level::Error)
+                    .style(Style::new().fg(Color::Cyan))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert_eq!(fg_of(&mut manager, "Themed"), Color::White);
+        assert_eq!(fg_of(&mut manager, "Custom"), Color::Cyan);
+    }
+
+    #[test]
+    fn test_wrapped_notifications_grow_and_stack_without_overlap() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        for message in ["Größere Dateien wurden übertragen", "Ünïcödé wörds wrap cleanly"] {
+            let notification = NotificationBuilder::new(message).anchor(Anchor::TopRight).max_width(16).build().unwrap();
+            manager.add(notification).unwrap();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        // 40 rows tall so the default 20% height cap (8 rows) leaves room to grow
+        let lines = render_lines_at(&mut manager, 80, 40);
+        for word in ["Größere", "Dateien", "wurden", "übertragen", "Ünïcödé", "wörds", "wrap", "cleanly"] {
+            assert!(lines.iter().any(|line| line.contains(word)), "missing {word}");
+        }
+
+        // Each box is as tall as its wrapped text, and the second starts below the first
+        let rows = |corner: char| lines.iter().enumerate().filter(move |(_, l)| l.contains(corner)).map(|(y, _)| y);
+        let (tops, bottoms): (Vec<usize>, Vec<usize>) = (rows('╭').collect(), rows('╰').collect());
+        assert_eq!((tops.len(), bottoms.len()), (2, 2));
+        assert_eq!((bottoms[0] - tops[0], bottoms[1] - tops[1]), (5, 4));
+        assert!(tops[1] > bottoms[0]);
+    }
+
+    #[test]
+    fn test_headline_draws_bold_over_dimmed_body() {
+        use ratatui::backend::TestBackend;
+        use ratatui::style::Modifier;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        let notification = NotificationBuilder::new("3 errors in src/main.rs")
+            .headline("Build failed")
+            .anchor(Anchor::TopLeft)
+            .animation(Animation::Fade)
+            .build()
+            .unwrap();
+        manager.add(notification).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| (0..80).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+
+        // Border, headline, body, border: the headline adds exactly one row
+        assert!(row(1).contains("Build failed") && row(2).contains("3 errors"));
+        assert!(row(3).contains('╰'));
+        let x = row(1).chars().position(|c| c == 'B').unwrap() as u16;
+        assert!(buffer[(x, 1)].modifier.contains(Modifier::BOLD));
+        assert!(buffer[(x, 2)].modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_clear_anchor_empties_only_that_anchor() {
+        use ratatui_notifications::notifications::{DismissReason, Notifications};
+
+        let log: DismissLog = Default::default();
+        let mut manager = Notifications::new();
+        let logged = manager.add(logged_notification(&log)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let kept = manager.add(NotificationBuilder::new("Kept").anchor(Anchor::BottomRight).protected(true).build().unwrap()).unwrap();
+        let status = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        assert_eq!((manager.count_at(Anchor::BottomRight), manager.count_at(Anchor::TopRight)), (3, 1));
+
+        manager.clear_anchor(Anchor::BottomRight);
+        assert_eq!(*log.lock().unwrap(), [(logged, DismissReason::Cleared)]);
+        assert_eq!(manager.count_at(Anchor::BottomRight), 1);
+        assert_eq!(manager.count_at(Anchor::TopLeft), 0);
+        let left: Vec<u64> = manager.iter().map(|(id, ..)| id).collect();
+        assert_eq!(left, [kept, status]);
+
+        // The anchor keeps working for new notifications
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.count_at(Anchor::BottomRight), 2);
+        assert_eq!(manager.len(), 3);
+    }
+
+    #[test]
+    fn test_snapshot_restores_into_a_new_manager() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut old = Notifications::new();
+        let leaving = old.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let pinned = NotificationBuilder::new("Pinned").level(Level::Warn).anchor(Anchor::TopRight).sticky(true);
+        old.add(pinned.build().unwrap()).unwrap();
+        old.add(create_test_notification(Anchor::BottomLeft)).unwrap();
+        old.tick(Duration::from_secs(1));
+        old.remove(leaving);
+
+        let snapshot = old.snapshot();
+        let contents: Vec<String> = snapshot.iter().map(|n| n.content().to_string()).collect();
+        assert_eq!(contents, ["Pinned", "Test notification"]);
+
+        // Restored notifications enter again, at their own anchors
+        let mut reloaded = Notifications::new();
+        let ids = reloaded.restore(snapshot).unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_eq!((reloaded.count_at(Anchor::TopRight), reloaded.count_at(Anchor::BottomLeft)), (1, 1));
+        let (_, restored, state) = reloaded.iter().next().unwrap();
+        assert_eq!((restored.level(), restored.sticky()), (Some(Level::Warn), true));
+        assert_eq!(state.phase(), ratatui_notifications::notifications::AnimationPhase::Pending);
+    }
+
+    #[test]
+    fn test_duration_sets_total_time_on_screen() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let short = NotificationBuilder::new("Short").anchor(Anchor::TopLeft).duration(Duration::from_secs(2));
+        manager.add(short.build().unwrap()).unwrap();
+        let critical = NotificationBuilder::new("Critical").anchor(Anchor::TopLeft).duration(Duration::from_secs(10));
+        manager.add(critical.build().unwrap()).unwrap();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+
+        let contents = |manager: &Notifications| -> Vec<String> {
+            manager.iter().map(|(_, n, _)| n.content().to_string()).collect()
+        };
+        for _ in 0..21 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(contents(&manager), ["Critical", "Test notification"]);
+
+        // The default lifetime is about 5.25s; the critical one outlives it
+        for _ in 0..40 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(contents(&manager), ["Critical"]);
+    }
+
+    #[test]
+    fn test_borderless_and_bordered_share_an_anchor() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let bare = NotificationBuilder::new("Bare text").anchor(Anchor::TopLeft).borderless().build().unwrap();
+        manager.add(bare).unwrap();
+        manager.add(NotificationBuilder::new("Boxed").anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        // One row of padded text, then the three-row box right below it
+        let lines = render_to_lines(&mut manager);
+        let (_, bare_row) = locate(&lines, "Bare text");
+        let (_, boxed_row) = locate(&lines, "Boxed");
+        assert!(!lines[bare_row as usize].contains('│'));
+        assert!(lines[bare_row as usize + 1].contains('╭'));
+        assert_eq!(boxed_row, bare_row + 2);
+    }
+
+    #[test]
+    fn test_default_border_applies_unless_builder_chooses() {
+        use ratatui::widgets::BorderType;
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().default_border(None);
+        manager.add(NotificationBuilder::new("Plain").anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+        let double = NotificationBuilder::new("Double").anchor(Anchor::TopRight).border_type(BorderType::Double);
+        manager.add(double.build().unwrap()).unwrap();
+        let borders: Vec<Option<BorderType>> = manager.iter().map(|(_, n, _)| n.border_type()).collect();
+        assert_eq!(borders, [None, Some(BorderType::Double)]);
+
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let lines = render_to_lines(&mut manager);
+        assert!(lines.iter().all(|line| !line.contains('╭')));
+        assert!(lines.iter().any(|line| line.contains('╔')));
+    }
+
+    #[test]
+    fn test_take_events_reports_phase_transitions() {
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications, PhaseEvent};
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        manager.tick(Duration::from_millis(100));
+        assert_eq!(
+            manager.take_events(),
+            vec![PhaseEvent { id, from: AnimationPhase::Pending, to: AnimationPhase::SlidingIn }]
+        );
+        assert!(manager.take_events().is_empty(), "taking drains the buffer");
+
+        // Past the 500 ms entry: the notification is fully shown
+        for _ in 0..5 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(
+            manager.take_events(),
+            vec![PhaseEvent { id, from: AnimationPhase::SlidingIn, to: AnimationPhase::Dwelling }]
+        );
+
+        // Past the 4 s dwell and 750 ms exit
+        for _ in 0..50 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let targets: Vec<AnimationPhase> = manager.take_events().iter().map(|event| event.to).collect();
+        assert_eq!(targets, vec![AnimationPhase::SlidingOut, AnimationPhase::Finished]);
+    }
+
+    #[test]
+    fn test_take_events_is_bounded() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        for _ in 0..1100 {
+            manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        }
+        manager.tick(Duration::from_millis(100));
+
+        assert_eq!(manager.take_events().len(), 1024);
+    }
+
+    #[test]
+    fn test_render_lays_out_within_area() {
+        use ratatui::backend::TestBackend;
+        use ratatui::layout::Rect;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::MiddleCenter)).unwrap();
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        // The right-hand pane of a two-pane layout
+        let pane = Rect::new(40, 4, 40, 16);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, pane)).unwrap();
+
+        let drawn = manager.damage_rect().unwrap();
+        assert!(pane.contains(drawn.as_position()), "{drawn:?} outside {pane:?}");
+        assert_eq!(pane.intersection(drawn), drawn);
+        let center = |start: u16, len: u16| i32::from(start) + i32::from(len) / 2;
+        assert!((center(drawn.x, drawn.width) - center(pane.x, pane.width)).abs() <= 1);
+        assert!((center(drawn.y, drawn.height) - center(pane.y, pane.height)).abs() <= 1);
+
+        // A new area next frame moves the notification with it
+        let pane = Rect::new(0, 0, 40, 24);
+        terminal.draw(|frame| manager.render(frame, pane)).unwrap();
+        let drawn = manager.damage_rect().unwrap();
+        assert_eq!(pane.intersection(drawn), drawn);
+        assert!((center(drawn.x, drawn.width) - center(pane.x, pane.width)).abs() <= 1);
+    }
+
+    #[test]
+    fn test_render_clips_entry_slide_to_area() {
+        use ratatui::backend::TestBackend;
+        use ratatui::layout::Rect;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let pane = Rect::new(10, 2, 40, 12);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+
+        // Mid-slide, the box must never spill over the neighbouring pane
+        for _ in 0..5 {
+            manager.tick(Duration::from_millis(100));
+            terminal.draw(|frame| manager.render(frame, pane)).unwrap();
+            if let Some(drawn) = manager.damage_rect() {
+                assert_eq!(pane.intersection(drawn), drawn);
+            }
+        }
+        let buffer = terminal.backend().buffer();
+        for y in 0..24 {
+            for x in 50..80 {
+                assert_eq!(buffer[(x, y)].symbol(), " ", "drew outside the pane at ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_focus_cycles_oldest_first_and_wraps() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert_eq!(manager.focus_next(), None);
+
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let second = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let third = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        assert_eq!(manager.focused_id(), None);
+
+        assert_eq!(manager.focus_next(), Some(first));
+        assert_eq!(manager.focus_next(), Some(second));
+        assert_eq!(manager.focus_next(), Some(third));
+        assert_eq!(manager.focus_next(), Some(first));
+        assert_eq!(manager.focus_prev(), Some(third));
+        assert_eq!(manager.focused_id(), Some(third));
+    }
+
+    #[test]
+    fn test_dismiss_focused_moves_focus_within_anchor() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let other = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let second = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        manager.focus_next();
+        assert_eq!(manager.dismiss_focused(), Some(first));
+        assert_eq!(manager.focused_id(), Some(second), "next at the same anchor, not {other}");
+
+        // The exiting one is skipped when cycling
+        assert_eq!(manager.focus_next(), Some(other));
+        assert_eq!(manager.focus_next(), Some(second));
+
+        assert_eq!(manager.dismiss_focused(), Some(second));
+        assert_eq!(manager.focused_id(), None, "nothing live left at that anchor");
+        assert_eq!(manager.dismiss_focused(), None);
+    }
+
+    #[test]
+    fn test_handle_key_triggers_action_of_focused_notification() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+        use ratatui_notifications::notifications::{ActionId, Notifications};
+
+        let mut manager = Notifications::new();
+        let plain = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let prompt = NotificationBuilder::new("Upload failed")
+            .anchor(Anchor::BottomRight)
+            .action("Dismiss", ActionId(2))
+            .action("Retry", ActionId(1))
+            .action_keys(vec![("Retry".to_string(), KeyCode::Char('r')), ("Gone".to_string(), KeyCode::Char('g'))])
+            .build()
+            .unwrap();
+        let prompt = manager.add(prompt).unwrap();
+        let r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+
+        assert_eq!(manager.handle_key(r), None, "nothing focused");
+        assert_eq!(manager.focus_next(), Some(plain));
+        assert_eq!(manager.handle_key(r), None, "focused notification has no bindings");
+
+        assert_eq!(manager.focus_next(), Some(prompt));
+        assert_eq!(manager.handle_key(r), Some((prompt, 1)), "'r' is bound to Retry, the second action");
+        assert_eq!(manager.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)), None);
+        assert_eq!(manager.handle_key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE)), None, "no such action");
+        let release = KeyEvent::new_with_kind(KeyCode::Char('r'), KeyModifiers::NONE, KeyEventKind::Release);
+        assert_eq!(manager.handle_key(release), None);
+    }
+
+    #[test]
+    fn test_focus_passes_on_when_focused_expires_or_is_removed() {
+        use ratatui_notifications::notifications::{AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let short = NotificationBuilder::new("Short")
+            .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)))
+            .build()
+            .unwrap();
+        let short = manager.add(short).unwrap();
+        let long = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let last = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        // Expiring hands focus to the next one at the same anchor
+        manager.focus_next();
+        assert_eq!(manager.focused_id(), Some(short));
+        for _ in 0..16 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.focused_id(), Some(long));
+
+        manager.remove(long);
+        assert_eq!(manager.focused_id(), Some(last));
+    }
+
+    #[test]
+    fn test_focused_notification_renders_bold_border() {
+        use ratatui::backend::TestBackend;
+        use ratatui::style::Modifier;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager.focus_next();
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        assert_eq!(buffer[(0, 0)].symbol(), "╭");
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(79, 23)].symbol(), "╯");
+        assert!(!buffer[(79, 23)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_extend_matches_adding_in_a_loop() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let batch = || (0..5).map(|n| {
+            NotificationBuilder::new(format!("Result {n}")).anchor(Anchor::TopRight).build().unwrap()
+        });
+        let mut extended = Notifications::new().max_concurrent(Some(2));
+        let mut looped = Notifications::new().max_concurrent(Some(2));
+        extended.add(create_test_notification(Anchor::TopRight)).unwrap();
+        looped.add(create_test_notification(Anchor::TopRight)).unwrap();
+
+        let results = extended.extend(batch());
+        let expected: Vec<_> = batch().map(|notification| looped.add(notification)).collect();
+
+        assert_eq!(results, expected);
+        let ids = |manager: &Notifications| manager.iter().map(|(id, _, _)| id).collect::<Vec<_>>();
+        assert_eq!(ids(&extended), ids(&looped));
+        assert_eq!(ids(&extended), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_extend_reports_each_failure_and_continues() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let mut manager = Notifications::new().max_concurrent(Some(1));
+        let results = manager.extend([
+            prioritized(5),
+            prioritized(1),
+            prioritized(5),
+        ]);
+
+        assert_eq!(results, vec![Ok(0), Err(NotificationError::Outranked), Ok(1)]);
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limit_drops_adds_until_window_rolls_over() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let mut manager = Notifications::new().rate_limit(Anchor::TopLeft, (2, Duration::from_secs(1)));
+        let spam = |n: u32| NotificationBuilder::new(format!("Spam {n}")).anchor(Anchor::TopLeft).build().unwrap();
+
+        assert!(manager.add(spam(0)).is_ok());
+        assert!(manager.add(spam(1)).is_ok());
+        assert_eq!(manager.add(spam(2)), Err(NotificationError::RateLimited));
+
+        // Other anchors are unaffected
+        assert!(manager.add(create_test_notification(Anchor::BottomRight)).is_ok());
+
+        manager.tick(Duration::from_millis(900));
+        assert_eq!(manager.add(spam(3)), Err(NotificationError::RateLimited));
+
+        manager.tick(Duration::from_millis(100));
+        assert!(manager.add(spam(4)).is_ok());
+        assert_eq!(manager.len(), 4);
+    }
+
+    #[test]
+    fn test_outranked_add_keeps_rate_budget() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let mut manager = Notifications::new()
+            .max_concurrent(Some(1))
+            .rate_limit(Anchor::TopLeft, (2, Duration::from_secs(1)));
+        manager.add(prioritized(5)).unwrap();
+
+        // Turned away adds leave the window's second slot unspent
+        assert_eq!(manager.add(prioritized(1)), Err(NotificationError::Outranked));
+        assert_eq!(manager.add(prioritized(1)), Err(NotificationError::Outranked));
+        assert!(manager.add(prioritized(5)).is_ok());
+        assert_eq!(manager.add(prioritized(9)), Err(NotificationError::RateLimited));
+    }
+
+    #[test]
+    fn test_rate_limit_window_waits_while_paused() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let mut manager = Notifications::new().rate_limit(Anchor::TopLeft, (1, Duration::from_secs(1)));
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+
+        manager.pause();
+        manager.tick(Duration::from_secs(2));
+        assert_eq!(
+            manager.add(create_test_notification(Anchor::TopLeft)),
+            Err(NotificationError::RateLimited)
+        );
+
+        manager.resume();
+        manager.tick(Duration::from_secs(1));
+        assert!(manager.add(create_test_notification(Anchor::TopLeft)).is_ok());
+    }
+
+    #[test]
+    fn test_remaining_counts_down_while_dwelling() {
+        use ratatui_notifications::notifications::{AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let notification = NotificationBuilder::new("Saved")
+            .auto_dismiss(AutoDismiss::After(Duration::from_secs(2)))
+            .build()
+            .unwrap();
+        let id = manager.add(notification).unwrap();
+        assert_eq!(manager.remaining(id), Some(Duration::from_secs(2)));
+
+        // The 500 ms entry does not count
+        for _ in 0..4 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.remaining(id), Some(Duration::from_secs(2)));
+
+        manager.tick(Duration::from_millis(100));
+        manager.tick(Duration::from_millis(400));
+        assert_eq!(manager.remaining(id), Some(Duration::from_millis(1500)));
+        assert_eq!(manager.remaining_fraction(id), Some(0.75));
+
+        for _ in 0..15 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.remaining(id), Some(Duration::ZERO));
+        assert_eq!(manager.remaining_fraction(id), Some(0.0));
+    }
+
+    #[test]
+    fn test_remaining_is_none_without_countdown() {
+        use ratatui_notifications::notifications::{AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let sticky = manager.add(NotificationBuilder::new("Sticky").sticky(true).build().unwrap()).unwrap();
+        let never = NotificationBuilder::new("Never").auto_dismiss(AutoDismiss::Never).build().unwrap();
+        let never = manager.add(never).unwrap();
+
+        assert_eq!(manager.remaining(sticky), None);
+        assert_eq!(manager.remaining(never), None);
+        assert_eq!(manager.remaining_fraction(never), None);
+        assert_eq!(manager.remaining(999), None);
+    }
+
+    #[test]
+    fn test_remaining_is_zero_once_exiting() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager.focus_next();
+        manager.dismiss_focused();
+
+        assert_eq!(manager.remaining(id), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_custom_renderer_draws_into_stacked_rects() {
+        use ratatui::backend::TestBackend;
+        use ratatui::prelude::{Frame, Rect};
+        use ratatui::widgets::Paragraph;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::{NotificationRenderer, NotificationState, Notifications};
+        use std::sync::{Arc, Mutex};
+
+        struct Hashes(Arc<Mutex<Vec<(String, Rect)>>>);
+
+        impl NotificationRenderer for Hashes {
+            fn render(&self, notification: &Notification, _state: &NotificationState, frame: &mut Frame, rect: Rect) {
+                self.0.lock().unwrap().push((notification.content().to_string(), rect));
+                frame.render_widget(Paragraph::new("#".repeat(usize::from(rect.width))), rect);
+            }
+        }
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = Notifications::new().with_renderer(Hashes(Arc::clone(&calls)));
+        let first = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+        // Layout is unchanged: two stacked boxes, each handed to the renderer
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert!(calls.iter().all(|(text, _)| text == "Test notification"));
+        let mut rects: Vec<Rect> = calls.iter().map(|&(_, rect)| rect).collect();
+        rects.sort_by_key(|rect| rect.y);
+        let (top, bottom) = (rects[0], rects[1]);
+        assert_eq!(top.bottom(), bottom.y);
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(top.x, top.y)].symbol(), "#");
+        assert_eq!(manager.hit(top.x, top.y).map(|hit| hit.id), Some(first));
+    }
+
+    #[test]
+    fn test_max_total_caps_notifications_across_anchors() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let anchors = [Anchor::TopLeft, Anchor::TopRight, Anchor::BottomLeft, Anchor::BottomRight];
+        let mut manager = Notifications::new().max_total(Some(3));
+        for n in 0..8 {
+            let outcome = manager
+                .add_with_eviction(create_test_notification(anchors[n % anchors.len()]))
+                .unwrap();
+            assert!(manager.len() <= 3, "total exceeded the cap after add {n}");
+            // The oldest anywhere makes room, whichever anchor it is at
+            assert_eq!(outcome.evicted, n.checked_sub(3).map(|oldest| oldest as u64));
+        }
+
+        let ids: Vec<u64> = manager.iter().map(|(id, _, _)| id).collect();
+        assert_eq!(ids, vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn test_max_total_composes_with_max_concurrent() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).max_total(Some(3));
+        let ids = manager.extend([
+            create_test_notification(Anchor::BottomRight),
+            create_test_notification(Anchor::TopLeft),
+            create_test_notification(Anchor::TopLeft),
+        ]);
+        assert_eq!(ids, vec![Ok(0), Ok(1), Ok(2)]);
+
+        // The anchor limit triggers first: the oldest at that anchor goes
+        let outcome = manager.add_with_eviction(create_test_notification(Anchor::TopLeft)).unwrap();
+        assert_eq!(outcome.evicted, Some(1));
+
+        // Then only the total is full: the oldest anywhere goes
+        let outcome = manager.add_with_eviction(create_test_notification(Anchor::TopRight)).unwrap();
+        assert_eq!(outcome.evicted, Some(0));
+        assert_eq!(manager.len(), 3);
+        assert_eq!(manager.remaining_capacity(Anchor::MiddleCenter), Some(0));
+    }
+
+    #[test]
+    fn test_max_total_discard_newest_and_batch() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_total(Some(2)).overflow(Overflow::DiscardNewest);
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let outcome = manager.add_with_eviction(create_test_notification(Anchor::BottomLeft)).unwrap();
+        assert_eq!(outcome.evicted, Some(1));
+
+        manager.batch(|m| {
+            for _ in 0..3 {
+                m.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            }
+        });
+        assert_eq!(manager.len(), 2);
+    }
+
+    #[test]
+    fn test_max_total_with_queue_holds_extras() {
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+
+        let mut manager = Notifications::new().max_total(Some(2)).overflow(Overflow::Queue);
+        for anchor in [Anchor::TopLeft, Anchor::TopRight, Anchor::BottomLeft] {
+            manager.add(create_test_notification(anchor)).unwrap();
+        }
+        manager.tick(Duration::from_millis(100));
+
+        let phases: Vec<AnimationPhase> = manager.iter().map(|(_, _, state)| state.phase()).collect();
+        assert_eq!(phases, vec![AnimationPhase::SlidingIn, AnimationPhase::SlidingIn, AnimationPhase::Pending]);
+        assert_eq!(manager.len(), 3);
+    }
+
+    #[test]
+    fn test_retint_changes_level_without_touching_timers() {
+        use ratatui_notifications::notifications::{AnimationPhase, Level, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        manager.tick(Duration::from_millis(600));
+        let remaining = manager.remaining(id);
+
+        assert!(manager.retint(id, Level::Error));
+        assert!(!manager.retint(id + 1, Level::Error));
+
+        let (_, notification, state) = manager.iter().next().unwrap();
+        assert_eq!(notification.level(), Some(Level::Error));
+        assert_eq!(state.phase(), AnimationPhase::Dwelling);
+        assert_eq!(manager.remaining(id), remaining);
+    }
+
+    #[test]
+    fn test_set_message_regrows_height_on_next_render() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager.add(
+            NotificationBuilder::new("Short")
+                .anchor(Anchor::TopLeft)
+                .max_width(20)
+                .animation(Animation::Fade)
+                .build()
+                .unwrap(),
+        ).unwrap();
+        manager.tick(Duration::from_millis(600));
+        let before = render_lines_at(&mut manager, 40, 40);
+        let remaining = manager.remaining(id);
+
+        assert!(manager.set_message(id, "one two three four five six seven eight nine ten"));
+        assert!(!manager.set_message(id + 1, "missing"));
+        let after = render_lines_at(&mut manager, 40, 40);
+
+        let rows = |lines: &[String]| lines.iter().filter(|line| !line.trim().is_empty()).count();
+        assert!(rows(&after) > rows(&before), "before: {before:?}\nafter: {after:?}");
+        assert!(after.iter().any(|line| line.contains("ten")));
+        assert_eq!(manager.remaining(id), remaining);
+    }
+
+    #[test]
+    fn test_tick_to_phase_advances_exactly_to_each_transition() {
+        use ratatui_notifications::notifications::{Animation, AnimationPhase, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager.add(
+            NotificationBuilder::new("Saved").animation(Animation::Fade).build().unwrap(),
+        ).unwrap();
+        let (entry, display, exit) = manager.iter().next().unwrap().2.phase_durations();
+
+        assert_eq!(manager.tick_to_phase(id, AnimationPhase::FadingIn), Some(Duration::ZERO));
+        assert_eq!(manager.tick_to_phase(id, AnimationPhase::Dwelling), Some(entry));
+        // The tick that completes the entry also counts against the dwell
+        let dwell = display.unwrap() - entry;
+        assert_eq!(manager.remaining(id), Some(dwell));
+        assert_eq!(manager.tick_to_phase(id, AnimationPhase::FadingOut), Some(dwell));
+        assert_eq!(manager.tick_to_phase(id, AnimationPhase::Finished), Some(exit));
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn test_tick_to_phase_rejects_unreachable_phases() {
+        use ratatui_notifications::notifications::{Animation, AnimationPhase, Notifications};
+
+        let mut manager = Notifications::new();
+        let sticky = manager.add(
+            NotificationBuilder::new("Pinned").animation(Animation::Fade).sticky(true).build().unwrap(),
+        ).unwrap();
+
+        assert_eq!(manager.tick_to_phase(sticky, AnimationPhase::SlidingIn), None);
+        assert!(manager.tick_to_phase(sticky, AnimationPhase::Dwelling).is_some());
+        assert_eq!(manager.tick_to_phase(sticky, AnimationPhase::FadingIn), None);
+        assert_eq!(manager.tick_to_phase(sticky, AnimationPhase::FadingOut), None);
+        assert_eq!(manager.tick_to_phase(sticky + 1, AnimationPhase::Dwelling), None);
+    }
+
+    #[test]
+    fn test_stack_direction_up_at_top_anchor_rests_on_bottom_edge() {
+        use ratatui_notifications::notifications::{Animation, Notifications, StackDirection};
+
+        let mut manager = Notifications::new().stack_direction(Anchor::TopRight, StackDirection::Up);
+        for text in ["older", "newer"] {
+            manager.add(
+                NotificationBuilder::new(text).anchor(Anchor::TopRight).animation(Animation::Fade).build().unwrap(),
+            ).unwrap();
+            manager.tick(Duration::from_millis(10));
+        }
+        manager.tick(Duration::from_millis(600));
+
+        let lines = render_lines_at(&mut manager, 40, 20);
+        let (_, older) = locate(&lines, "older");
+        let (newer_x, newer) = locate(&lines, "newer");
+        assert!(newer > 10, "stack should sit in the bottom half: {lines:?}");
+        assert!(older < newer, "newest should rest on the baseline: {lines:?}");
+        assert!(newer_x > 20);
+    }
+
+    #[test]
+    fn test_stack_direction_up_slides_in_from_moved_baseline() {
+        use ratatui_notifications::notifications::{Animation, Notifications, StackDirection};
+
+        let mut manager = Notifications::new().stack_direction(Anchor::TopRight, StackDirection::Up);
+        manager.add(
+            NotificationBuilder::new("arriving").anchor(Anchor::TopRight).animation(Animation::Slide).build().unwrap(),
+        ).unwrap();
+        manager.tick(Duration::ZERO);
+        render_lines_at(&mut manager, 40, 20);
+
+        manager.tick(Duration::from_millis(300));
+        let (_, entering) = locate(&render_lines_at(&mut manager, 40, 20), "╭");
+        manager.tick(Duration::from_millis(300));
+        let (_, settled) = locate(&render_lines_at(&mut manager, 40, 20), "╭");
+
+        assert!(entering > settled, "should slide up from below, not down from the top");
+    }
+
+    #[test]
+    fn test_action_at_finds_button_under_point() {
+        use ratatui_notifications::notifications::{ActionId, Hit, HitRegion, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager.add(
+            NotificationBuilder::new("Upload failed")
+                .anchor(Anchor::TopLeft)
+                .action("Retry", ActionId(1))
+                .action("Dismiss", ActionId(2))
+                .build()
+                .unwrap(),
+        ).unwrap();
+        manager.tick(Duration::from_millis(600));
+        let lines = render_lines_at(&mut manager, 60, 20);
+
+        let (retry_x, retry_y) = locate(&lines, "[Retry]");
+        let (dismiss_x, dismiss_y) = locate(&lines, "[Dismiss]");
+        let (text_x, text_y) = locate(&lines, "Upload failed");
+        assert!(retry_y > text_y);
+        assert_eq!(manager.action_at(retry_x + 3, retry_y), Some((id, ActionId(1))));
+        assert_eq!(manager.action_at(dismiss_x, dismiss_y), Some((id, ActionId(2))));
+        assert_eq!(manager.hit(dismiss_x, dismiss_y), Some(Hit { id, region: HitRegion::Action(ActionId(2)) }));
+        // The gap between buttons and the body are not actions
+        assert_eq!(manager.action_at(dismiss_x - 1, dismiss_y), None);
+        assert_eq!(manager.action_at(text_x, text_y), None);
+        assert_eq!(manager.hit(text_x, text_y), Some(Hit { id, region: HitRegion::Body }));
+    }
+
+    #[test]
+    fn test_actions_row_keeps_stacked_notifications_apart() {
+        use ratatui_notifications::notifications::{ActionId, Notifications};
+
+        let mut manager = Notifications::new();
+        for text in ["first", "second"] {
+            manager.add(
+                NotificationBuilder::new(text)
+                    .anchor(Anchor::TopLeft)
+                    .action("OK", ActionId(0))
+                    .build()
+                    .unwrap(),
+            ).unwrap();
+            manager.tick(Duration::from_millis(10));
+        }
+        manager.tick(Duration::from_millis(600));
+        let lines = render_lines_at(&mut manager, 60, 40);
+
+        let (_, first) = locate(&lines, "first");
+        let (_, second) = locate(&lines, "second");
+        let buttons: Vec<usize> = (0..lines.len()).filter(|&row| lines[row].contains("[OK]")).collect();
+        assert_eq!(buttons, vec![usize::from(first) + 1, usize::from(second) + 1], "{lines:#?}");
+    }
+
+    #[test]
+    fn test_handle_dismiss_animates_out_and_goes_inactive() {
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+
+        let mut manager = Notifications::new();
+        let handle = manager.add_handle(create_test_notification(Anchor::TopRight)).unwrap();
+        manager.tick(Duration::from_millis(600));
+        assert!(handle.is_active(&manager));
+        assert_eq!(u64::from(handle), handle.id());
+
+        assert!(handle.dismiss(&mut manager));
+        assert!(handle.is_active(&manager), "still animating out");
+        let phase = manager.iter().find(|&(id, ..)| id == handle.id()).map(|(.., state)| state.phase());
+        assert_eq!(phase, Some(AnimationPhase::SlidingOut));
+
+        manager.tick(Duration::from_secs(1));
+        assert!(!handle.is_active(&manager));
+        assert!(!handle.dismiss(&mut manager));
+        assert!(!handle.remove(&mut manager));
+    }
+
+    #[test]
+    fn test_handle_from_other_manager_is_ignored() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut first = Notifications::new();
+        let mut second = Notifications::new();
+        let handle = first.add_handle(create_test_notification(Anchor::TopRight)).unwrap();
+        let other_id = second.add(create_test_notification(Anchor::TopRight)).unwrap();
+        assert_eq!(handle.id(), other_id, "raw ids collide across managers");
+
+        assert!(!handle.is_active(&second));
+        assert!(!handle.remove(&mut second));
+        assert!(!handle.dismiss(&mut second));
+        assert_eq!(second.len(), 1);
+
+        assert!(handle.remove(&mut first));
+        assert!(!handle.is_active(&first));
+    }
+
+    #[test]
+    fn test_monochrome_emits_no_colours_and_tags_levels() {
+        use ratatui::backend::TestBackend;
+        use ratatui::style::{Color, Stylize};
+        use ratatui::text::Line;
+        use ratatui::Terminal;
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new().monochrome(true);
+        let error = NotificationBuilder::new(Line::from("disk full".red().on_blue()))
+            .title("Backup")
+            .level(Level::Error)
+            .anchor(Anchor::TopLeft)
+            .build()
+            .unwrap();
+        let warn = NotificationBuilder::new("low battery")
+            .level(Level::Warn)
+            .anchor(Anchor::BottomRight)
+            .build()
+            .unwrap();
+        let error_id = manager.add(error).unwrap();
+        manager.add(warn).unwrap();
+        manager.point_at(error_id, 60, 12);
+        manager.tick(Duration::from_millis(600));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("[ERROR] Backup"), "titled notifications tag the title");
+        assert!(text.contains("[WARN] low battery"), "untitled notifications tag the message");
+        assert!(text.contains("disk full"));
+        assert!(
+            buffer.content().iter().all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset),
+            "no cell carries a colour"
+        );
+
+        // Colour comes back without the flag
+        let mut manager = Notifications::new();
+        manager.add(NotificationBuilder::new("low battery").level(Level::Warn).build().unwrap()).unwrap();
+        manager.tick(Duration::from_millis(600));
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(!text.contains("[WARN]"));
+        assert!(buffer.content().iter().any(|cell| cell.fg != Color::Reset));
+    }
+
+    #[test]
+    fn test_needs_fast_frames_only_while_animating() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert!(!manager.needs_fast_frames());
+
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        assert!(manager.needs_fast_frames(), "about to enter");
+        manager.tick(Duration::from_millis(100));
+        assert!(manager.needs_fast_frames(), "entering");
+
+        manager.tick(Duration::from_millis(500));
+        assert!(manager.has_notification());
+        assert!(!manager.needs_fast_frames(), "idle while displayed");
+
+        manager.tick(Duration::from_secs(4));
+        assert!(manager.needs_fast_frames(), "exiting");
+        manager.pause();
+        assert!(!manager.needs_fast_frames(), "nothing moves while paused");
+        manager.resume();
+
+        manager.tick(Duration::from_secs(1));
+        assert!(!manager.has_notification());
+        assert!(!manager.needs_fast_frames());
+    }
+
+    #[test]
+    fn test_max_height_truncates_whole_lines_with_indicator() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let body = (1..=10).map(|n| format!("line {n}")).collect::<Vec<_>>().join("\n");
+        manager
+            .add(NotificationBuilder::new(body).anchor(Anchor::TopLeft).max_height(6).build().unwrap())
+            .unwrap();
+        manager.add(NotificationBuilder::new("next").anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+        manager.tick(Duration::from_millis(600));
+
+        let lines = render_lines_at(&mut manager, 80, 40);
+        let top = usize::from(locate(&lines, "line 1 ").1);
+        assert!(lines[top + 2].contains("line 3"));
+        assert!(lines[top + 3].contains("▼ 7 more lines"), "{lines:#?}");
+        assert!(!lines.iter().any(|line| line.contains("line 4")));
+        assert!(lines[top + 4].contains("╰"), "box ends at the capped height");
+
+        // The next notification stacks below the clamped footprint
+        let next = usize::from(locate(&lines, "next").1);
+        assert!(next > top + 4 && next <= top + 7, "{lines:#?}");
+    }
+
+    #[test]
+    fn test_overflow_summary_counts_evictions() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let messages = |manager: &Notifications| -> Vec<String> {
+            manager.iter().map(|(_, notification, _)| notification.content().to_string()).collect()
+        };
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).overflow_summary(true);
+        for n in 1..=5 {
+            manager.add(NotificationBuilder::new(format!("toast {n}")).anchor(Anchor::TopRight).build().unwrap()).unwrap();
+        }
+
+        assert_eq!(messages(&manager), vec!["3 notifications suppressed", "toast 4", "toast 5"]);
+        let summary = manager.iter().next().map(|(_, notification, _)| notification.level());
+        assert_eq!(summary, Some(Some(Level::Warn)));
+        assert_eq!(manager.count_at(Anchor::TopRight), 3, "the summary sits on top of the limit");
+
+        // Once drops stop, the summary times out and the count starts over
+        for _ in 0..60 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(manager.is_empty());
+        for n in 6..=8 {
+            manager.add(NotificationBuilder::new(format!("toast {n}")).anchor(Anchor::TopRight).build().unwrap()).unwrap();
+        }
+        assert_eq!(messages(&manager), vec!["toast 7", "1 notification suppressed", "toast 8"]);
+    }
+
+    #[test]
+    fn test_overflow_summary_drawn_alongside_max_concurrent_notifications() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(2)).overflow_summary(true);
+        for n in 1..=4 {
+            manager.add(NotificationBuilder::new(format!("toast {n}")).anchor(Anchor::TopRight).build().unwrap()).unwrap();
+        }
+        manager.tick(Duration::from_millis(600));
+
+        let lines = render_to_lines(&mut manager);
+        for needle in ["2 notifications suppressed", "toast 3", "toast 4"] {
+            assert!(lines.iter().any(|line| line.contains(needle)), "{needle} is drawn");
+        }
+    }
+
+    #[test]
+    fn test_overflow_summary_moved_by_update_leaves_limits_intact() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let toast = |n: u32, anchor: Anchor| NotificationBuilder::new(format!("toast {n}")).anchor(anchor).build().unwrap();
+        let mut manager = Notifications::new().max_concurrent(Some(1)).overflow_summary(true);
+        manager.add(toast(1, Anchor::TopRight)).unwrap();
+        let live = manager.add(toast(2, Anchor::TopRight)).unwrap();
+        let summary = manager.iter().map(|(id, ..)| id).find(|&id| id != live).unwrap();
+
+        let moved = NotificationBuilder::new("1 notification suppressed").anchor(Anchor::BottomLeft).build().unwrap();
+        assert!(manager.update(summary, moved));
+        manager.remove(live);
+
+        // TopRight is empty now; the moved summary counts against neither anchor
+        manager.add(toast(3, Anchor::TopRight)).unwrap();
+        manager.add(toast(4, Anchor::BottomLeft)).unwrap();
+        assert_eq!(manager.count_at(Anchor::TopRight), 1);
+        assert_eq!(manager.count_at(Anchor::BottomLeft), 2);
+    }
+
+    #[test]
+    fn test_overflow_summary_does_not_take_a_max_visible_slot() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager =
+            Notifications::new().max_concurrent(Some(1)).max_visible(Anchor::TopRight, 1).overflow_summary(true);
+        for n in 1..=2 {
+            manager.add(NotificationBuilder::new(format!("toast {n}")).anchor(Anchor::TopRight).build().unwrap()).unwrap();
+        }
+        assert_eq!(manager.queued_len(Anchor::TopRight), 0);
+
+        manager.tick(Duration::from_millis(600));
+        let lines = render_to_lines(&mut manager);
+        assert!(lines.iter().any(|line| line.contains("toast 2")));
+        assert!(lines.iter().any(|line| line.contains("1 notification suppressed")));
+    }
+
+    #[test]
+    fn test_overflow_summary_off_by_default() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(1));
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn test_outranked_kind_add_keeps_the_notification_it_would_replace() {
+        use ratatui_notifications::notifications::{NotificationError, Notifications};
+
+        let build = |anchor: Anchor, priority: u8| {
+            NotificationBuilder::new("Building").anchor(anchor).kind("build").priority(priority).build().unwrap()
+        };
+        let mut manager = Notifications::new().max_concurrent(Some(1));
+        let old = manager.add(build(Anchor::BottomLeft, 0)).unwrap();
+        manager.add(prioritized(5)).unwrap();
+
+        // TopLeft stays full of a better notification, so the replacement is
+        // turned away and the one it would have replaced is untouched
+        assert_eq!(manager.add(build(Anchor::TopLeft, 0)), Err(NotificationError::Outranked));
+        assert!(manager.iter().any(|(id, ..)| id == old));
+
+        // Replacing in place frees its own slot, even when it outranks the newcomer
+        let new = manager.add(build(Anchor::BottomLeft, 0)).unwrap();
+        assert_ne!(new, old);
+        assert!(!manager.iter().any(|(id, ..)| id == old));
+    }
+
+    #[test]
+    fn test_monochrome_tag_follows_message_and_level_changes() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new().monochrome(true);
+        let id = manager
+            .add(NotificationBuilder::new("low battery").level(Level::Warn).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_millis(600));
+        assert!(render_to_lines(&mut manager).iter().any(|line| line.contains("[WARN] low battery")));
+
+        manager.set_message(id, "battery critical");
+        manager.retint(id, Level::Error);
+        let lines = render_to_lines(&mut manager);
+        assert!(lines.iter().any(|line| line.contains("[ERROR] battery critical")));
+        assert!(!lines.iter().any(|line| line.contains("[WARN]") || line.contains("low battery")));
+    }
+
+    #[test]
+    fn test_clear_keeps_protected_notifications() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+
+        let transient = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let protected = manager
+            .add(
+                NotificationBuilder::new("Critical")
+                    .anchor(Anchor::BottomRight)
+                    .protected(true)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        manager.clear();
+
+        assert!(!manager.remove(transient));
+        assert!(manager.remove(protected));
+    }
+
+    #[test]
+    fn test_overflow_evicts_unprotected_before_protected() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(2));
+        let banner = manager
+            .add(NotificationBuilder::new("Offline mode").anchor(Anchor::TopRight).protected(true).build().unwrap())
+            .unwrap();
+        let first = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let second = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+
+        let ids: Vec<u64> = manager.iter().map(|(id, ..)| id).collect();
+        assert_eq!(ids, vec![banner, second], "the oldest unprotected toast is evicted instead");
+        assert!(!ids.contains(&first));
+
+        // With only protected candidates left, the oldest still has to go
+        let mut manager = Notifications::new().max_concurrent(Some(1));
+        let old = manager.add(NotificationBuilder::new("a").protected(true).build().unwrap()).unwrap();
+        let new = manager.add(NotificationBuilder::new("b").protected(true).build().unwrap()).unwrap();
+        let ids: Vec<u64> = manager.iter().map(|(id, ..)| id).collect();
+        assert_eq!(ids, vec![new]);
+        assert_ne!(old, new);
+    }
+
+    #[test]
+    fn test_protected_notification_auto_dismisses_only_with_finite_duration() {
+        use ratatui_notifications::notifications::{AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let timed = manager.add(NotificationBuilder::new("timed").protected(true).build().unwrap()).unwrap();
+        let banner = manager
+            .add(
+                NotificationBuilder::new("Offline mode")
+                    .protected(true)
+                    .auto_dismiss(AutoDismiss::Never)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let ids: Vec<u64> = manager.iter().map(|(id, ..)| id).collect();
+        assert!(!ids.contains(&timed));
+        assert_eq!(ids, vec![banner]);
+    }
+
+    #[test]
+    fn test_force_clear_removes_protected_notifications() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+
+        let protected = manager
+            .add(NotificationBuilder::new("Critical").protected(true).build().unwrap())
+            .unwrap();
+
+        manager.clear();
+        assert!(manager.has_notification());
+
+        manager.force_clear();
+        assert!(!manager.has_notification());
+        assert!(!manager.remove(protected));
+    }
+
+    #[test]
+    fn test_remove_is_idempotent_across_tick_cleanup() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert!(manager.remove(id));
+
+        // Ticking after a manual removal must not resurrect or re-remove it
+        manager.tick(Duration::from_secs(10));
+        assert!(!manager.remove(id));
+        assert!(!manager.has_notification());
+    }
+
+    #[test]
+    fn test_animated_icon_advances_between_renders() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let frames = vec!["◐".to_string(), "◓".to_string(), "◑".to_string()];
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Syncing")
+                    .title("Sync")
+                    .animated_icon(frames, Duration::from_millis(100))
+                    .animation(ratatui_notifications::notifications::Animation::Fade)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        // 300ms elapsed: a full cycle of three glyphs, back to the first
+        manager.tick(Duration::from_millis(300));
+        let first = render_to_lines(&mut manager).join("\n");
+        assert!(first.contains('◐'));
+        assert!(!first.contains('◓'));
+
+        manager.tick(Duration::from_millis(100));
+        let second = render_to_lines(&mut manager).join("\n");
+        assert!(second.contains('◓'));
+        assert!(!second.contains('◐'));
+    }
+
+    #[test]
+    fn test_animated_icon_shown_without_title() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Working")
+                    .animated_icon(vec!["⚙".to_string()], Duration::from_millis(100))
+                    .animation(ratatui_notifications::notifications::Animation::Fade)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        manager.tick(Duration::from_secs(1));
+        assert!(render_to_lines(&mut manager).join("\n").contains('⚙'));
+    }
+
+    // Adds wide TopLeft and TopCenter notifications whose boxes overlap on an
+    // 80-column screen, then returns the char drawn in the overlapping region
+    fn overlap_winner(order: Vec<Anchor>) -> char {
+        use ratatui_notifications::notifications::{Animation, Notifications, SizeConstraint};
+
+        let mut manager = Notifications::new().anchor_z_order(order);
+        for (anchor, fill) in [(Anchor::TopLeft, "L"), (Anchor::TopCenter, "C")] {
+            manager
+                .add(
+                    NotificationBuilder::new(fill.repeat(56))
+                        .anchor(anchor)
+                        .animation(Animation::Fade)
+                        .max_size(SizeConstraint::Absolute(60), SizeConstraint::Absolute(5))
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+
+        let lines = render_to_lines(&mut manager);
+        let content_row = lines
+            .iter()
+            .find(|line| line.contains('L') || line.contains('C'))
+            .unwrap();
+        content_row.chars().nth(40).unwrap()
+    }
+
+    #[test]
+    fn test_anchor_z_order_last_listed_draws_on_top() {
+        assert_eq!(overlap_winner(vec![Anchor::TopLeft, Anchor::TopCenter]), 'C');
+        assert_eq!(overlap_winner(vec![Anchor::TopCenter, Anchor::TopLeft]), 'L');
+    }
+
+    #[test]
+    fn test_anchor_z_order_listed_anchor_beats_unlisted() {
+        assert_eq!(overlap_winner(vec![Anchor::TopLeft]), 'L');
+        assert_eq!(overlap_winner(vec![Anchor::TopCenter]), 'C');
+    }
+
+    #[test]
+    fn test_precompute_populates_layout_at_add() {
+        use ratatui::layout::Rect;
+        use ratatui_notifications::notifications::{calculate_size, Notifications};
+
+        let area = Rect::new(0, 0, 100, 30);
+        let mut manager = Notifications::new().precompute(true).precompute_area(area);
+        let notification = NotificationBuilder::new("Expensive report ".repeat(10))
+            .title("Report")
+            .build()
+            .unwrap();
+        let expected = calculate_size(&notification, area);
+
+        let id = manager.add(notification).unwrap();
+
+        assert_eq!(manager.precomputed_size(id), Some(expected));
+
+        // Already wrapped to fit inside the border
+        let lines = manager.precomputed_lines(id).unwrap();
+        assert!(lines.lines.len() > 1);
+        assert!(lines.lines.iter().all(|line| line.width() <= usize::from(expected.0 - 2)));
+        assert!(lines.lines[0].to_string().starts_with("Expensive report"));
+    }
+
+    #[test]
+    fn test_precompute_off_leaves_layout_lazy() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_eq!(manager.precomputed_size(id), None);
+        assert_eq!(manager.precomputed_lines(id), None);
+    }
+
+    #[test]
+    fn test_precompute_rewraps_when_render_width_differs() {
+        use ratatui::layout::Rect;
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        // Cached at 200 columns, rendered at 80: the box must fit the 80-column frame
+        let mut manager = Notifications::new()
+            .precompute(true)
+            .precompute_area(Rect::new(0, 0, 200, 24));
+        manager
+            .add(
+                NotificationBuilder::new("W".repeat(70))
+                    .anchor(Anchor::TopLeft)
+                    .animation(Animation::Fade)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let lines = render_to_lines(&mut manager);
+        let top_border = lines.iter().find(|line| line.contains('╭')).unwrap();
+        // 40% of 80 columns
+        assert_eq!(top_border.trim().chars().count(), 32);
+    }
+
+    #[test]
+    fn test_new_badge_shown_only_during_entry() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Release notes are available")
+                    .title("Update")
+                    .animation(Animation::Fade)
+                    .new_badge(true)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        // Halfway through the default 500ms entry
+        manager.tick(Duration::from_millis(250));
+        assert!(render_to_lines(&mut manager).join("\n").contains("NEW"));
+
+        // Entry complete, now dwelling
+        manager.tick(Duration::from_millis(300));
+        assert!(!render_to_lines(&mut manager).join("\n").contains("NEW"));
+    }
+
+    #[test]
+    fn test_new_badge_off_by_default() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Release notes are available")
+                    .animation(Animation::Fade)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        manager.tick(Duration::from_millis(250));
+        assert!(!render_to_lines(&mut manager).join("\n").contains("NEW"));
+    }
+
+    #[test]
+    fn test_find_by_returns_matching_ids_in_order() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        let err_a = manager
+            .add(NotificationBuilder::new("a").level(Level::Error).build().unwrap())
+            .unwrap();
+        manager
+            .add(NotificationBuilder::new("b").level(Level::Info).build().unwrap())
+            .unwrap();
+        let err_c = manager
+            .add(NotificationBuilder::new("c").level(Level::Error).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+
+        assert_eq!(manager.find_by(|n| n.level() == Some(Level::Error)), vec![err_a, err_c]);
+        assert_eq!(manager.find_by(|n| n.anchor() == Anchor::TopLeft), vec![err_c]);
+        assert!(manager.find_by(|n| n.level() == Some(Level::Warn)).is_empty());
+    }
+
+    #[test]
+    fn test_by_kind_returns_notifications_of_that_kind() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let upload = |text: &'static str| NotificationBuilder::new(text).kind("upload").build().unwrap();
+        let mut manager = Notifications::new();
+        let leaving = manager.add(upload("a.txt")).unwrap();
+        manager.add(NotificationBuilder::new("Saved").kind("save").build().unwrap()).unwrap();
+        manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        // One still exiting, one showing
+        manager.dismiss(leaving);
+        let showing = manager.add(upload("b.txt")).unwrap();
+
+        assert_eq!(manager.by_kind("upload"), vec![leaving, showing]);
+        assert_eq!(manager.by_kind("save").len(), 1);
+        assert!(manager.by_kind("missing").is_empty());
+    }
+
+    #[test]
+    fn test_find_by_supports_bulk_removal() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        let keep = manager
+            .add(NotificationBuilder::new("keep").level(Level::Info).build().unwrap())
+            .unwrap();
+        for _ in 0..3 {
+            manager
+                .add(NotificationBuilder::new("drop").level(Level::Debug).build().unwrap())
+                .unwrap();
+        }
+
+        for id in manager.find_by(|n| n.level() == Some(Level::Debug)) {
+            assert!(manager.remove(id));
+        }
+
+        assert_eq!(manager.find_by(|_| true), vec![keep]);
+    }
+
+    #[test]
+    fn test_debug_stats_track_known_sequence() {
+        use ratatui_notifications::notifications::{DebugStats, Notifications};
+
+        let mut manager = Notifications::new().max_visible(Anchor::BottomRight, 1);
+        assert_eq!(manager.debug_stats(), DebugStats::default());
+
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.tick(Duration::from_millis(16));
+
+        assert_eq!(
+            manager.debug_stats(),
+            DebugStats { states: 3, queued: 1, anchor_entries: 3, ids_issued: 3 }
+        );
+
+        // Removing frees the slot; ids issued never goes down
+        manager.remove(first);
+        manager.tick(Duration::from_millis(16));
+
+        assert_eq!(
+            manager.debug_stats(),
+            DebugStats { states: 2, queued: 0, anchor_entries: 2, ids_issued: 3 }
+        );
+
+        manager.force_clear();
+        assert_eq!(manager.debug_stats().states, 0);
+        assert_eq!(manager.debug_stats().ids_issued, 3);
+    }
+
+    #[test]
+    fn test_default_anchor_applies_to_unanchored_notifications() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().default_anchor(Anchor::TopLeft);
+        let unanchored = manager
+            .add(NotificationBuilder::new("No anchor").build().unwrap())
+            .unwrap();
+        let explicit = manager
+            .add(create_test_notification(Anchor::BottomRight))
+            .unwrap();
+
+        assert_eq!(manager.find_by(|n| n.anchor() == Anchor::TopLeft), vec![unanchored]);
+        assert_eq!(manager.find_by(|n| n.anchor() == Anchor::BottomRight), vec![explicit]);
+    }
+
+    #[test]
+    fn test_default_anchor_renders_at_configured_corner() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().default_anchor(Anchor::TopLeft);
+        manager
+            .add(NotificationBuilder::new("Here").animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let lines = render_to_lines(&mut manager);
+        let row = lines.iter().position(|line| line.contains("Here")).unwrap();
+        assert!(row < 12);
+        assert!(lines[row].find("Here").unwrap() < 40);
+    }
+
+    #[test]
+    fn test_hidden_notification_expires_on_schedule_without_drawing() {
+        use ratatui_notifications::notifications::{Animation, AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        let hidden = manager
+            .add(
+                NotificationBuilder::new("Secret")
+                    .animation(Animation::Fade)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(2)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert!(manager.set_visible(hidden, false));
+
+        // Entry (500ms) + dwell (2s) + exit (750ms): alive while hidden at 2.5s...
+        for _ in 0..25 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(!render_to_lines(&mut manager).join("\n").contains("Secret"));
+        assert!(manager.has_notification());
+
+        // ...and gone once the full schedule has elapsed
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(!manager.has_notification());
+        assert!(!manager.set_visible(hidden, true));
+
+        let shown = manager
+            .add(NotificationBuilder::new("Visible").animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+        assert!(manager.set_visible(shown, true));
+        assert!(render_to_lines(&mut manager).join("\n").contains("Visible"));
+    }
+
+    #[test]
+    fn test_set_visible_toggles_drawing() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        let id = manager
+            .add(NotificationBuilder::new("Toggle").animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        manager.set_visible(id, false);
+        assert!(!render_to_lines(&mut manager).join("\n").contains("Toggle"));
+
+        manager.set_visible(id, true);
+        assert!(render_to_lines(&mut manager).join("\n").contains("Toggle"));
+    }
+
+    // Returns the (col, row) where `needle` is drawn
+    fn locate(lines: &[String], needle: &str) -> (u16, u16) {
+        lines
+            .iter()
+            .enumerate()
+            .find_map(|(row, line)| {
+                line.find(needle)
+                    .map(|byte| (line[..byte].chars().count() as u16, row as u16))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hit_resolves_notification_under_point() {
+        use ratatui_notifications::notifications::{Animation, Hit, HitRegion, Notifications};
+
+        let mut manager = Notifications::new();
+        let top = manager
+            .add(NotificationBuilder::new("Top").anchor(Anchor::TopLeft).animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        let bottom = manager
+            .add(NotificationBuilder::new("Bottom").animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        // Nothing drawn yet
+        assert_eq!(manager.hit(0, 0), None);
+
+        let lines = render_to_lines(&mut manager);
+        let (col, row) = locate(&lines, "Top");
+        assert_eq!(manager.hit(col, row), Some(Hit { id: top, region: HitRegion::Body }));
+        // Border cells count as the body too
+        assert_eq!(manager.hit(col - 2, row - 1).map(|hit| hit.id), Some(top));
+
+        let (col, row) = locate(&lines, "Bottom");
+        assert_eq!(manager.hit(col, row).map(|hit| hit.id), Some(bottom));
+
+        assert_eq!(manager.hit(40, 12), None);
+
+        manager.remove(top);
+        let (col, row) = locate(&lines, "Top");
+        assert_eq!(manager.hit(col, row), None);
+    }
+
+    #[test]
+    fn test_hit_prefers_notification_drawn_on_top() {
+        use ratatui_notifications::notifications::{Animation, Notifications, SizeConstraint};
+
+        let mut manager = Notifications::new().anchor_z_order(vec![Anchor::TopLeft, Anchor::TopCenter]);
+        let mut ids = Vec::new();
+        for anchor in [Anchor::TopLeft, Anchor::TopCenter] {
+            ids.push(
+                manager
+                    .add(
+                        NotificationBuilder::new("X".repeat(56))
+                            .anchor(anchor)
+                            .animation(Animation::Fade)
+                            .max_size(SizeConstraint::Absolute(60), SizeConstraint::Absolute(5))
+                            .build()
+                            .unwrap(),
+                    )
+                    .unwrap(),
+            );
+        }
+        manager.tick(Duration::from_secs(1));
+        render_to_lines(&mut manager);
+
+        assert_eq!(manager.hit(40, 2).map(|hit| hit.id), Some(ids[1]));
+        assert_eq!(manager.hit(3, 2).map(|hit| hit.id), Some(ids[0]));
+    }
+
+    #[test]
+    fn test_click_on_exiting_notification_is_ignored_by_default() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::{Animation, AnimationPhase, Notifications};
+
+        for hit_animating in [false, true] {
+            let mut manager = Notifications::new().hit_animating(hit_animating);
+            let id = manager
+                .add(NotificationBuilder::new("Leaving").anchor(Anchor::TopLeft).animation(Animation::Fade).build().unwrap())
+                .unwrap();
+            manager.tick(Duration::from_secs(1));
+            manager.dismiss(id);
+            manager.tick(Duration::from_millis(100));
+            let (col, row) = locate(&render_to_lines(&mut manager), "Leaving");
+            assert_eq!(manager.iter().next().map(|(_, _, state)| state.phase()), Some(AnimationPhase::FadingOut));
+
+            let click = manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), (col, row)));
+            if hit_animating {
+                assert_eq!(click, Some(id));
+            } else {
+                assert_eq!(click, None, "mid-exit clicks are ignored");
+                assert_eq!(manager.notification_at(col, row), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_accessible_text_lists_active_notifications_in_order() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(NotificationBuilder::new("Disk full").level(Level::Error).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        let hidden = manager
+            .add(NotificationBuilder::new("Internal").level(Level::Debug).build().unwrap())
+            .unwrap();
+        manager
+            .add(NotificationBuilder::new("Saved").title("Backup").level(Level::Info).build().unwrap())
+            .unwrap();
+        manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+        manager.set_visible(hidden, false);
+
+        assert_eq!(
+            manager.accessible_text(),
+            vec!["Error: Disk full", "Info: Backup - Saved", "Info: Plain"]
+        );
+    }
+
+    #[test]
+    fn test_dismiss_guard_vetoes_errors_while_info_expires() {
+        use ratatui_notifications::notifications::{AutoDismiss, Level, Notifications};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let consulted = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&consulted);
+
+        let mut manager = Notifications::new();
+        manager.set_dismiss_guard(Box::new(move |n| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            n.level() != Some(Level::Error)
+        }));
+
+        let error = manager
+            .add(
+                NotificationBuilder::new("Disk full")
+                    .level(Level::Error)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager
+            .add(
+                NotificationBuilder::new("Saved")
+                    .level(Level::Info)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        for _ in 0..50 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert_eq!(manager.find_by(|_| true), vec![error]);
+        // Info once, then the error on every expiry of its restarted timer
+        assert!(consulted.load(Ordering::SeqCst) >= 3);
+    }
+
+    #[test]
+    fn test_without_dismiss_guard_everything_expires() {
+        use ratatui_notifications::notifications::{AutoDismiss, Level, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Disk full")
+                    .level(Level::Error)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        for _ in 0..50 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert!(!manager.has_notification());
+    }
+
+    // Returns the foreground colour of the first cell of `needle`
+    fn fg_of(manager: &mut ratatui_notifications::notifications::Notifications, needle: &str) -> ratatui::style::Color {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let lines: Vec<String> = (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let (col, row) = locate(&lines, needle);
+        buffer[(col, row)].fg
+    }
+
+    fn brightness(color: ratatui::style::Color) -> u32 {
+        match color {
+            ratatui::style::Color::Rgb(r, g, b) => r as u32 + g as u32 + b as u32,
+            other => panic!("expected an RGB colour, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_depth_fade_dims_older_notifications_in_stack() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().depth_fade(0.3);
+        for text in ["Oldest", "Middle", "Newest"] {
+            manager
+                .add(
+                    NotificationBuilder::new(text)
+                        .anchor(Anchor::TopLeft)
+                        .animation(Animation::Fade)
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+
+        let newest = brightness(fg_of(&mut manager, "Newest"));
+        let middle = brightness(fg_of(&mut manager, "Middle"));
+        let third = brightness(fg_of(&mut manager, "Oldest"));
+
+        assert!(newest > middle);
+        assert!(middle > third);
+    }
+
+    #[test]
+    fn test_depth_fade_stops_short_of_invisible() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().depth_fade(0.5);
+        for text in ["Oldest", "Older", "Newest"] {
+            manager
+                .add(NotificationBuilder::new(text).anchor(Anchor::TopLeft).animation(Animation::Fade).build().unwrap())
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+
+        // Two steps of 0.5 would blend fully into the background
+        let oldest = brightness(fg_of(&mut manager, "Oldest"));
+        let older = brightness(fg_of(&mut manager, "Older"));
+        assert!(oldest > 0);
+        assert!(oldest < older);
+    }
+
+    #[test]
+    fn test_depth_fade_off_keeps_stack_uniform() {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new();
+        for text in ["Oldest", "Newest"] {
+            manager
+                .add(NotificationBuilder::new(text).anchor(Anchor::TopLeft).animation(Animation::Fade).build().unwrap())
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+
+        assert_eq!(fg_of(&mut manager, "Oldest"), fg_of(&mut manager, "Newest"));
+    }
+
+    fn deep_stack(manager: &mut ratatui_notifications::notifications::Notifications, anchor: Anchor) {
+        use ratatui_notifications::notifications::Animation;
+
+        for text in ["Message 1", "Message 2", "Message 3", "Message 4"] {
+            manager
+                .add(NotificationBuilder::new(text).anchor(anchor).animation(Animation::Fade).build().unwrap())
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_collapse_faded_folds_faintest_into_summary() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().depth_fade(0.25).collapse_faded(0.5);
+        deep_stack(&mut manager, Anchor::BottomRight);
+
+        let lines = render_to_lines(&mut manager);
+        let text = lines.join("\n");
+
+        assert!(!text.contains("Message 1"));
+        let (_, summary_row) = locate(&lines, "+1 more");
+        let (_, farthest_row) = locate(&lines, "Message 2");
+        let (_, newest_row) = locate(&lines, "Message 4");
+        assert!(summary_row < farthest_row);
+        assert!(farthest_row < newest_row);
+    }
+
+    #[test]
+    fn test_collapse_faded_summary_follows_stack_direction() {
+        use ratatui_notifications::notifications::{Notifications, StackDirection};
+
+        // A top anchor growing up rests on the bottom edge; the far edge is on top
+        let mut manager = Notifications::new()
+            .depth_fade(0.25)
+            .collapse_faded(0.5)
+            .stack_direction(Anchor::TopRight, StackDirection::Up);
+        deep_stack(&mut manager, Anchor::TopRight);
+
+        let lines = render_to_lines(&mut manager);
+        let (_, summary_row) = locate(&lines, "+1 more");
+        let (_, farthest_row) = locate(&lines, "Message 2");
+        assert_eq!(summary_row + 1, farthest_row, "{lines:#?}");
+
+        // A bottom anchor growing down hangs from the top edge, newest farthest down
+        let mut manager = Notifications::new()
+            .depth_fade(0.25)
+            .collapse_faded(0.5)
+            .stack_direction(Anchor::BottomLeft, StackDirection::Down);
+        deep_stack(&mut manager, Anchor::BottomLeft);
+
+        let lines = render_to_lines(&mut manager);
+        let (_, summary_row) = locate(&lines, "+1 more");
+        let (_, farthest_row) = locate(&lines, "Message 4");
+        assert_eq!(summary_row, farthest_row + 1, "{lines:#?}");
+    }
+
+    #[test]
+    fn test_collapse_faded_without_depth_fade_keeps_all() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().collapse_faded(0.5);
+        deep_stack(&mut manager, Anchor::TopLeft);
+
+        let text = render_to_lines(&mut manager).join("\n");
+
+        assert!(text.contains("Message 1"));
+        assert!(!text.contains("more"));
+    }
+
+    #[test]
+    fn test_batch_avoids_eviction_a_later_remove_prevents() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let setup = || {
+            let mut manager = Notifications::new().max_concurrent(Some(2));
+            let stale = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            let keep = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            (manager, stale, keep)
+        };
+
+        // Sequential: the add evicts `stale` immediately, then the remove
+        // empties a second slot, leaving a single notification
+        let (mut sequential, stale, keep) = setup();
+        let fresh = sequential.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        sequential.remove(keep);
+        assert!(!sequential.remove(stale));
+        assert_eq!(sequential.find_by(|_| true), vec![fresh]);
+
+        // Batched: the same operations, but the remove makes room so nothing is evicted
+        let (mut batched, stale, keep) = setup();
+        let fresh = batched.batch(|m| {
+            let fresh = m.add(create_test_notification(Anchor::BottomRight)).unwrap();
+            m.remove(keep);
+            fresh
+        });
+        assert_eq!(batched.find_by(|_| true), vec![stale, fresh]);
+    }
+
+    #[test]
+    fn test_batch_enforces_limit_once_at_end() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(2));
+        let ids = manager.batch(|m| {
+            (0..4)
+                .map(|_| m.add(create_test_notification(Anchor::TopLeft)).unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        // All four coexist during the batch; the two oldest go afterwards
+        assert_eq!(manager.find_by(|_| true), ids[2..].to_vec());
+    }
+
+    fn mouse(kind: crossterm::event::MouseEventKind, (column, row): (u16, u16)) -> crossterm::event::MouseEvent {
+        crossterm::event::MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    // Two rendered notifications, returning the manager, both ids and a point inside each
+    fn clickable_pair(
+        trigger: ratatui_notifications::notifications::ClickTrigger,
+    ) -> (ratatui_notifications::notifications::Notifications, [u64; 2], [(u16, u16); 2]) {
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().click_trigger(trigger);
+        let left = manager
+            .add(NotificationBuilder::new("Left").anchor(Anchor::TopLeft).animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        let right = manager
+            .add(NotificationBuilder::new("Right").anchor(Anchor::TopRight).animation(Animation::Fade).build().unwrap())
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        let lines = render_to_lines(&mut manager);
+        let points = [locate(&lines, "Left"), locate(&lines, "Right")];
+        (manager, [left, right], points)
+    }
+
+    #[test]
+    fn test_click_release_on_other_notification_does_not_dismiss() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::ClickTrigger;
+
+        let (mut manager, ids, points) = clickable_pair(ClickTrigger::Release);
+
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), points[0])), None);
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Up(MouseButton::Left), points[1])), None);
+
+        manager.tick(Duration::from_secs(1));
+        assert_eq!(manager.find_by(|_| true), ids.to_vec());
+    }
+
+    #[test]
+    fn test_click_press_and_release_on_same_notification_dismisses() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::ClickTrigger;
+
+        let (mut manager, ids, points) = clickable_pair(ClickTrigger::Release);
+
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), points[0])), None);
+        assert_eq!(
+            manager.handle_click(&mouse(MouseEventKind::Up(MouseButton::Left), points[0])),
+            Some(ids[0])
+        );
+
+        // Exit animation plays out, then it is gone
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.find_by(|_| true), vec![ids[1]]);
+    }
+
+    #[test]
+    fn test_click_press_trigger_dismisses_on_down() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::ClickTrigger;
+
+        let (mut manager, ids, points) = clickable_pair(ClickTrigger::Press);
+
+        assert_eq!(
+            manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), points[1])),
+            Some(ids[1])
+        );
+        // Releases and other buttons do nothing in press mode
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Up(MouseButton::Left), points[0])), None);
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Right), points[0])), None);
+    }
+
+    fn severity_scored_manager() -> ratatui_notifications::notifications::Notifications {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        Notifications::new()
+            .max_concurrent(Some(3))
+            .overflow(Overflow::Scored)
+            .overflow_scorer(Box::new(|n| match n.level() {
+                Some(Level::Error) => 100,
+                Some(Level::Warn) => 50,
+                _ => 0,
+            }))
+    }
+
+    #[test]
+    fn test_scored_overflow_evicts_lowest_score() {
+        use ratatui_notifications::notifications::Level;
+
+        let mut manager = severity_scored_manager();
+        let error = manager.add(NotificationBuilder::new("e").level(Level::Error).build().unwrap()).unwrap();
+        let info = manager.add(NotificationBuilder::new("i").level(Level::Info).build().unwrap()).unwrap();
+        let warn = manager.add(NotificationBuilder::new("w").level(Level::Warn).build().unwrap()).unwrap();
+
+        // Full: the info is the least valuable even though the error is older
+        let newcomer = manager.add(NotificationBuilder::new("n").level(Level::Warn).build().unwrap()).unwrap();
+
+        assert_eq!(manager.find_by(|_| true), vec![error, warn, newcomer]);
+        assert!(!manager.remove(info));
+    }
+
+    #[test]
+    fn test_scored_overflow_ties_go_to_oldest() {
+        use ratatui_notifications::notifications::Level;
+
+        let mut manager = severity_scored_manager();
+        let first = manager.add(NotificationBuilder::new("a").level(Level::Warn).build().unwrap()).unwrap();
+        let second = manager.add(NotificationBuilder::new("b").level(Level::Warn).build().unwrap()).unwrap();
+        let third = manager.add(NotificationBuilder::new("c").level(Level::Warn).build().unwrap()).unwrap();
+        let fourth = manager.add(NotificationBuilder::new("d").level(Level::Error).build().unwrap()).unwrap();
+
+        assert_eq!(manager.find_by(|_| true), vec![second, third, fourth]);
+        assert!(!manager.remove(first));
+    }
+
+    #[test]
+    fn test_scored_overflow_without_scorer_discards_oldest() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_concurrent(Some(1)).overflow(Overflow::Scored);
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let newest = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_eq!(manager.find_by(|_| true), vec![newest]);
+    }
+
+    #[test]
+    fn test_time_color_shifts_rendered_foreground_as_time_runs_out() {
+        use ratatui::style::Color;
+        use ratatui_notifications::notifications::{Animation, AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Hurry")
+                    .animation(Animation::Fade)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(4)))
+                    .time_color(Color::Rgb(0, 255, 0), Color::Rgb(255, 0, 0))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        // Entry done, nearly all display time left: green
+        for _ in 0..5 {
+            manager.tick(Duration::from_millis(100));
+        }
+        match fg_of(&mut manager, "Hurry") {
+            Color::Rgb(r, g, _) => assert!(g > 230 && r < 25, "expected green, got ({}, {})", r, g),
+            other => panic!("expected RGB, got {:?}", other),
+        }
+
+        // 50ms of display time left: red
+        manager.tick(Duration::from_millis(3850));
+        match fg_of(&mut manager, "Hurry") {
+            Color::Rgb(r, g, _) => assert!(r > 250 && g < 5, "expected red, got ({}, {})", r, g),
+            other => panic!("expected RGB, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_render_disabled_draws_nothing_while_timers_run() {
+        use ratatui_notifications::notifications::{Animation, AutoDismiss, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Gated")
+                    .animation(Animation::Fade)
+                    .auto_dismiss(AutoDismiss::After(Duration::from_secs(2)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_millis(100));
+        manager.render_enabled(false);
+
+        for _ in 0..20 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(render_to_lines(&mut manager).iter().all(|line| line.trim().is_empty()));
+        assert!(manager.has_notification());
+
+        // Re-enabled mid-life it draws again...
+        manager.render_enabled(true);
+        assert!(render_to_lines(&mut manager).join("\n").contains("Gated"));
+
+        // ...and it expired on its original schedule regardless
+        manager.render_enabled(false);
+        for _ in 0..15 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(!manager.has_notification());
+    }
+
+    #[test]
+    fn test_dismiss_when_persists_until_flag_flips() {
+        use ratatui_notifications::notifications::{AutoDismiss, Notifications};
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let online = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&online);
+
+        let mut manager = Notifications::new();
+        let banner = manager
+            .add(
+                NotificationBuilder::new("Offline")
+                    .auto_dismiss(AutoDismiss::Never)
+                    .dismiss_when(Box::new(move || flag.load(Ordering::SeqCst)))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.find_by(|_| true), vec![banner]);
+
+        online.store(true, Ordering::SeqCst);
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(!manager.has_notification());
+    }
+
+    // Returns the background colour of the first cell of `needle`
+    fn bg_of(manager: &mut ratatui_notifications::notifications::Notifications, needle: &str) -> ratatui::style::Color {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let lines: Vec<String> = (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let (col, row) = locate(&lines, needle);
+        buffer[(col, row)].bg
+    }
+
+    #[test]
+    fn test_anchor_style_applies_only_at_its_anchor() {
+        use ratatui::style::{Color, Style};
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().anchor_style(Anchor::BottomCenter, Style::new().bg(Color::Blue));
+        for (text, anchor) in [("Styled", Anchor::BottomCenter), ("Plain", Anchor::TopRight)] {
+            manager
+                .add(NotificationBuilder::new(text).anchor(anchor).animation(Animation::Fade).build().unwrap())
+                .unwrap();
+        }
+        manager.tick(Duration::from_secs(1));
+
+        assert_eq!(bg_of(&mut manager, "Styled"), Color::Blue);
+        assert_ne!(bg_of(&mut manager, "Plain"), Color::Blue);
+    }
+
+    #[test]
+    fn test_notification_style_overrides_anchor_style() {
+        use ratatui::style::{Color, Style};
+        use ratatui_notifications::notifications::{Animation, Notifications};
+
+        let mut manager = Notifications::new().anchor_style(Anchor::BottomCenter, Style::new().bg(Color::Blue));
+        manager
+            .add(
+                NotificationBuilder::new("Own")
+                    .anchor(Anchor::BottomCenter)
+                    .animation(Animation::Fade)
+                    .style(Style::new().bg(Color::Red))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+
+        assert_eq!(bg_of(&mut manager, "Own"), Color::Red);
+    }
+
+    #[test]
+    fn test_replay_reproduces_rendered_output() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut recorded = Notifications::new();
+        recorded.start_recording();
+        let first = recorded.add(create_test_notification(Anchor::TopRight)).unwrap();
+        for _ in 0..3 {
+            recorded.tick(Duration::from_millis(100));
+        }
+        recorded.add(create_test_notification(Anchor::BottomLeft)).unwrap();
+        recorded.tick(Duration::from_millis(100));
+        recorded.remove(first);
+        recorded.tick(Duration::from_millis(100));
+        let recording = recorded.stop_recording();
+
+        let mut replayed = Notifications::new();
+        replayed.replay(&recording).unwrap();
+
+        assert_eq!(recording.duration(), Duration::from_millis(500));
+        assert_eq!(render_to_lines(&mut replayed), render_to_lines(&mut recorded));
+    }
+
+    #[test]
+    fn test_replay_maps_recorded_ids() {
+        use ratatui_notifications::notifications::Notifications;
+
+        // Start recording mid-session so recorded IDs don't start at zero
+        let mut recorded = Notifications::new();
+        recorded.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        recorded.start_recording();
+        let id = recorded.add(create_test_notification(Anchor::TopRight)).unwrap();
+        recorded.tick(Duration::from_millis(100));
+        recorded.remove(id);
+        let recording = recorded.stop_recording();
+
+        let mut replayed = Notifications::new();
+        replayed.replay(&recording).unwrap();
+
+        assert!(!replayed.has_notification());
+    }
+
+    #[test]
+    fn test_replay_applies_updates() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut recorded = Notifications::new();
+        recorded.start_recording();
+        let id = recorded.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        recorded.tick(Duration::from_secs(1));
+        recorded.update(id, NotificationBuilder::new("Updated").build().unwrap());
+        let recording = recorded.stop_recording();
+
+        let mut replayed = Notifications::new();
+        replayed.replay(&recording).unwrap();
+
+        assert_eq!(replayed.find_by(|n| n.content().to_string() == "Updated").len(), 1);
+    }
+
+    #[test]
+    fn test_replay_applies_clear_anchor() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut recorded = Notifications::new();
+        recorded.start_recording();
+        recorded.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        recorded.add(create_test_notification(Anchor::BottomLeft)).unwrap();
+        recorded.clear_anchor(Anchor::TopLeft);
+        let recording = recorded.stop_recording();
+
+        let mut replayed = Notifications::new();
+        replayed.replay(&recording).unwrap();
+        assert_eq!((replayed.count_at(Anchor::TopLeft), replayed.count_at(Anchor::BottomLeft)), (0, 1));
+    }
+
+    #[test]
+    fn test_recording_skips_internal_removals() {
+        use ratatui_notifications::notifications::{Notifications, RecordedEvent};
+
+        let mut manager = Notifications::new().max_concurrent(Some(1));
+        manager.start_recording();
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let recording = manager.stop_recording();
+
+        assert_eq!(recording.len(), 2);
+        assert!(recording.events().iter().all(|(_, event)| matches!(event, RecordedEvent::Add(..))));
+        assert!(manager.stop_recording().is_empty());
+    }
+
+    fn wide_notification() -> Notification {
+        NotificationBuilder::new("A fairly long message that needs most of a wide terminal to sit on one line")
+            .anchor(Anchor::TopLeft)
+            .animation(ratatui_notifications::notifications::Animation::Fade)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_on_resize_refits_to_smaller_terminal() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut resized = Notifications::new();
+        resized.add(wide_notification()).unwrap();
+        resized.tick(Duration::from_secs(1));
+        render_lines_at(&mut resized, 100, 24);
+        resized.on_resize(40, 12);
+
+        // A manager that only ever saw the small terminal lays out the same way
+        let mut fresh = Notifications::new();
+        fresh.add(wide_notification()).unwrap();
+        fresh.tick(Duration::from_secs(1));
+
+        let lines = render_lines_at(&mut resized, 40, 12);
+        assert_eq!(lines, render_lines_at(&mut fresh, 40, 12));
+        assert!(lines.iter().any(|line| line.contains('╮') || line.contains('┐')));
+    }
+
+    #[test]
+    fn test_on_resize_drops_stale_hit_targets() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.tick(Duration::from_secs(1));
+        let lines = render_to_lines(&mut manager);
+        let (col, row) = locate(&lines, "Test");
+        assert!(manager.hit(col, row).is_some());
+
+        manager.on_resize(40, 12);
+        assert!(manager.hit(col, row).is_none());
+    }
+
+    #[test]
+    fn test_on_resize_remeasures_precomputed_layouts() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().precompute(true).precompute_area(ratatui::layout::Rect::new(0, 0, 100, 24));
+        let id = manager.add(wide_notification()).unwrap();
+        let (wide, _) = manager.precomputed_size(id).unwrap();
+
+        manager.on_resize(40, 12);
+        let (narrow, _) = manager.precomputed_size(id).unwrap();
+        assert!(narrow < wide);
+        assert!(narrow <= 40);
+    }
+
+    #[test]
+    fn test_point_at_draws_connector_to_target() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let (_, row) = locate(&render_to_lines(&mut manager), "Test");
+        assert!(manager.point_at(id, 60, row));
+
+        let lines = render_to_lines(&mut manager);
+        let line: Vec<char> = lines[row as usize].chars().collect();
+        let box_right = line.iter().rposition(|&c| c == '│').unwrap();
+
+        assert!(line[box_right + 1..60].iter().all(|&c| c == '─'));
+        assert_eq!(line[60], ' ');
+    }
+
+    #[test]
+    fn test_point_at_bends_toward_diagonal_target() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager.point_at(id, 50, 20);
+
+        let lines = render_to_lines(&mut manager);
+        let (right_col, _) = locate(&lines, "╮");
+        let row: Vec<char> = lines[20].chars().collect();
+
+        // Down from the box's right column, bend on the target row, then across
+        assert_eq!(lines[19].chars().nth(right_col as usize), Some('│'));
+        assert_eq!(row[right_col as usize], '╰');
+        assert!(row[right_col as usize + 1..50].iter().all(|&c| c == '─'));
+        assert_eq!(row[50], ' ');
+    }
+
+    #[test]
+    fn test_point_at_unknown_id() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert!(!manager.point_at(42, 0, 0));
+    }
+
+    #[test]
+    fn test_best_anchor_avoiding_top_right_region() {
+        use ratatui::layout::Rect;
+        use ratatui_notifications::notifications::Notifications;
+
+        let manager = Notifications::new();
+        let anchor = manager.best_anchor_avoiding(Rect::new(50, 0, 30, 8), Rect::new(0, 0, 80, 24));
+        assert_eq!(anchor, Anchor::BottomLeft);
+    }
+
+    #[test]
+    fn test_best_anchor_avoiding_left_half() {
+        use ratatui::layout::Rect;
+        use ratatui_notifications::notifications::Notifications;
+
+        let manager = Notifications::new();
+        let anchor = manager.best_anchor_avoiding(Rect::new(0, 0, 40, 24), Rect::new(0, 0, 80, 24));
+        assert!(matches!(anchor, Anchor::TopRight | Anchor::MiddleRight | Anchor::BottomRight));
+    }
+
+    #[test]
+    fn test_best_anchor_avoiding_accounts_for_existing_stack() {
+        use ratatui::layout::Rect;
+        use ratatui_notifications::notifications::Notifications;
+
+        let area = Rect::new(0, 0, 80, 24);
+        let top_half = Rect::new(0, 0, 80, 12);
+
+        let mut manager = Notifications::new();
+        assert_eq!(manager.best_anchor_avoiding(top_half, area), Anchor::BottomLeft);
+
+        // A tall stack at BottomLeft now reaches into the top half
+        for _ in 0..6 {
+            manager.add(create_test_notification(Anchor::BottomLeft)).unwrap();
+        }
+        manager.tick(Duration::from_millis(100));
+        assert_eq!(manager.best_anchor_avoiding(top_half, area), Anchor::BottomRight);
+    }
+
+    // Stacks two notifications at TopLeft, removes the first, and returns the
+    // second's row before the removal and after `after` of reflow
+    fn reflow_rows(reflow: Option<(Duration, ratatui_notifications::notifications::Easing)>, after: Duration) -> (u16, u16) {
+        use ratatui_notifications::notifications::{Notifications, SizeConstraint};
+
+        // A tall leader so the follower has several rows to travel
+        let mut manager = Notifications::new().reflow(reflow);
+        let leader = NotificationBuilder::new("One\nTwo\nThree\nFour\nFive")
+            .anchor(Anchor::TopLeft)
+            .max_size(SizeConstraint::Percentage(0.4), SizeConstraint::Absolute(8))
+            .build()
+            .unwrap();
+        let first = manager.add(leader).unwrap();
+        manager
+            .add(NotificationBuilder::new("Follower").anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let (_, before) = locate(&render_to_lines(&mut manager), "Follower");
+
+        manager.remove(first);
+        render_to_lines(&mut manager);
+        manager.tick(after);
+        let (_, during) = locate(&render_to_lines(&mut manager), "Follower");
+        (before, during)
+    }
+
+    #[test]
+    fn test_reflow_disabled_snaps_to_new_position() {
+        let (before, _) = reflow_rows(None, Duration::ZERO);
+        let (_, snapped) = reflow_rows(None, Duration::from_millis(100));
+        assert!(snapped < before);
+    }
+
+    #[test]
+    fn test_reflow_glides_over_duration() {
+        use ratatui_notifications::notifications::Easing;
+
+        let reflow = Some((Duration::from_secs(1), Easing::Linear));
+        let (_, end) = reflow_rows(None, Duration::ZERO);
+        let (before, start) = reflow_rows(reflow, Duration::ZERO);
+        let (_, halfway) = reflow_rows(reflow, Duration::from_millis(500));
+        let (_, done) = reflow_rows(reflow, Duration::from_secs(1));
+
+        assert_eq!(start, before);
+        assert!(halfway < before && halfway > end);
+        assert_eq!(done, end);
+    }
+
+    #[test]
+    fn test_reflow_duration_and_easing_shape_positions() {
+        use ratatui_notifications::notifications::Easing;
+
+        let half = Duration::from_millis(500);
+        let (_, linear) = reflow_rows(Some((Duration::from_secs(1), Easing::Linear)), half);
+        let (_, ease_in) = reflow_rows(Some((Duration::from_secs(1), Easing::EaseInQuad)), half);
+        let (_, ease_out) = reflow_rows(Some((Duration::from_secs(1), Easing::EaseOutCubic)), half);
+        let (_, slower) = reflow_rows(Some((Duration::from_secs(4), Easing::Linear)), half);
+
+        // Rows shrink as the follower moves up: ease-in lags, ease-out leads
+        assert!(ease_in > linear);
+        assert!(ease_out < linear);
+        assert!(slower > linear);
+    }
+
+    #[test]
+    fn test_pin_top_keeps_notification_at_anchor() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let pinned = manager
+            .add(NotificationBuilder::new("Recording").anchor(Anchor::BottomRight).build().unwrap())
+            .unwrap();
+        assert!(manager.pin_top(pinned, true));
+        for text in ["Newer one", "Newest one"] {
+            manager.tick(Duration::from_millis(100));
+            manager.add(NotificationBuilder::new(text).anchor(Anchor::BottomRight).build().unwrap()).unwrap();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        // BottomRight stacks upward, so the anchor end is the lowest row
+        let lines = render_to_lines(&mut manager);
+        let (_, pinned_row) = locate(&lines, "Recording");
+        let (_, newest_row) = locate(&lines, "Newest one");
+        let (_, newer_row) = locate(&lines, "Newer one");
+        assert!(pinned_row > newest_row && newest_row > newer_row);
+
+        manager.pin_top(pinned, false);
+        let lines = render_to_lines(&mut manager);
+        assert!(locate(&lines, "Recording").1 < locate(&lines, "Newer one").1);
+    }
+
+    #[test]
+    fn test_pin_top_unknown_id() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert!(!manager.pin_top(7, true));
+    }
+
+    fn progress_notification(name: &'static str) -> Notification {
+        use ratatui_notifications::notifications::AutoDismiss;
+
+        NotificationBuilder::new(name)
+            .progress(0.0)
+            .auto_dismiss(AutoDismiss::Never)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_progress_draws_gauge_row() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(progress_notification("report.pdf")).unwrap();
+        manager.tick(Duration::from_secs(1));
+        manager.set_progress(id, 0.5);
+
+        let lines = render_to_lines(&mut manager);
+        let (_, name_row) = locate(&lines, "report.pdf");
+        let (_, gauge_row) = locate(&lines, "50%");
+        assert_eq!(gauge_row, name_row + 1);
+        assert!(lines[gauge_row as usize].contains('█') && lines[gauge_row as usize].contains('░'));
+
+        manager.set_progress(id, 0.75);
+        assert!(render_to_lines(&mut manager).join("\n").contains("75%"));
+    }
+
+    #[test]
+    fn test_progress_easing_fills_gauge_gradually() {
+        use ratatui_notifications::notifications::{Easing, Notifications};
+
+        let filled = |manager: &mut Notifications| {
+            let lines = render_to_lines(manager);
+            lines.iter().map(|line| line.matches('█').count()).sum::<usize>()
+        };
+
+        let mut manager = Notifications::new().progress_easing(Some((Duration::from_millis(300), Easing::Linear)));
+        let id = manager.add(progress_notification("report.pdf")).unwrap();
+        manager.tick(Duration::from_secs(1));
+        manager.set_progress(id, 0.1);
+        manager.tick(Duration::from_millis(300));
+        let start = filled(&mut manager);
+
+        manager.set_progress(id, 0.8);
+        let mut fills = vec![filled(&mut manager)];
+        for _ in 0..6 {
+            manager.tick(Duration::from_millis(50));
+            fills.push(filled(&mut manager));
+        }
+
+        assert_eq!(fills[0], start, "the fill does not jump");
+        assert!(fills.windows(2).all(|pair| pair[1] >= pair[0]));
+        assert!(fills[3] > fills[0] && fills[3] < fills[6], "midway between: {fills:?}");
+        assert!(render_to_lines(&mut manager).join("\n").contains("80%"));
+    }
+
+    #[test]
+    fn test_sticky_progress_stays_until_removed() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager
+            .add(NotificationBuilder::new("Uploading").progress(0.0).sticky(true).build().unwrap())
+            .unwrap();
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(manager.set_progress(id, 1.5));
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert_eq!(manager.find_by(|n| n.progress() == Some(1.0)), vec![id]);
+        assert!(manager.remove(id));
+    }
+
+    #[test]
+    fn test_progress_linger_delays_exit() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager
+            .add(
+                NotificationBuilder::new("Uploading")
+                    .progress(0.0)
+                    .sticky(true)
+                    .progress_linger(Duration::from_secs(1))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        manager.tick(Duration::from_secs(1));
+        manager.set_progress(id, 1.0);
+
+        // Repeated completions in later frames never restart the linger
+        for _ in 0..8 {
+            manager.tick(Duration::from_millis(100));
+            manager.set_progress(id, 1.0);
+        }
+        assert_eq!(manager.find_by(|_| true), vec![id]);
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(manager.find_by(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_set_progress_many_updates_all() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let a = manager.add(progress_notification("a.txt")).unwrap();
+        let b = manager.add(progress_notification("b.txt")).unwrap();
+        let c = manager.add(progress_notification("c.txt")).unwrap();
+
+        let updated = manager.set_progress_many(&[(a, 0.25), (b, 0.5), (c, 0.75)]);
+
+        assert_eq!(updated, 3);
+        assert_eq!(manager.find_by(|n| n.progress() == Some(0.25)), vec![a]);
+        assert_eq!(manager.find_by(|n| n.progress() == Some(0.5)), vec![b]);
+        assert_eq!(manager.find_by(|n| n.progress() == Some(0.75)), vec![c]);
+    }
+
+    #[test]
+    fn test_set_progress_many_skips_unknown_and_clamps() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let a = manager.add(progress_notification("a.txt")).unwrap();
+
+        let updated = manager.set_progress_many(&[(a, -0.5), (999, 0.5)]);
+
+        assert_eq!(updated, 1);
+        assert_eq!(manager.find_by(|n| n.progress() == Some(0.0)), vec![a]);
+        assert!(!manager.set_progress(999, 0.5));
+    }
+
+    #[test]
+    fn test_set_progress_many_dismisses_completed() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let a = manager.add(progress_notification("a.txt")).unwrap();
+        let b = manager.add(progress_notification("b.txt")).unwrap();
+        let c = manager.add(progress_notification("c.txt")).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        manager.set_progress_many(&[(a, 1.0), (b, 0.5), (c, 1.0)]);
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        assert_eq!(manager.find_by(|_| true), vec![b]);
+    }
+
+    #[test]
+    fn test_damage_rect_covers_all_drawn_notifications() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        assert_eq!(manager.damage_rect(), None);
+
+        for (text, anchor) in [("North west", Anchor::TopLeft), ("South east", Anchor::BottomRight)] {
+            manager.add(NotificationBuilder::new(text).anchor(anchor).build().unwrap()).unwrap();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let lines = render_to_lines(&mut manager);
+        let damage = manager.damage_rect().unwrap();
+
+        let (nw_col, nw_row) = locate(&lines, "North west");
+        let (se_col, se_row) = locate(&lines, "South east");
+        assert!(damage.contains(ratatui::layout::Position::new(nw_col, nw_row)));
+        assert!(damage.contains(ratatui::layout::Position::new(se_col + 9, se_row)));
+
+        // Nothing outside the damage rect was drawn
+        for (row, line) in lines.iter().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                if c != ' ' {
+                    assert!(damage.contains(ratatui::layout::Position::new(col as u16, row as u16)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_damage_rect_includes_connectors() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        render_to_lines(&mut manager);
+        let boxed = manager.damage_rect().unwrap();
+
+        manager.point_at(id, 60, 20);
+        render_to_lines(&mut manager);
+        let damage = manager.damage_rect().unwrap();
+
+        assert_eq!(damage.union(boxed), damage);
+        assert_eq!(damage.right(), 60);
+        assert_eq!(damage.bottom(), 21);
+    }
+
+    #[test]
+    fn test_damage_rect_cleared_when_nothing_draws() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let id = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        render_to_lines(&mut manager);
+        assert!(manager.damage_rect().is_some());
+
+        manager.remove(id);
+        render_to_lines(&mut manager);
+        assert_eq!(manager.damage_rect(), None);
+    }
+
+    fn paged_manager() -> ratatui_notifications::notifications::Notifications {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().page_size(Some(2));
+        for n in 1..=5 {
+            manager
+                .add(
+                    NotificationBuilder::new(format!("Item {n} with room for the page label"))
+                        .anchor(Anchor::TopLeft)
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        manager
+    }
+
+    fn shown_items(lines: &[String]) -> Vec<u32> {
+        (1..=5).filter(|n| lines.iter().any(|line| line.contains(&format!("Item {n}")))).collect()
+    }
+
+    #[test]
+    fn test_paging_shows_current_page_with_indicator() {
+        let mut manager = paged_manager();
+
+        let lines = render_to_lines(&mut manager);
+        assert_eq!(shown_items(&lines), vec![1, 2]);
+        assert!(lines.iter().any(|line| line.contains("Page 1/3")));
+
+        manager.next_page();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let lines = render_to_lines(&mut manager);
+        assert_eq!(shown_items(&lines), vec![3, 4]);
+        assert!(lines.iter().any(|line| line.contains("Page 2/3")));
+    }
+
+    #[test]
+    fn test_paging_stops_at_first_and_last_page() {
+        let mut manager = paged_manager();
+
+        manager.prev_page();
+        assert!(render_to_lines(&mut manager).iter().any(|line| line.contains("Page 1/3")));
+
+        for _ in 0..5 {
+            manager.next_page();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let lines = render_to_lines(&mut manager);
+        assert_eq!(shown_items(&lines), vec![5]);
+        assert!(lines.iter().any(|line| line.contains("Page 3/3")));
+
+        // Page 2 was skipped over, so its notifications only start entering now
+        manager.prev_page();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert!(render_to_lines(&mut manager).iter().any(|line| line.contains("Page 2/3")));
+    }
+
+    #[test]
+    fn test_paging_pauses_off_page_timers() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().page_size(Some(1));
+        for text in ["First page", "Second page"] {
+            manager.add(NotificationBuilder::new(text).anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+        }
+
+        // Long enough for the first to finish, not for both back to back
+        for _ in 0..60 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let lines = render_to_lines(&mut manager);
+        assert_eq!(manager.debug_stats().states, 1);
+        assert!(lines.iter().any(|line| line.contains("Second page")));
+        assert!(!lines.iter().any(|line| line.contains("Page ")));
+    }
+
+    #[test]
+    fn test_paging_indicator_compacts_on_narrow_boxes() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().page_size(Some(1));
+        for text in ["Tiny", "Tiny"] {
+            manager.add(NotificationBuilder::new(text).anchor(Anchor::TopLeft).build().unwrap()).unwrap();
+        }
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let lines = render_to_lines(&mut manager);
+        assert!(lines.iter().any(|line| line.contains("1/2")));
+        assert!(!lines.iter().any(|line| line.contains("Page")));
+    }
+
+    fn saving(progress: u32) -> Notification {
+        NotificationBuilder::new(format!("Saving {progress}%"))
+            .kind("saving")
+            .anchor(Anchor::TopLeft)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_kind_update_rewrites_existing_in_place() {
+        use ratatui_notifications::notifications::{KindMode, Notifications};
+
+        let mut manager = Notifications::new().kind_mode(KindMode::Update);
+        let first = manager.add(saving(10)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let before = locate(&render_to_lines(&mut manager), "Saving 10%");
+
+        let second = manager.add(saving(60)).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(manager.debug_stats().states, 1);
+
+        // No entry animation: the new text is drawn at the same spot straight away
+        let lines = render_to_lines(&mut manager);
+        assert_eq!(locate(&lines, "Saving 60%"), before);
+        assert!(!lines.iter().any(|line| line.contains("Saving 10%")));
+    }
+
+    #[test]
+    fn test_kind_replace_is_default() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let first = manager.add(saving(10)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let second = manager.add(saving(60)).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(manager.find_by(|n| n.kind() == Some("saving")), vec![second]);
+    }
+
+    #[test]
+    fn test_kind_only_matches_same_kind() {
+        use ratatui_notifications::notifications::{KindMode, Notifications};
+
+        let mut manager = Notifications::new().kind_mode(KindMode::Update);
+        manager.add(saving(10)).unwrap();
+        manager.add(NotificationBuilder::new("Loading").kind("loading").build().unwrap()).unwrap();
+        manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+        manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+
+        assert_eq!(manager.debug_stats().states, 4);
+    }
+
+    #[test]
+    fn test_kind_ignores_exiting_notification() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        use ratatui_notifications::notifications::{KindMode, Notifications};
+
+        let mut manager = Notifications::new().kind_mode(KindMode::Update);
+        let first = manager.add(saving(10)).unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+        let lines = render_to_lines(&mut manager);
+        let at = locate(&lines, "Saving 10%");
+        assert_eq!(manager.handle_click(&mouse(MouseEventKind::Down(MouseButton::Left), at)), Some(first));
+
+        let second = manager.add(saving(60)).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_pill_draws_caps_around_centred_text() {
+        use ratatui_notifications::notifications::{Notifications, Shape};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("Saved")
+                    .title("Ignored")
+                    .shape(Shape::Pill)
+                    .anchor(Anchor::TopLeft)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let lines = render_to_lines(&mut manager);
+        let (col, row) = locate(&lines, "Saved");
+        let line: Vec<char> = lines[row as usize].chars().collect();
+        let open = line[..col as usize].iter().rposition(|&c| c == '(').unwrap();
+        let close = col as usize + 5 + line[col as usize + 5..].iter().position(|&c| c == ')').unwrap();
+
+        // Equal gaps either side of the text, nothing else between the caps
+        assert_eq!(col as usize - open - 1, close - (col as usize + 5));
+        assert!(line[open + 1..col as usize].iter().all(|&c| c == ' '));
+
+        // One row: no borders, no title
+        assert!(!lines.iter().any(|line| line.contains('╭') || line.contains("Ignored")));
+    }
+
+    #[test]
+    fn test_empty_notification_shows_just_its_icon() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(NotificationBuilder::new("").level(Level::Error).anchor(Anchor::TopLeft).build().unwrap())
+            .unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let lines = render_to_lines(&mut manager);
+        let (col, row) = locate(&lines, "✖");
+        let line: Vec<char> = lines[row as usize].chars().collect();
+
+        // The icon is the body, between the side borders; the top border has no title
+        assert_eq!(line.iter().filter(|&&c| c != ' ').collect::<String>(), "│✖│");
+        assert!(lines[row as usize - 1].chars().all(|c| "╭─╮ ".contains(c)));
+        assert!(col > 0);
+    }
+
+    #[test]
+    fn test_empty_pill_shows_just_its_icon() {
+        use ratatui_notifications::notifications::{Level, Notifications, Shape};
+
+        let mut manager = Notifications::new();
+        manager
+            .add(
+                NotificationBuilder::new("")
+                    .level(Level::Warn)
+                    .shape(Shape::Pill)
+                    .anchor(Anchor::TopLeft)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        for _ in 0..10 {
+            manager.tick(Duration::from_millis(100));
+        }
+
+        let lines = render_to_lines(&mut manager);
+        let (_, row) = locate(&lines, "⚠");
+        assert_eq!(lines[row as usize].trim(), "( ⚠ )");
+    }
 }
 
 // FILE: tests/notifications/test_orc_manager.rs - Tests for Notifications manager orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.85.0
@@ -1,15 +1,17 @@
 // FILE: tests/notifications/test_orc_stacking.rs - Tests for stacking orchestrator
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF render orchestrators
-// CLOG: Initial creation with comprehensive stacking tests
+// VERSION: 1.2.0
+// WCTX: Stack growth direction override
+// CLOG: Test directed stacking
 
 use ratatui::prelude::*;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 // Import types and structures we'll need
-use ratatui_notifications::notifications::types::{Anchor, AnimationPhase};
-use ratatui_notifications::notifications::orc_stacking::calculate_stacking_positions;
+use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, StackDirection};
+use ratatui_notifications::notifications::orc_stacking::{
+    calculate_directed_stacking_positions, calculate_stacking_positions,
+};
 
 // Helper struct to simulate NotificationState for testing
 #[derive(Clone)]
@@ -19,6 +21,7 @@ struct MockNotificationState {
     created_at: Instant,
     full_rect: Rect,
     exterior_padding: u16,
+    pinned_top: bool,
 }
 
 impl MockNotificationState {
@@ -29,9 +32,15 @@ impl MockNotificationState {
             created_at: Instant::now(),
             full_rect: Rect::new(0, 0, width, height),
             exterior_padding: 0,
+            pinned_top: false,
         }
     }
 
+    fn pinned(mut self) -> Self {
+        self.pinned_top = true;
+        self
+    }
+
     fn with_created_at(mut self, created_at: Instant) -> Self {
         self.created_at = created_at;
         self
@@ -63,6 +72,10 @@ impl ratatui_notifications::notifications::orc_stacking::StackableNotification f
         // Mock implementation: return full_rect dimensions
         (self.full_rect.width, self.full_rect.height)
     }
+
+    fn is_pinned_top(&self) -> bool {
+        self.pinned_top
+    }
 }
 
 #[test]
@@ -216,7 +229,7 @@ fn test_stacking_respects_available_height() {
     // Create 10 notifications, each 15 pixels tall
     for i in 1..=10 {
         let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 15)
-            .with_created_at(now + Duration::from_millis(i as u64 * 10));
+            .with_created_at(now + Duration::from_millis(i * 10));
         notifications.insert(i, state);
     }
 
@@ -235,7 +248,7 @@ fn test_stacking_respects_available_height() {
 
     // Should return fewer than 10 due to height constraint
     assert!(result.len() < 10, "Should limit based on available height");
-    assert!(result.len() > 0, "Should return at least some notifications");
+    assert!(!result.is_empty(), "Should return at least some notifications");
 
     // All returned notifications should fit within frame
     for stacked in &result {
@@ -252,7 +265,7 @@ fn test_max_concurrent_limit_respected() {
     // Create 10 notifications
     for i in 1..=10 {
         let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 10)
-            .with_created_at(now + Duration::from_millis(i as u64 * 10));
+            .with_created_at(now + Duration::from_millis(i * 10));
         notifications.insert(i, state);
     }
 
@@ -308,5 +321,117 @@ fn test_pending_and_finished_notifications_excluded() {
     assert_eq!(result[0].id, 2, "Should only include the Dwelling notification");
 }
 
+#[test]
+fn test_pinned_notifications_stack_first_in_both_directions() {
+    let now = Instant::now();
+    let mut notifications = HashMap::new();
+    for i in 1..=4 {
+        let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 10)
+            .with_created_at(now + Duration::from_millis(i * 10));
+        let state = if i == 2 || i == 3 { state.pinned() } else { state };
+        notifications.insert(i, state);
+    }
+    let ids_at_anchor: Vec<u64> = (1..=4).collect();
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let order = |anchor| -> Vec<u64> {
+        calculate_stacking_positions(&notifications, anchor, &ids_at_anchor, frame_area, None)
+            .iter()
+            .map(|stacked| stacked.id)
+            .collect()
+    };
+
+    // Pinned by age first, then the usual order for the direction
+    assert_eq!(order(Anchor::BottomRight), vec![2, 3, 4, 1]);
+    assert_eq!(order(Anchor::TopLeft), vec![2, 3, 1, 4]);
+}
+
+#[test]
+fn test_pinned_notifications_survive_max_concurrent() {
+    let now = Instant::now();
+    let mut notifications = HashMap::new();
+    for i in 1..=5 {
+        let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 10)
+            .with_created_at(now + Duration::from_millis(i * 10));
+        let state = if i == 1 { state.pinned() } else { state };
+        notifications.insert(i, state);
+    }
+    let ids_at_anchor: Vec<u64> = (1..=5).collect();
+
+    let result = calculate_stacking_positions(
+        &notifications,
+        Anchor::BottomRight,
+        &ids_at_anchor,
+        Rect::new(0, 0, 100, 200),
+        Some(3),
+    );
+
+    let ids: Vec<u64> = result.iter().map(|stacked| stacked.id).collect();
+    assert_eq!(ids, vec![1, 5, 4]);
+}
+
+#[test]
+fn test_equal_created_at_breaks_ties_by_id() {
+    let now = Instant::now();
+    let mut notifications = HashMap::new();
+    for i in 1..=4 {
+        let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 10).with_created_at(now);
+        notifications.insert(i, state);
+    }
+
+    // Same result whatever order the anchor lists the IDs in
+    for ids_at_anchor in [vec![3, 1, 4, 2], vec![4, 3, 2, 1], vec![1, 2, 3, 4]] {
+        let result = calculate_stacking_positions(
+            &notifications,
+            Anchor::BottomRight,
+            &ids_at_anchor,
+            Rect::new(0, 0, 100, 200),
+            Some(3),
+        );
+
+        let ids: Vec<u64> = result.iter().map(|stacked| stacked.id).collect();
+        assert_eq!(ids, vec![4, 3, 2]);
+    }
+}
+
+#[test]
+fn test_directed_stacking_overrides_growth_direction() {
+    let now = Instant::now();
+    let mut notifications = HashMap::new();
+    for i in 1..=2 {
+        let state = MockNotificationState::new(i, AnimationPhase::Dwelling, 40, 10)
+            .with_created_at(now + Duration::from_millis(i * 10));
+        notifications.insert(i, state);
+    }
+    let ids_at_anchor = vec![1, 2];
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let stack = |anchor, direction| -> Vec<(u64, Rect)> {
+        calculate_directed_stacking_positions(&notifications, anchor, direction, &ids_at_anchor, frame_area, None)
+            .iter()
+            .map(|stacked| (stacked.id, stacked.rect))
+            .collect()
+    };
+
+    // The anchor's own direction matches the undirected stacking
+    let default_stack: Vec<(u64, Rect)> =
+        calculate_stacking_positions(&notifications, Anchor::TopRight, &ids_at_anchor, frame_area, None)
+            .iter()
+            .map(|stacked| (stacked.id, stacked.rect))
+            .collect();
+    assert_eq!(stack(Anchor::TopRight, StackDirection::Down), default_stack);
+
+    // Growing up from a top anchor rests the stack on the bottom edge, newest lowest
+    assert_eq!(
+        stack(Anchor::TopRight, StackDirection::Up),
+        vec![(2, Rect::new(60, 90, 40, 10)), (1, Rect::new(60, 80, 40, 10))]
+    );
+
+    // A middle anchor grows up from its middle row
+    let middle = stack(Anchor::MiddleRight, StackDirection::Up);
+    assert_eq!(middle[0].0, 2);
+    assert!(middle[1].1.bottom() <= middle[0].1.y);
+}
+
 // FILE: tests/notifications/test_orc_stacking.rs - Tests for stacking orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
@@ -0,0 +1,95 @@
+// FILE: tests/test_serde.rs - Integration tests for the serde feature
+// VERSION: 1.0.0
+// WCTX: Serde support for notifications
+// CLOG: Initial creation
+
+#![cfg(feature = "serde")]
+
+use std::time::Duration;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{BorderType, Padding};
+use ratatui_notifications::{
+    ActionId, Anchor, Animation, AutoDismiss, Easing, Level, Notification, Notifications, Overflow, Position, Shape,
+    SizeConstraint, SlideDirection, Timing,
+};
+
+fn rich_notification() -> Notification {
+    Notification::new(Text::from(vec![
+        Line::from(vec![Span::raw("Build "), Span::styled("failed", Style::default().fg(Color::Red))]),
+        Line::from("see log").centered(),
+    ]))
+    .title(Line::from("CI").style(Style::default().add_modifier(Modifier::BOLD)))
+    .headline("cargo test")
+    .level(Level::Error)
+    .anchor(Anchor::BottomLeft)
+    .animation(Animation::Fade)
+    .easing(Easing::EaseOutCubic)
+    .slide_direction(SlideDirection::FromLeft)
+    .timing(Timing::Fixed(Duration::from_millis(200)), Timing::Auto, Timing::Fixed(Duration::from_millis(300)))
+    .auto_dismiss(AutoDismiss::After(Duration::from_secs(9)))
+    .max_size(SizeConstraint::Percentage(0.5), SizeConstraint::Absolute(8))
+    .padding(Padding::new(2, 2, 0, 1))
+    .margin(1)
+    .border_style(Style::default().fg(Color::Rgb(10, 20, 30)))
+    .border_type(BorderType::Double)
+    .entry_position(Position::new(3, 4))
+    .protected(true)
+    .animated_icon(vec!["|".to_string(), "/".to_string()], Duration::from_millis(120))
+    .time_color(Color::Green, Color::Yellow)
+    .kind("ci")
+    .shape(Shape::Pill)
+    .progress(0.25)
+    .sticky(true)
+    .priority(3)
+    .action("Retry", ActionId(7))
+    .on_dismiss(Box::new(|_, _| {}))
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn test_notification_round_trips_through_json() {
+    let notification = rich_notification();
+
+    let json = serde_json::to_string(&notification).unwrap();
+    let restored: Notification = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    assert_eq!(restored.content(), notification.content());
+    assert_eq!(restored.title(), notification.title());
+    assert_eq!(restored.level(), Some(Level::Error));
+    assert_eq!(restored.anchor(), Anchor::BottomLeft);
+    assert_eq!(restored.auto_dismiss(), AutoDismiss::After(Duration::from_secs(9)));
+    assert_eq!(restored.border_type(), Some(BorderType::Double));
+    assert_eq!(restored.padding(), Padding::new(2, 2, 0, 1));
+    assert_eq!(restored.actions(), notification.actions());
+}
+
+#[test]
+fn test_option_enums_round_trip_through_json() {
+    for overflow in [Overflow::DiscardOldest, Overflow::DiscardNewest, Overflow::Scored, Overflow::Queue] {
+        let json = serde_json::to_string(&overflow).unwrap();
+        assert_eq!(serde_json::from_str::<Overflow>(&json).unwrap(), overflow);
+    }
+    let json = serde_json::to_string(&Level::Warn).unwrap();
+    assert_eq!(serde_json::from_str::<Level>(&json).unwrap(), Level::Warn);
+}
+
+#[test]
+fn test_snapshot_restores_from_json() {
+    let mut old = Notifications::new();
+    old.add(rich_notification()).unwrap();
+    old.add(Notification::new("Saved").level(Level::Info).build().unwrap()).unwrap();
+
+    let json = serde_json::to_string(&old.snapshot()).unwrap();
+    let mut reloaded = Notifications::new();
+    reloaded.restore(serde_json::from_str(&json).unwrap()).unwrap();
+
+    let levels: Vec<_> = reloaded.iter().map(|(_, notification, _)| notification.level()).collect();
+    assert_eq!(levels, vec![Some(Level::Error), Some(Level::Info)]);
+}
+
+// FILE: tests/test_serde.rs - Integration tests for the serde feature
+// END OF VERSION: 1.0.0